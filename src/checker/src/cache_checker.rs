@@ -1,14 +1,18 @@
+use std::time::Duration;
+
 use anyhow::Ok;
 use async_trait::async_trait;
-use base64::{engine::general_purpose::STANDARD, Engine};
 use moka::sync::Cache;
 use tracing::Instrument;
 
-use crate::{CheckRequest, CheckResult, Checker, CheckerRef};
+use crate::{CheckRequest, CheckResult, Checker, CheckerRef, Generations, DEFAULT_CACHE_CAPACITY};
 
 pub struct CacheChecker {
     delegate: CheckerRef,
     cache: Cache<String, bool>,
+    /// Part of the keys of the results, read when a check starts, so that the results read before a write are no
+    /// longer hit, including those of the checks that were still running when it landed.
+    generations: Generations,
 }
 
 #[async_trait]
@@ -40,22 +44,29 @@ impl Checker for CacheChecker {
 
 impl CacheChecker {
     pub fn new(delegate: CheckerRef) -> Self {
-        let cache = Cache::new(100);
-        Self { delegate, cache }
+        Self::with_options(delegate, DEFAULT_CACHE_CAPACITY, None)
     }
 
-    fn request_cache_key(&self, req: &CheckRequest) -> String {
-        let mut contextual_tuples_cache_key = String::new();
-        for tk in req.contextual_tuples.clone() {
-            let key = format!("/{}", tk.cache_key());
-            contextual_tuples_cache_key.push_str(key.as_str());
+    /// Keeps at most `capacity` results, each for at most `ttl` when there is one.
+    pub fn with_options(delegate: CheckerRef, capacity: u64, ttl: Option<Duration>) -> Self {
+        let mut builder = Cache::builder().max_capacity(capacity);
+        if let Some(ttl) = ttl {
+            builder = builder.time_to_live(ttl);
         }
-        STANDARD.encode(format!(
-            "{}/{}/{}{}",
-            req.tenant_id,
-            req.model_id,
-            req.tuple_key.cache_key(),
-            contextual_tuples_cache_key
-        ))
+        Self {
+            delegate,
+            cache: builder.build(),
+            generations: Default::default(),
+        }
+    }
+
+    /// Keys the results by the generations, the writes bump them to drop the cached results of their tenant.
+    pub fn with_generations(mut self, generations: Generations) -> Self {
+        self.generations = generations;
+        self
+    }
+
+    fn request_cache_key(&self, req: &CheckRequest) -> String {
+        format!("{}/{}", self.generations.get(&req.tenant_id), req.cache_key())
     }
 }
@@ -1,7 +1,16 @@
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum CheckerError {
     #[error("Not found _this type by object type: {object_type}, relation: {relation}")]
     NotFoundThisTypes { object_type: String, relation: String },
+    #[error("Not found condition: {0}")]
+    NotFoundCondition(String),
+    #[error("Failed to evaluate condition {name}: {source}")]
+    ConditionEvaluation {
+        name: String,
+        source: schema::ConditionError,
+    },
+    #[error("Resolution too complex, the depth limit was reached")]
+    ResolutionTooComplex,
 }
@@ -2,12 +2,17 @@ mod error;
 mod objects;
 mod users;
 
+use std::{collections::HashMap, sync::Arc};
+
 use anyhow::Result;
 use futures::{future::BoxFuture, FutureExt};
-use protocol::{Typesystem, Userset};
+use protocol::{Tuple, Typesystem, Userset};
+use schema::Condition;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use storage::{RelationshipTupleReaderRef, TupleFilter};
+use storage::{ContextualTupleReader, RelationshipTupleReaderRef, TupleFilter};
+
+use crate::{filter_by_condition, superadmin_filter};
 
 pub use objects::ObjectsExpander;
 pub use users::UsersExpander;
@@ -37,17 +42,71 @@ pub enum ExpandTree {
         base: Box<ExpandTree>,
         subtract: Box<ExpandTree>,
     },
+    /// subjects allowed on every object by the superadmins of the model
+    Superadmin(Vec<ExpandTreeNode>),
 }
 pub struct Expander {
     tuple_reader: RelationshipTupleReaderRef,
+    /// conditions of the model, keyed by name
+    conditions: HashMap<String, Condition>,
+    /// values for condition parameters, merged with the context stored on each tuple
+    context: HashMap<String, serde_json::Value>,
 }
 
 impl Expander {
     pub fn new(tuple_reader: RelationshipTupleReaderRef) -> Self {
-        Self { tuple_reader }
+        Self {
+            tuple_reader,
+            conditions: HashMap::new(),
+            context: HashMap::new(),
+        }
+    }
+
+    /// Expands as if the tuples were stored along with the stored ones.
+    pub fn with_contextual_tuples(&self, tuples: Vec<Tuple>) -> Self {
+        Self {
+            tuple_reader: Arc::new(ContextualTupleReader::new(self.tuple_reader.clone(), tuples)),
+            conditions: self.conditions.clone(),
+            context: self.context.clone(),
+        }
+    }
+
+    /// Evaluates the conditions of the tuples, those of the model, against the context, as a check does.
+    pub fn with_context(
+        &self,
+        conditions: HashMap<String, Condition>,
+        context: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        Self {
+            tuple_reader: self.tuple_reader.clone(),
+            conditions,
+            context,
+        }
+    }
+
+    /// Reads the tuples of the filter whose condition holds.
+    async fn list(&self, tenant_id: &str, typesystem: &Typesystem, filter: TupleFilter) -> Result<Vec<Tuple>> {
+        let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+        filter_by_model_conditions(typesystem, &self.conditions, &self.context, tuples)
     }
 }
 
+/// Keeps the tuples whose condition holds for the context, each against the subjects its relation takes in the model,
+/// so that an expansion drops the tuples a check ignores.
+fn filter_by_model_conditions(
+    typesystem: &Typesystem,
+    conditions: &HashMap<String, Condition>,
+    context: &HashMap<String, serde_json::Value>,
+    tuples: Vec<Tuple>,
+) -> Result<Vec<Tuple>> {
+    let mut result = Vec::with_capacity(tuples.len());
+    for t in tuples {
+        let references = typesystem.get_directly_related_types(&t.object_type, &t.relation)?;
+        result.extend(filter_by_condition(conditions, context, vec![t], &references)?);
+    }
+    Ok(result)
+}
+
 impl Expander {
     pub async fn expand(
         &self,
@@ -58,13 +117,39 @@ impl Expander {
         object_id: String,
     ) -> Result<ExpandTree> {
         let typ = typesystem.get_relation(&object_type, &relation)?;
-        self.userset_to_tree(&tenant_id, &typ.rewrite, &relation, &object_type, &object_id)
-            .await
+        let tree = self
+            .userset_to_tree(
+                &tenant_id,
+                &typesystem,
+                &typ.rewrite,
+                &relation,
+                &object_type,
+                &object_id,
+            )
+            .await?;
+        if typesystem.superadmins.is_empty() {
+            return Ok(tree);
+        }
+        let tuples = self
+            .list(&tenant_id, &typesystem, superadmin_filter(&typesystem))
+            .await?;
+        let superadmins = tuples
+            .iter()
+            .map(|t| ExpandTreeNode {
+                user_type: t.user_type.to_owned(),
+                user_id: t.user_id.to_owned(),
+                user_relation: t.user_relation.to_owned(),
+            })
+            .collect();
+        Ok(ExpandTree::Union {
+            children: vec![Box::new(tree), Box::new(ExpandTree::Superadmin(superadmins))],
+        })
     }
 
     fn userset_to_tree<'a, 'b>(
         &'a self,
         tenant_id: &'b str,
+        typesystem: &'b Typesystem,
         userset: &'b Userset,
         relation: &'b str,
         object_type: &'b str,
@@ -82,7 +167,7 @@ impl Expander {
                         relation_eq: Some(relation.to_string()),
                         ..Default::default()
                     };
-                    let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                    let tuples = self.list(tenant_id, typesystem, filter).await?;
                     Ok(ExpandTree::This(
                         tuples
                             .iter()
@@ -106,7 +191,7 @@ impl Expander {
                         relation_eq: Some(rel.tupleset.relation.to_string()),
                         ..Default::default()
                     };
-                    let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                    let tuples = self.list(tenant_id, typesystem, filter).await?;
 
                     Ok(ExpandTree::TupleTo {
                         tupleset: ExpandTreeNode {
@@ -128,7 +213,7 @@ impl Expander {
                     let mut list = vec![];
                     for child in children {
                         list.push(Box::new(
-                            self.userset_to_tree(&tenant_id, &child, relation, object_type, object_id)
+                            self.userset_to_tree(tenant_id, typesystem, child, relation, object_type, object_id)
                                 .await?,
                         ))
                     }
@@ -138,7 +223,7 @@ impl Expander {
                     let mut list = vec![];
                     for child in children {
                         list.push(Box::new(
-                            self.userset_to_tree(&tenant_id, &child, relation, object_type, object_id)
+                            self.userset_to_tree(tenant_id, typesystem, child, relation, object_type, object_id)
                                 .await?,
                         ))
                     }
@@ -146,11 +231,11 @@ impl Expander {
                 }
                 Userset::Difference { base, subtract } => Ok(ExpandTree::Difference {
                     base: Box::new(
-                        self.userset_to_tree(&tenant_id, &base, relation, object_type, object_id)
+                        self.userset_to_tree(tenant_id, typesystem, base, relation, object_type, object_id)
                             .await?,
                     ),
                     subtract: Box::new(
-                        self.userset_to_tree(&tenant_id, &subtract, relation, object_type, object_id)
+                        self.userset_to_tree(tenant_id, typesystem, subtract, relation, object_type, object_id)
                             .await?,
                     ),
                 }),
@@ -1,23 +1,62 @@
 use anyhow::Result;
 use futures::{future::BoxFuture, FutureExt};
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use protocol::{RelationReference, Typesystem, Userset};
-use storage::{RelationshipTupleReaderRef, TupleFilter};
+use protocol::{Subject, Tuple, Typesystem, Userset};
+use schema::Condition;
+use storage::{ContextualTupleReader, Pagination, RelationshipTupleReaderRef, TupleFilter};
 
-use crate::expander::error::ExpanderError;
+use super::filter_by_model_conditions;
+use crate::{expander::error::ExpanderError, superadmin_filter};
+
+/// How many tuples a superadmin reads at a time while listing every object of a type.
+const PAGE_SIZE: u64 = 1000;
 
 pub struct ObjectsExpander {
     tuple_reader: RelationshipTupleReaderRef,
+    /// conditions of the model, keyed by name
+    conditions: HashMap<String, Condition>,
+    /// values for condition parameters, merged with the context stored on each tuple
+    context: HashMap<String, serde_json::Value>,
 }
 
 impl ObjectsExpander {
     pub fn new(tuple_reader: RelationshipTupleReaderRef) -> Self {
-        Self { tuple_reader }
+        Self {
+            tuple_reader,
+            conditions: HashMap::new(),
+            context: HashMap::new(),
+        }
+    }
+
+    /// Expands as if the tuples were stored along with the stored ones.
+    pub fn with_contextual_tuples(&self, tuples: Vec<Tuple>) -> Self {
+        Self {
+            tuple_reader: Arc::new(ContextualTupleReader::new(self.tuple_reader.clone(), tuples)),
+            conditions: self.conditions.clone(),
+            context: self.context.clone(),
+        }
+    }
+
+    /// Evaluates the conditions of the tuples, those of the model, against the context, as a check does.
+    pub fn with_context(
+        &self,
+        conditions: HashMap<String, Condition>,
+        context: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        Self {
+            tuple_reader: self.tuple_reader.clone(),
+            conditions,
+            context,
+        }
     }
 }
 
 impl ObjectsExpander {
+    #[allow(clippy::too_many_arguments)]
     pub async fn objects(
         &self,
         typesystem: Typesystem,
@@ -30,6 +69,20 @@ impl ObjectsExpander {
     ) -> Result<HashSet<String>> {
         let typ = typesystem.get_relation(&object_type, &relation)?;
 
+        if !typesystem.superadmins.is_empty() {
+            let filter = TupleFilter {
+                user_type_eq: Some(user_type.to_owned()),
+                user_id_eq: Some(user_id.to_owned()),
+                user_relation_eq: user_relation.to_owned(),
+                user_relation_is_null: user_relation.is_none().then_some(true),
+                ..superadmin_filter(&typesystem)
+            };
+            // a superadmin is allowed on every object of the type, that is every object some tuple refers to
+            if !self.list(&tenant_id, &typesystem, filter).await?.is_empty() {
+                return self.all_objects(&tenant_id, &object_type).await;
+            }
+        }
+
         self.userset_to_objects(
             &tenant_id,
             &typesystem,
@@ -42,6 +95,36 @@ impl ObjectsExpander {
         )
         .await
     }
+
+    /// Reads the tuples of the filter whose condition holds.
+    async fn list(&self, tenant_id: &str, typesystem: &Typesystem, filter: TupleFilter) -> Result<Vec<Tuple>> {
+        let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+        filter_by_model_conditions(typesystem, &self.conditions, &self.context, tuples)
+    }
+
+    /// Every object of the type some tuple refers to, read a page at a time.
+    async fn all_objects(&self, tenant_id: &str, object_type: &str) -> Result<HashSet<String>> {
+        let filter = TupleFilter {
+            object_type_eq: Some(object_type.to_owned()),
+            ..Default::default()
+        };
+        let mut object_ids = HashSet::new();
+        let mut page = 1;
+        loop {
+            let (tuples, pages) = self
+                .tuple_reader
+                .clone()
+                .list(tenant_id, filter.clone(), Some(Pagination { size: PAGE_SIZE, page }))
+                .await?;
+            object_ids.extend(tuples.into_iter().map(|t| t.object_id));
+            if page >= pages.unwrap_or_default() {
+                return Ok(object_ids);
+            }
+            page += 1;
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn userset_to_objects<'a, 'b>(
         &'a self,
         tenant_id: &'b str,
@@ -62,10 +145,12 @@ impl ObjectsExpander {
                     let rts = typesystem.get_directly_related_types(object_type, relation)?;
                     let mut object_ids = HashSet::new();
                     for rt in rts {
-                        let (typ, rt_user_relation, user_relation_is_exist) = match rt {
-                            RelationReference::Direct(typ) => (typ, None, false),
-                            RelationReference::Relation { r#type, relation } => (r#type, Some(relation), true),
-                            RelationReference::Wildcard(typ) => (typ, None, true),
+                        let (typ, rt_user_relation, user_relation_is_exist) = match rt.subject() {
+                            Subject::Direct(typ) => (typ.to_owned(), None, false),
+                            Subject::Relation { r#type, relation } => {
+                                (r#type.to_owned(), Some(relation.to_owned()), true)
+                            }
+                            Subject::Wildcard(typ) => (typ.to_owned(), None, true),
                         };
                         if user_type.eq(&typ) {
                             if user_relation_is_exist {
@@ -78,8 +163,7 @@ impl ObjectsExpander {
                                             user_relation_eq: Some(user_relation.to_owned()),
                                             ..Default::default()
                                         };
-                                        let (tuples, _) =
-                                            self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                        let tuples = self.list(tenant_id, typesystem, filter).await?;
                                         object_ids.extend(tuples.into_iter().map(|t| t.object_id));
                                     } else {
                                         continue;
@@ -92,7 +176,7 @@ impl ObjectsExpander {
                                         user_relation_is_null: Some(false),
                                         ..Default::default()
                                     };
-                                    let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                    let tuples = self.list(tenant_id, typesystem, filter).await?;
                                     object_ids.extend(tuples.into_iter().map(|t| t.object_id));
                                 }
                             } else {
@@ -103,7 +187,7 @@ impl ObjectsExpander {
                                     user_id_eq: Some(user_id.to_owned()),
                                     ..Default::default()
                                 };
-                                let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                let tuples = self.list(tenant_id, typesystem, filter).await?;
                                 object_ids.extend(tuples.into_iter().map(|t| t.object_id));
                             }
                         } else {
@@ -143,7 +227,7 @@ impl ObjectsExpander {
                                     user_relation_eq: Some(rt_user_relation),
                                     ..Default::default()
                                 };
-                                let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                let tuples = self.list(tenant_id, typesystem, filter).await?;
                                 object_ids.extend(tuples.iter().map(|t| t.object_id.to_owned()));
                             } else {
                                 continue;
@@ -173,15 +257,15 @@ impl ObjectsExpander {
                     let rts = typesystem.get_directly_related_types(object_type, &ttu.tupleset.relation)?;
                     let mut object_ids = HashSet::new();
                     for rt in rts {
-                        match rt {
-                            RelationReference::Direct(ot) => {
+                        match rt.subject() {
+                            Subject::Direct(ot) => {
                                 let mid_object_ids = self
                                     .userset_to_objects(
                                         tenant_id,
                                         typesystem,
                                         &rel.rewrite,
                                         &ttu.computed_userset.relation,
-                                        &ot,
+                                        ot,
                                         user_type,
                                         user_id,
                                         user_relation,
@@ -190,15 +274,15 @@ impl ObjectsExpander {
                                 let filter = TupleFilter {
                                     object_type_eq: Some(object_type.to_owned()),
                                     relation_eq: Some(ttu.tupleset.relation.clone()),
-                                    user_type_eq: Some(ot.clone()),
+                                    user_type_eq: Some(ot.to_owned()),
                                     user_id_in: Some(mid_object_ids.into_iter().collect()),
                                     user_relation_is_null: Some(true),
                                     ..Default::default()
                                 };
-                                let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                let tuples = self.list(tenant_id, typesystem, filter).await?;
                                 object_ids.extend(tuples.into_iter().map(|t| t.object_id));
                             }
-                            RelationReference::Wildcard(_) | RelationReference::Relation { .. } => {
+                            Subject::Wildcard(_) | Subject::Relation { .. } => {
                                 return Err(ExpanderError::NotOnlyDirect {
                                     tupleset: ttu.tupleset.relation.clone(),
                                 }
@@ -230,7 +314,7 @@ impl ObjectsExpander {
                     Ok(object_ids)
                 }
                 Userset::Intersection { children } => {
-                    let mut object_ids = HashSet::new();
+                    let mut object_ids: Option<HashSet<String>> = None;
                     for child in children {
                         let child_object_ids = self
                             .userset_to_objects(
@@ -244,18 +328,17 @@ impl ObjectsExpander {
                                 user_relation,
                             )
                             .await?;
-                        if child_object_ids.len() == 0 {
-                            return Ok(HashSet::new());
-                        } else {
-                            object_ids = object_ids
-                                .clone()
-                                .intersection(&child_object_ids)
-                                .map(|x| x.to_owned())
-                                .collect();
+                        // the first child is the set the others narrow down
+                        object_ids = match object_ids {
+                            None => Some(child_object_ids),
+                            Some(ids) => Some(ids.intersection(&child_object_ids).map(|x| x.to_owned()).collect()),
+                        };
+                        if object_ids.as_ref().is_some_and(HashSet::is_empty) {
+                            break;
                         }
                     }
 
-                    Ok(object_ids)
+                    Ok(object_ids.unwrap_or_default())
                 }
                 Userset::Difference { base, subtract } => {
                     let base_object_ids = self
@@ -1,23 +1,65 @@
 use anyhow::Result;
 use futures::{future::BoxFuture, FutureExt};
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use protocol::{RelationReference, Typesystem, Userset};
-use storage::{RelationshipTupleReaderRef, TupleFilter};
+use protocol::{Subject, Tuple, Typesystem, Userset};
+use schema::Condition;
+use storage::{ContextualTupleReader, RelationshipTupleReaderRef, TupleFilter};
 
-use super::error::ExpanderError;
+use super::{error::ExpanderError, filter_by_model_conditions};
+use crate::superadmin_filter;
 
 pub struct UsersExpander {
     tuple_reader: RelationshipTupleReaderRef,
+    /// conditions of the model, keyed by name
+    conditions: HashMap<String, Condition>,
+    /// values for condition parameters, merged with the context stored on each tuple
+    context: HashMap<String, serde_json::Value>,
 }
 
 impl UsersExpander {
     pub fn new(tuple_reader: RelationshipTupleReaderRef) -> Self {
-        Self { tuple_reader }
+        Self {
+            tuple_reader,
+            conditions: HashMap::new(),
+            context: HashMap::new(),
+        }
+    }
+
+    /// Expands as if the tuples were stored along with the stored ones.
+    pub fn with_contextual_tuples(&self, tuples: Vec<Tuple>) -> Self {
+        Self {
+            tuple_reader: Arc::new(ContextualTupleReader::new(self.tuple_reader.clone(), tuples)),
+            conditions: self.conditions.clone(),
+            context: self.context.clone(),
+        }
+    }
+
+    /// Evaluates the conditions of the tuples, those of the model, against the context, as a check does.
+    pub fn with_context(
+        &self,
+        conditions: HashMap<String, Condition>,
+        context: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        Self {
+            tuple_reader: self.tuple_reader.clone(),
+            conditions,
+            context,
+        }
+    }
+
+    /// Reads the tuples of the filter whose condition holds.
+    async fn list(&self, tenant_id: &str, typesystem: &Typesystem, filter: TupleFilter) -> Result<Vec<Tuple>> {
+        let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+        filter_by_model_conditions(typesystem, &self.conditions, &self.context, tuples)
     }
 }
 
 impl UsersExpander {
+    #[allow(clippy::too_many_arguments)]
     pub async fn users(
         &self,
         typesystem: Typesystem,
@@ -30,19 +72,32 @@ impl UsersExpander {
     ) -> Result<HashSet<String>> {
         let typ = typesystem.get_relation(&object_type, &relation)?;
 
-        self.userset_to_users(
-            &tenant_id,
-            &typesystem,
-            &typ.rewrite,
-            &relation,
-            &object_type,
-            &object_id,
-            &user_type,
-            &user_relation,
-        )
-        .await
+        let mut user_ids = self
+            .userset_to_users(
+                &tenant_id,
+                &typesystem,
+                &typ.rewrite,
+                &relation,
+                &object_type,
+                &object_id,
+                &user_type,
+                &user_relation,
+            )
+            .await?;
+        if !typesystem.superadmins.is_empty() {
+            let filter = TupleFilter {
+                user_type_eq: Some(user_type.to_owned()),
+                user_relation_eq: user_relation.to_owned(),
+                user_relation_is_null: user_relation.is_none().then_some(true),
+                ..superadmin_filter(&typesystem)
+            };
+            let tuples = self.list(&tenant_id, &typesystem, filter).await?;
+            user_ids.extend(tuples.into_iter().map(|t| t.user_id));
+        }
+        Ok(user_ids)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn userset_to_users<'a, 'b>(
         &'a self,
         tenant_id: &'b str,
@@ -63,10 +118,12 @@ impl UsersExpander {
                     let rts = typesystem.get_directly_related_types(object_type, relation)?;
                     let mut user_ids = HashSet::new();
                     for rt in rts {
-                        let (typ, rt_user_relation, user_relation_is_exist) = match rt {
-                            RelationReference::Direct(typ) => (typ, None, false),
-                            RelationReference::Relation { r#type, relation } => (r#type, Some(relation), true),
-                            RelationReference::Wildcard(typ) => (typ, None, true),
+                        let (typ, rt_user_relation, user_relation_is_exist) = match rt.subject() {
+                            Subject::Direct(typ) => (typ.to_owned(), None, false),
+                            Subject::Relation { r#type, relation } => {
+                                (r#type.to_owned(), Some(relation.to_owned()), true)
+                            }
+                            Subject::Wildcard(typ) => (typ.to_owned(), None, true),
                         };
                         if user_type.eq(&typ) {
                             if user_relation_is_exist {
@@ -80,8 +137,7 @@ impl UsersExpander {
                                             user_relation_eq: Some(user_relation.to_owned()),
                                             ..Default::default()
                                         };
-                                        let (tuples, _) =
-                                            self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                        let tuples = self.list(tenant_id, typesystem, filter).await?;
                                         user_ids.extend(tuples.into_iter().map(|t| t.user_id));
                                     } else {
                                         continue;
@@ -95,7 +151,7 @@ impl UsersExpander {
                                         user_relation_is_null: Some(false),
                                         ..Default::default()
                                     };
-                                    let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                    let tuples = self.list(tenant_id, typesystem, filter).await?;
                                     user_ids.extend(tuples.into_iter().map(|t| t.user_id));
                                 }
                             } else {
@@ -106,7 +162,7 @@ impl UsersExpander {
                                     user_type_eq: Some(typ.to_owned()),
                                     ..Default::default()
                                 };
-                                let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                let tuples = self.list(tenant_id, typesystem, filter).await?;
                                 user_ids.extend(tuples.into_iter().map(|t| t.user_id));
                             }
                         } else {
@@ -146,7 +202,7 @@ impl UsersExpander {
                                     user_relation_is_null: Some(true),
                                     ..Default::default()
                                 };
-                                let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                let tuples = self.list(tenant_id, typesystem, filter).await?;
                                 user_ids.extend(tuples.into_iter().map(|t| t.user_id));
                             } else {
                                 continue;
@@ -176,15 +232,15 @@ impl UsersExpander {
                     let rts = typesystem.get_directly_related_types(object_type, &ttu.tupleset.relation)?;
                     let mut object_ids = HashSet::new();
                     for rt in rts {
-                        match rt {
-                            RelationReference::Direct(ot) => {
+                        match rt.subject() {
+                            Subject::Direct(ot) => {
                                 let mid_user_ids = self
                                     .userset_to_users(
                                         tenant_id,
                                         typesystem,
                                         &rel.rewrite,
                                         &ttu.computed_userset.relation,
-                                        &ot,
+                                        ot,
                                         object_id,
                                         user_type,
                                         user_relation,
@@ -194,14 +250,14 @@ impl UsersExpander {
                                     object_type_eq: Some(object_type.to_owned()),
                                     object_id_in: Some(mid_user_ids.into_iter().collect()),
                                     relation_eq: Some(ttu.tupleset.relation.clone()),
-                                    user_type_eq: Some(ot.clone()),
+                                    user_type_eq: Some(ot.to_owned()),
                                     user_relation_is_null: Some(true),
                                     ..Default::default()
                                 };
-                                let (tuples, _) = self.tuple_reader.clone().list(tenant_id, filter, None).await?;
+                                let tuples = self.list(tenant_id, typesystem, filter).await?;
                                 object_ids.extend(tuples.into_iter().map(|t| t.object_id));
                             }
-                            RelationReference::Wildcard(_) | RelationReference::Relation { .. } => {
+                            Subject::Wildcard(_) | Subject::Relation { .. } => {
                                 return Err(ExpanderError::NotOnlyDirect {
                                     tupleset: ttu.tupleset.relation.clone(),
                                 }
@@ -232,7 +288,7 @@ impl UsersExpander {
                     Ok(user_ids)
                 }
                 Userset::Intersection { children } => {
-                    let mut user_ids = HashSet::new();
+                    let mut user_ids: Option<HashSet<String>> = None;
                     for child in children {
                         let child_user_ids = self
                             .userset_to_users(
@@ -246,18 +302,17 @@ impl UsersExpander {
                                 user_relation,
                             )
                             .await?;
-                        if child_user_ids.len() == 0 {
-                            return Ok(HashSet::new());
-                        } else {
-                            user_ids = user_ids
-                                .clone()
-                                .intersection(&child_user_ids)
-                                .map(|x| x.to_owned())
-                                .collect();
+                        // the first child is the set the others narrow down
+                        user_ids = match user_ids {
+                            None => Some(child_user_ids),
+                            Some(ids) => Some(ids.intersection(&child_user_ids).map(|x| x.to_owned()).collect()),
+                        };
+                        if user_ids.as_ref().is_some_and(HashSet::is_empty) {
+                            break;
                         }
                     }
 
-                    Ok(user_ids)
+                    Ok(user_ids.unwrap_or_default())
                 }
                 Userset::Difference { base, subtract } => {
                     let base_user_ids = self
@@ -1,17 +1,27 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::DEFAULT_RESOLUTION_DEPTH;
+
 #[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize, JsonSchema)]
 pub struct ResolutionMetadata {
     pub depth: u32,
     pub datastore_query_count: u32,
+    /// allowed because the user is a superadmin of the tenant, the relation was not resolved
+    #[serde(default)]
+    pub superadmin: bool,
+    /// a branch was cut because it led back to a tuple being resolved, the answer may differ on another path
+    #[serde(skip)]
+    pub cycle: bool,
 }
 
 impl Default for ResolutionMetadata {
     fn default() -> Self {
         Self {
-            depth: 25,
+            depth: DEFAULT_RESOLUTION_DEPTH,
             datastore_query_count: 0,
+            superadmin: false,
+            cycle: false,
         }
     }
 }
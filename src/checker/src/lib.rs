@@ -2,9 +2,10 @@ pub mod cache_checker;
 pub mod expander;
 pub mod local_checker;
 pub mod remote_checker;
+pub mod singleflight_checker;
 mod stream;
 use async_trait::async_trait;
-use futures::Future;
+use futures::{Future, StreamExt};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -17,15 +18,37 @@ extern crate tracing;
 #[cfg(test)]
 mod tests;
 
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+};
 
 use anyhow::Result;
-use graph::ResolutionMetadata;
-use protocol::{TupleKey, Typesystem};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use error::CheckerError;
+use protocol::{RelationReference, Tuple, TupleKey, Typesystem};
+use schema::Condition;
+use storage::TupleFilter;
+use tokio::sync::{Semaphore, SemaphorePermit};
 
 pub use cache_checker::CacheChecker;
+pub use graph::ResolutionMetadata;
 pub use local_checker::LocalChecker;
 pub use remote_checker::RemoteChecker;
+pub use singleflight_checker::SingleflightChecker;
+
+/// How many datastore queries a check has in flight at the same time, counting those of the requests it dispatches.
+/// Each union or intersection also resolves at most that many of its children at the same time.
+pub const DEFAULT_CONCURRENCY_LIMIT: usize = 10;
+
+/// How many check results the cache keeps, unless the server configures otherwise.
+pub const DEFAULT_CACHE_CAPACITY: u64 = 100;
+
+/// How many times a check may dispatch before it fails as too complex, unless the server or the request lowers it.
+pub const DEFAULT_RESOLUTION_DEPTH: u32 = 25;
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct CheckRequest {
@@ -33,10 +56,124 @@ pub struct CheckRequest {
     pub model_id: String,
 
     pub typesystem: Typesystem,
+    /// conditions of the model, keyed by name
+    pub conditions: HashMap<String, Condition>,
     pub tuple_key: TupleKey,
     pub contextual_tuples: Vec<TupleKey>,
+    /// values for condition parameters, merged with the context stored on each tuple
+    pub context: HashMap<String, serde_json::Value>,
     pub resolution_metadata: ResolutionMetadata,
     pub visited_paths: HashSet<String>,
+    /// the superadmins of the model were already looked up for the user, set on the requests spawned by a check
+    pub superadmin_checked: bool,
+    /// results shared with other checks, such as the other items of a batch
+    pub memo: Option<Memo>,
+    /// the datastore queries of the check the request belongs to, set by the checker the check starts on
+    pub reads: Option<Reads>,
+}
+
+impl CheckRequest {
+    /// Identifies what the request asks, requests with the same key have the same answer.
+    pub fn cache_key(&self) -> String {
+        let mut contextual_tuples_cache_key = String::new();
+        for tk in &self.contextual_tuples {
+            let key = format!("/{}", tk.cache_key());
+            contextual_tuples_cache_key.push_str(key.as_str());
+        }
+        // sort the context so that equal contexts produce equal keys
+        let context: BTreeMap<_, _> = self.context.iter().collect();
+        let context_cache_key = if context.is_empty() {
+            String::new()
+        } else {
+            format!("/{}", serde_json::to_string(&context).unwrap_or_default())
+        };
+        STANDARD.encode(format!(
+            "{}/{}/{}{}{}",
+            self.tenant_id,
+            self.model_id,
+            self.tuple_key.cache_key(),
+            contextual_tuples_cache_key,
+            context_cache_key
+        ))
+    }
+}
+
+/// The results of the requests resolved by the checks sharing it, keyed by `CheckRequest::cache_key`, so that the
+/// sub-problems they have in common are resolved once. It lives as long as the checks, nothing evicts it.
+#[derive(Debug, Clone, Default)]
+pub struct Memo(Arc<Mutex<HashMap<String, CheckResult>>>);
+
+impl Memo {
+    fn get(&self, key: &str) -> Option<CheckResult> {
+        self.0.lock().unwrap().get(key).copied()
+    }
+
+    fn insert(&self, key: String, result: CheckResult) {
+        self.0.lock().unwrap().insert(key, result);
+    }
+}
+
+impl PartialEq for Memo {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The datastore queries of one check and of the requests it dispatches: at most `limit` are in flight at the same
+/// time, and each one is counted, even when it belongs to a child dropped once its union or intersection was decided.
+#[derive(Debug, Clone)]
+pub struct Reads {
+    permits: Arc<Semaphore>,
+    count: Arc<AtomicU32>,
+}
+
+impl Reads {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(limit.max(1))),
+            count: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    /// Waits until another query may be in flight, the query is counted right away.
+    async fn acquire(&self) -> SemaphorePermit<'_> {
+        // the semaphore is never closed
+        let permit = self.permits.acquire().await.unwrap();
+        self.add(1);
+        permit
+    }
+
+    /// Counts the queries made elsewhere on behalf of the check, such as by a remote checker.
+    fn add(&self, count: u32) {
+        self.count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn count(&self) -> u32 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for Reads {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.count, &other.count)
+    }
+}
+
+/// Per tenant counters bumped by every write of the tenant. The checkers that remember or coalesce checks put the
+/// generation of the tenant in their keys, so that a check made after a write is not answered with what was read
+/// before it.
+#[derive(Debug, Clone, Default)]
+pub struct Generations(Arc<Mutex<HashMap<String, u64>>>);
+
+impl Generations {
+    pub fn get(&self, tenant_id: &str) -> u64 {
+        self.0.lock().unwrap().get(tenant_id).copied().unwrap_or_default()
+    }
+
+    /// Call it once a write of the tuples or of the models of the tenant succeeded.
+    pub fn bump(&self, tenant_id: &str) {
+        *self.0.lock().unwrap().entry(String::from(tenant_id)).or_default() += 1;
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize, JsonSchema, Default)]
@@ -60,6 +197,10 @@ impl CheckResult {
             },
         }
     }
+    fn with_cycle(mut self, cycle: bool) -> CheckResult {
+        self.resolution_metadata.cycle = cycle;
+        self
+    }
 }
 
 #[async_trait]
@@ -73,39 +214,136 @@ pub trait Checker: Send + Sync {
 
 pub type CheckerRef = Arc<dyn Checker>;
 
-async fn union_check<F>(count: usize, f: impl Fn(usize) -> F) -> Result<CheckResult>
+/// Tuples on the system objects of the superadmins of the model, narrowed to a user by the caller. The model must
+/// declare superadmins, an empty `or` would match any relation.
+fn superadmin_filter(typesystem: &Typesystem) -> TupleFilter {
+    TupleFilter {
+        or: Some(
+            typesystem
+                .superadmins
+                .iter()
+                .map(|s| TupleFilter {
+                    object_type_eq: Some(String::from(&s.object)),
+                    relation_eq: Some(String::from(&s.relation)),
+                    ..Default::default()
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+/// Keeps the tuples related through one of the references whose condition, either stored on the tuple or required by
+/// the reference (`user with cond`), holds for the context.
+fn filter_by_condition(
+    conditions: &HashMap<String, Condition>,
+    context: &HashMap<String, serde_json::Value>,
+    tuples: Vec<Tuple>,
+    references: &[RelationReference],
+) -> Result<Vec<Tuple>> {
+    let mut result = Vec::with_capacity(tuples.len());
+    for t in tuples {
+        let mut allow = false;
+        for reference in references.iter().filter(|r| r.matches(&t)) {
+            let condition = match (t.condition.as_deref(), reference.condition()) {
+                (Some(stored), Some(required)) if !stored.eq(required) => continue,
+                (stored, required) => stored.or(required),
+            };
+            allow = match condition {
+                Some(name) => evaluate_condition(conditions, context, name, &t)?,
+                None => true,
+            };
+            if allow {
+                break;
+            }
+        }
+        if allow {
+            result.push(t);
+        }
+    }
+    Ok(result)
+}
+
+fn evaluate_condition(
+    conditions: &HashMap<String, Condition>,
+    context: &HashMap<String, serde_json::Value>,
+    name: &str,
+    t: &Tuple,
+) -> Result<bool> {
+    let condition = conditions
+        .get(name)
+        .ok_or_else(|| CheckerError::NotFoundCondition(String::from(name)))?;
+    let mut context = context.clone();
+    if let Some(tuple_context) = &t.context {
+        context.extend(tuple_context.iter().map(|(k, v)| (k.to_owned(), v.to_owned())));
+    }
+    let allow = condition
+        .evaluate(&context)
+        .map_err(|source| CheckerError::ConditionEvaluation {
+            name: String::from(name),
+            source,
+        })?;
+    trace!("condition {} of tuple {:?} evaluates to {}", name, t, allow);
+    Ok(allow)
+}
+
+/// Resolves the children at most `limit` at a time, the first that allows decides and the others are dropped.
+async fn union_check<F>(count: usize, limit: usize, f: impl Fn(usize) -> F) -> Result<CheckResult>
 where
     F: Future<Output = Result<CheckResult>>,
 {
+    let mut results = futures::stream::iter((0..count).map(f)).buffer_unordered(limit.max(1));
     let mut db_read = 0u32;
-    for i in 0..count {
-        let result = f(i).await;
-        if let Ok(cr) = result {
-            db_read += cr.resolution_metadata.datastore_query_count;
-            if cr.allow {
-                return result;
+    let mut cycle = false;
+    let mut error = None;
+    while let Some(result) = results.next().await {
+        match result {
+            Ok(cr) if cr.allow => {
+                return Ok(
+                    CheckResult::new_dqc(true, db_read + cr.resolution_metadata.datastore_query_count)
+                        .with_cycle(cr.resolution_metadata.cycle),
+                )
+            }
+            Ok(cr) => {
+                db_read += cr.resolution_metadata.datastore_query_count;
+                cycle |= cr.resolution_metadata.cycle;
             }
+            // another child may still allow
+            Err(err) => error = error.or(Some(err)),
         }
     }
-    Ok(CheckResult::new_dqc(false, db_read))
+    match error {
+        Some(err) => Err(err),
+        None => Ok(CheckResult::new_dqc(false, db_read).with_cycle(cycle)),
+    }
 }
 
-async fn intersection_check<F>(count: usize, f: impl Fn(usize) -> F) -> Result<CheckResult>
+/// Resolves the children at most `limit` at a time, the first that denies decides and the others are dropped.
+async fn intersection_check<F>(count: usize, limit: usize, f: impl Fn(usize) -> F) -> Result<CheckResult>
 where
     F: Future<Output = Result<CheckResult>>,
 {
+    let mut results = futures::stream::iter((0..count).map(f)).buffer_unordered(limit.max(1));
     let mut db_read = 0u32;
-    for i in 0..count {
-        let result = f(i).await;
-        if let Ok(res) = result {
-            db_read += res.resolution_metadata.datastore_query_count;
-            if !res.allow {
-                return Ok(CheckResult::new_dqc(false, db_read));
+    let mut cycle = false;
+    let mut error = None;
+    while let Some(result) = results.next().await {
+        match result {
+            Ok(res) => {
+                db_read += res.resolution_metadata.datastore_query_count;
+                if !res.allow {
+                    return Ok(CheckResult::new_dqc(false, db_read).with_cycle(res.resolution_metadata.cycle));
+                }
+                cycle |= res.resolution_metadata.cycle;
             }
+            // another child may still deny
+            Err(err) => error = error.or(Some(err)),
         }
     }
-
-    Ok(CheckResult::new_dqc(true, db_read))
+    match error {
+        Some(err) => Err(err),
+        None => Ok(CheckResult::new_dqc(true, db_read).with_cycle(cycle)),
+    }
 }
 
 async fn exclusion_check<F>(base: F, subtract: F) -> Result<CheckResult>
@@ -116,13 +354,14 @@ where
     let base_result = base.await?;
     db_read += base_result.resolution_metadata.datastore_query_count;
     if !base_result.allow {
-        return Ok(CheckResult::new_dqc(false, db_read));
+        return Ok(CheckResult::new_dqc(false, db_read).with_cycle(base_result.resolution_metadata.cycle));
     }
 
     let subtract_result = subtract.await?;
     db_read += subtract_result.resolution_metadata.datastore_query_count;
-    if subtract_result.allow {
-        return Ok(CheckResult::new_dqc(false, db_read));
+    // a subtract denied after cutting a cycle is undecided, it excludes rather than grants
+    if subtract_result.allow || subtract_result.resolution_metadata.cycle {
+        return Ok(CheckResult::new_dqc(false, db_read).with_cycle(subtract_result.resolution_metadata.cycle));
     }
-    Ok(CheckResult::new_dqc(true, db_read))
+    Ok(CheckResult::new_dqc(true, db_read).with_cycle(base_result.resolution_metadata.cycle))
 }
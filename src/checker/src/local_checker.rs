@@ -1,33 +1,64 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use futures::{future::BoxFuture, FutureExt};
-use protocol::{RelationReference, SetOperator, TupleKey, TupleToUserset, Userset, WILDCARD};
-use storage::{RelationshipTupleReaderRef, TupleFilter};
+use protocol::{SetOperator, Subject, Tuple, TupleKey, TupleToUserset, Userset, WILDCARD};
+use storage::{merge_contextual, RelationshipTupleReaderRef, TupleFilter};
 use tracing::Instrument;
 
 use crate::{
-    error::CheckerError, exclusion_check, graph::ResolutionMetadata, intersection_check, union_check, CheckRequest,
-    CheckResult, Checker, CheckerRef,
+    error::CheckerError, exclusion_check, filter_by_condition, graph::ResolutionMetadata, intersection_check,
+    superadmin_filter, union_check, CheckRequest, CheckResult, Checker, CheckerRef, Reads, DEFAULT_CONCURRENCY_LIMIT,
 };
 
 pub struct LocalChecker {
     resolver: Option<CheckerRef>,
     tuple_reader: RelationshipTupleReaderRef,
+    concurrency_limit: usize,
 }
 
 #[async_trait]
 impl Checker for LocalChecker {
-    async fn check(&self, req: CheckRequest) -> Result<CheckResult> {
+    async fn check(&self, mut req: CheckRequest) -> Result<CheckResult> {
+        if req.reads.is_none() {
+            // the check starts here, it counts the queries of every request it dispatches, including those of the
+            // children dropped by a decided union or intersection
+            let reads = Reads::new(self.concurrency_limit);
+            req.reads = Some(reads.clone());
+            let mut result = self.check(req).await?;
+            result.resolution_metadata.datastore_query_count = reads.count();
+            return Ok(result);
+        }
         let span = info_span!("local-checker");
         let _enter = span.enter();
         trace!("tuple is {}, model id is {}", &req.tuple_key, &req.model_id);
-        let relation = req
-            .typesystem
-            .get_relation(&req.tuple_key.object_type, &req.tuple_key.relation)?;
-
-        self.check_rewrite(&req, &relation.rewrite)
-            .instrument(span.clone())
-            .await
+        if req.resolution_metadata.depth == 0 {
+            return Err(CheckerError::ResolutionTooComplex.into());
+        }
+        // the tuple is already being resolved on this path, following it again can only loop
+        if !req.visited_paths.insert(req.tuple_key.to_string()) {
+            trace!("cycle on {}", &req.tuple_key);
+            return Ok(CheckResult::new_dqc(false, req.resolution_metadata.datastore_query_count).with_cycle(true));
+        }
+        let Some(memo) = req.memo.clone() else {
+            return self.resolve(req).instrument(span.clone()).await;
+        };
+        let key = req.cache_key();
+        if let Some(result) = memo.get(&key) {
+            trace!("memo hit on {}", &req.tuple_key);
+            return Ok(CheckResult {
+                resolution_metadata: ResolutionMetadata {
+                    datastore_query_count: req.resolution_metadata.datastore_query_count,
+                    ..result.resolution_metadata
+                },
+                ..result
+            });
+        }
+        let result = self.resolve(req).instrument(span.clone()).await?;
+        // a cut branch depends on the path the request was reached by, another path may resolve it
+        if !result.resolution_metadata.cycle {
+            memo.insert(key, result);
+        }
+        Ok(result)
     }
 
     async fn close(&self) {}
@@ -39,7 +70,51 @@ impl Checker for LocalChecker {
 
 impl LocalChecker {
     pub fn new(resolver: Option<CheckerRef>, tuple_reader: RelationshipTupleReaderRef) -> Self {
-        Self { resolver, tuple_reader }
+        Self {
+            resolver,
+            tuple_reader,
+            concurrency_limit: DEFAULT_CONCURRENCY_LIMIT,
+        }
+    }
+
+    /// Keeps at most `limit` datastore queries of a check in flight, and resolves at most `limit` children of a union
+    /// or an intersection at the same time.
+    pub fn with_concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit = limit;
+        self
+    }
+
+    /// Resolves the relation of the request, after its depth and its path were checked.
+    async fn resolve(&self, mut req: CheckRequest) -> Result<CheckResult> {
+        let span = info_span!("resolve");
+        let _enter = span.enter();
+        let relation = req
+            .typesystem
+            .get_relation(&req.tuple_key.object_type, &req.tuple_key.relation)?;
+
+        if !req.superadmin_checked && !req.typesystem.superadmins.is_empty() {
+            let datastore_query_count = req.resolution_metadata.datastore_query_count + 1;
+            if self.check_superadmin(&req).instrument(span.clone()).await? {
+                return Ok(CheckResult {
+                    allow: true,
+                    resolution_metadata: ResolutionMetadata {
+                        datastore_query_count,
+                        superadmin: true,
+                        ..Default::default()
+                    },
+                });
+            }
+            req.resolution_metadata.datastore_query_count = datastore_query_count;
+            req.superadmin_checked = true;
+        }
+
+        let mut result = self
+            .check_rewrite(&req, &relation.rewrite)
+            .instrument(span.clone())
+            .await?;
+        // the rewrite counts only the queries it made
+        result.resolution_metadata.datastore_query_count += req.resolution_metadata.datastore_query_count;
+        Ok(result)
     }
 
     async fn check_rewrite(&self, req: &CheckRequest, rewrite: &Userset) -> Result<CheckResult> {
@@ -53,11 +128,51 @@ impl LocalChecker {
             }
             Userset::Difference { base, subtract } => {
                 let children = vec![base.to_owned(), subtract.to_owned()];
-                self.check_set_operation(req, SetOperator::Intersection, &children)
-                    .await
+                self.check_set_operation(req, SetOperator::Exclusion, &children).await
             }
         }
     }
+
+    /// The stored tuples selected by the filter along with the contextual tuples of the request it selects.
+    async fn list_tuples(&self, req: &CheckRequest, filter: TupleFilter) -> Result<Vec<Tuple>> {
+        let permit = match &req.reads {
+            Some(reads) => Some(reads.acquire().await),
+            None => None,
+        };
+        let (mut tuples, _) = self
+            .tuple_reader
+            .clone()
+            .list(&req.tenant_id, filter.clone(), None)
+            .await?;
+        drop(permit);
+        if !req.contextual_tuples.is_empty() {
+            let contextual: Vec<Tuple> = req.contextual_tuples.iter().cloned().map(Tuple::from).collect();
+            merge_contextual(&mut tuples, &contextual, &filter);
+        }
+        Ok(tuples)
+    }
+
+    /// Whether the user is related to a system object by one of the superadmin relations of the model.
+    async fn check_superadmin(&self, req: &CheckRequest) -> Result<bool> {
+        let user_relation = &req.tuple_key.user_relation;
+        let filter = TupleFilter {
+            user_type_eq: Some(String::from(&req.tuple_key.user_type)),
+            user_id_eq: Some(String::from(&req.tuple_key.user_id)),
+            user_relation_eq: (!user_relation.is_empty()).then(|| String::from(user_relation)),
+            user_relation_is_null: user_relation.is_empty().then_some(true),
+            ..superadmin_filter(&req.typesystem)
+        };
+        let tuples = self.list_tuples(req, filter).await?;
+        for t in tuples {
+            let references = req.typesystem.get_directly_related_types(&t.object_type, &t.relation)?;
+            if !filter_by_condition(&req.conditions, &req.context, vec![t], &references)?.is_empty() {
+                trace!("{} is a superadmin", &req.tuple_key);
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     async fn check_direct(&self, req: &CheckRequest) -> Result<CheckResult> {
         let span = info_span!("direct-check");
         let _enter = span.enter();
@@ -83,8 +198,8 @@ impl LocalChecker {
         };
         let or_filter: Vec<TupleFilter> = related_usersets
             .iter()
-            .filter_map(|ru| match ru {
-                RelationReference::Direct(typ) => {
+            .filter_map(|ru| match ru.subject() {
+                Subject::Direct(typ) => {
                     if typ.eq(&req.tuple_key.user_type) {
                         Some(TupleFilter {
                             user_type_eq: Some(String::from(&req.tuple_key.user_type)),
@@ -95,7 +210,7 @@ impl LocalChecker {
                         None
                     }
                 }
-                RelationReference::Relation { r#type, relation } => {
+                Subject::Relation { r#type, relation } => {
                     if !r#type.eq(&req.tuple_key.user_type) {
                         Some(TupleFilter {
                             user_type_eq: Some(String::from(r#type)),
@@ -106,11 +221,11 @@ impl LocalChecker {
                         None
                     }
                 }
-                RelationReference::Wildcard(typ) => {
+                Subject::Wildcard(typ) => {
                     if typ.eq(&req.tuple_key.user_type) {
                         Some(TupleFilter {
                             user_type_eq: Some(String::from(&req.tuple_key.user_type)),
-                            user_id_eq: Some(String::from(&req.tuple_key.user_id)),
+                            user_id_eq: Some(String::from(WILDCARD)),
                             ..Default::default()
                         })
                     } else {
@@ -123,24 +238,16 @@ impl LocalChecker {
             filter.or = Some(or_filter);
         }
 
-        let (tuples, _) = self
-            .tuple_reader
-            .clone()
-            .list(&req.tenant_id, filter, None)
-            .instrument(span.clone())
-            .await?;
+        let tuples = self.list_tuples(req, filter).instrument(span.clone()).await?;
+        let tuples = filter_by_condition(&req.conditions, &req.context, tuples, &related_usersets)?;
 
         let _enter = span.enter();
         trace!("tuples: {:?}", tuples);
 
         if tuples.is_empty() {
-            return Ok(CheckResult::new_dqc(
-                false,
-                req.resolution_metadata.datastore_query_count + 1,
-            ));
+            return Ok(CheckResult::new_dqc(false, 1));
         }
 
-        // TODO concurrence
         let direct_asserts: Vec<bool> = tuples
             .iter()
             .filter_map(|t| {
@@ -159,18 +266,16 @@ impl LocalChecker {
         if !direct_asserts.is_empty() {
             let allow = direct_asserts.iter().any(|x| x.to_owned());
             trace!("direct_asserts present, allow is {}", allow);
-            return Ok(CheckResult::new_dqc(
-                allow,
-                req.resolution_metadata.datastore_query_count + 1,
-            ));
+            return Ok(CheckResult::new_dqc(allow, 1));
         }
         let handlers: Vec<_> = tuples
             .iter()
-            .filter(|t| !(t.user_type.eq(&req.tuple_key.user_type) || matches!(t.user_relation, None)))
+            .filter(|t| !(t.user_type.eq(&req.tuple_key.user_type) || t.user_relation.is_none()))
             .map(move |t| CheckRequest {
                 tenant_id: req.tenant_id.to_owned(),
                 model_id: req.model_id.to_owned(),
                 typesystem: req.typesystem.clone(),
+                conditions: req.conditions.clone(),
                 tuple_key: TupleKey {
                     user_type: String::from(&req.tuple_key.user_type),
                     user_id: String::from(&req.tuple_key.user_id),
@@ -178,13 +283,18 @@ impl LocalChecker {
                     relation: String::from(t.user_relation.as_ref().unwrap()),
                     object_type: String::from(&t.user_type),
                     object_id: String::from(&t.user_id),
+                    ..Default::default()
                 },
                 contextual_tuples: req.contextual_tuples.clone(),
+                context: req.context.clone(),
                 resolution_metadata: ResolutionMetadata {
-                    depth: req.resolution_metadata.depth,
-                    datastore_query_count: req.resolution_metadata.datastore_query_count,
+                    depth: req.resolution_metadata.depth - 1,
+                    ..Default::default()
                 },
                 visited_paths: req.visited_paths.clone(),
+                superadmin_checked: true,
+                memo: req.memo.clone(),
+                reads: req.reads.clone(),
             })
             .collect();
 
@@ -193,18 +303,25 @@ impl LocalChecker {
             handlers.iter().map(|x| &x.tuple_key).collect::<Vec<_>>()
         );
 
-        if let Some(r) = self.resolver.clone() {
+        let mut result = if let Some(r) = self.resolver.clone() {
             let r = r.clone();
             trace!("use {} checker", r.name());
-            union_check(handlers.len(), |i| r.check(handlers.get(i).unwrap().to_owned()))
-                .instrument(span.clone())
-                .await
+            union_check(handlers.len(), self.concurrency_limit, |i| {
+                r.check(handlers.get(i).unwrap().to_owned())
+            })
+            .instrument(span.clone())
+            .await?
         } else {
             trace!("use {} checker", self.name());
-            union_check(handlers.len(), |i| self.check(handlers.get(i).unwrap().to_owned()))
-                .instrument(span.clone())
-                .await
-        }
+            union_check(handlers.len(), self.concurrency_limit, |i| {
+                self.check(handlers.get(i).unwrap().to_owned())
+            })
+            .instrument(span.clone())
+            .await?
+        };
+        // the tuples read before dispatching
+        result.resolution_metadata.datastore_query_count += 1;
+        Ok(result)
     }
 
     async fn check_computed(&self, req: &CheckRequest, relation: &str) -> Result<CheckResult> {
@@ -214,6 +331,7 @@ impl LocalChecker {
             tenant_id: req.tenant_id.to_owned(),
             model_id: req.model_id.to_owned(),
             typesystem: req.typesystem.clone(),
+            conditions: req.conditions.clone(),
             tuple_key: TupleKey {
                 user_type: String::from(&req.tuple_key.user_type),
                 user_id: String::from(&req.tuple_key.user_id),
@@ -221,13 +339,18 @@ impl LocalChecker {
                 relation: String::from(relation),
                 object_type: String::from(&req.tuple_key.object_type),
                 object_id: String::from(&req.tuple_key.object_id),
+                ..Default::default()
             },
             contextual_tuples: req.contextual_tuples.clone(),
+            context: req.context.clone(),
             resolution_metadata: ResolutionMetadata {
-                depth: req.resolution_metadata.depth,
-                datastore_query_count: req.resolution_metadata.datastore_query_count,
+                depth: req.resolution_metadata.depth - 1,
+                ..Default::default()
             },
             visited_paths: req.visited_paths.clone(),
+            superadmin_checked: true,
+            memo: req.memo.clone(),
+            reads: req.reads.clone(),
         };
         if let Some(r) = self.resolver.clone() {
             trace!("use {} checker", r.name());
@@ -246,55 +369,79 @@ impl LocalChecker {
             relation_eq: Some(String::from(&ttu.tupleset.relation)),
             ..Default::default()
         };
-        let (tuples, _) = self.tuple_reader.clone().list(&req.tenant_id, filter, None).await?;
+        let tuples = self.list_tuples(req, filter).await?;
+        let tupleset_types = req
+            .typesystem
+            .get_directly_related_types(&req.tuple_key.object_type, &ttu.tupleset.relation)?;
+        let tuples = filter_by_condition(&req.conditions, &req.context, tuples, &tupleset_types)?;
 
         let handlers: Vec<_> = tuples
             .iter()
             .filter_map(|t| {
-                if t.user_type.eq(&req.tuple_key.user_type) || matches!(t.user_relation, None) {
+                // the tupleset relates objects, the computed relation is resolved on each of them when they have it
+                let computed = &ttu.computed_userset.relation;
+                let has_computed = req
+                    .typesystem
+                    .types
+                    .get(&t.user_type)
+                    .is_some_and(|typ| typ.relations.contains_key(computed));
+                if t.user_relation.is_some() || !has_computed {
                     return None;
                 }
                 Some(CheckRequest {
                     tenant_id: req.tenant_id.to_owned(),
                     model_id: req.model_id.to_owned(),
                     typesystem: req.typesystem.clone(),
+                    conditions: req.conditions.clone(),
                     tuple_key: TupleKey {
-                        user_type: String::from(&t.user_type),
-                        user_id: String::from(&t.user_id),
-                        user_relation: String::from(""),
-                        relation: String::from(&ttu.computed_userset.relation),
+                        user_type: String::from(&req.tuple_key.user_type),
+                        user_id: String::from(&req.tuple_key.user_id),
+                        user_relation: String::from(&req.tuple_key.user_relation),
+                        relation: String::from(computed),
                         object_type: String::from(&t.user_type),
                         object_id: String::from(&t.user_id),
+                        ..Default::default()
                     },
                     contextual_tuples: req.contextual_tuples.clone(),
+                    context: req.context.clone(),
                     resolution_metadata: ResolutionMetadata {
-                        depth: req.resolution_metadata.depth,
-                        datastore_query_count: req.resolution_metadata.datastore_query_count,
+                        depth: req.resolution_metadata.depth - 1,
+                        ..Default::default()
                     },
                     visited_paths: req.visited_paths.clone(),
+                    superadmin_checked: true,
+                    memo: req.memo.clone(),
+                    reads: req.reads.clone(),
                 })
             })
             .collect();
 
-        if let Some(r) = self.resolver.clone() {
+        let mut result = if let Some(r) = self.resolver.clone() {
             let r = r.clone();
             trace!("use {} checker", r.name());
-            union_check(handlers.len(), |i| r.check(handlers.get(i).unwrap().to_owned()))
-                .instrument(span.clone())
-                .await
+            union_check(handlers.len(), self.concurrency_limit, |i| {
+                r.check(handlers.get(i).unwrap().to_owned())
+            })
+            .instrument(span.clone())
+            .await?
         } else {
             trace!("use {} checker", self.name());
-            union_check(handlers.len(), |i| self.check(handlers.get(i).unwrap().to_owned()))
-                .instrument(span.clone())
-                .await
-        }
+            union_check(handlers.len(), self.concurrency_limit, |i| {
+                self.check(handlers.get(i).unwrap().to_owned())
+            })
+            .instrument(span.clone())
+            .await?
+        };
+        // the tuples read before dispatching
+        result.resolution_metadata.datastore_query_count += 1;
+        Ok(result)
     }
 
     fn check_set_operation<'a, 'b>(
         &'a self,
         req: &'b CheckRequest,
         operator: SetOperator,
-        children: &'b Vec<Box<Userset>>,
+        children: &'b [Box<Userset>],
     ) -> BoxFuture<'b, Result<CheckResult>>
     where
         'a: 'b,
@@ -302,15 +449,21 @@ impl LocalChecker {
         async move {
             match operator {
                 SetOperator::Union => {
-                    union_check(children.len(), |i| self.check_rewrite(&req, children.get(i).unwrap())).await
+                    union_check(children.len(), self.concurrency_limit, |i| {
+                        self.check_rewrite(req, children.get(i).unwrap())
+                    })
+                    .await
                 }
                 SetOperator::Intersection => {
-                    intersection_check(children.len(), |i| self.check_rewrite(&req, children.get(i).unwrap())).await
+                    intersection_check(children.len(), self.concurrency_limit, |i| {
+                        self.check_rewrite(req, children.get(i).unwrap())
+                    })
+                    .await
                 }
                 SetOperator::Exclusion => {
                     exclusion_check(
-                        self.check_rewrite(&req, children.get(0).unwrap()),
-                        self.check_rewrite(&req, children.get(1).unwrap()),
+                        self.check_rewrite(req, children.first().unwrap()),
+                        self.check_rewrite(req, children.get(1).unwrap()),
                     )
                     .await
                 }
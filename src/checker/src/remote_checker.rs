@@ -28,6 +28,7 @@ impl RemoteChecker {
 #[async_trait]
 impl Checker for RemoteChecker {
     async fn check(&self, req: CheckRequest) -> anyhow::Result<CheckResult> {
+        let reads = req.reads.clone();
         let contextual_tuples = req
             .contextual_tuples
             .into_iter()
@@ -38,6 +39,8 @@ impl Checker for RemoteChecker {
                 relation: ct.relation,
                 object_type: ct.object_type,
                 object_id: ct.object_id,
+                condition: ct.condition,
+                context: ct.context.map(proto::json_to_struct),
             })
             .collect();
         let mut client = self.client.clone();
@@ -52,8 +55,12 @@ impl Checker for RemoteChecker {
                     relation: req.tuple_key.relation,
                     object_type: req.tuple_key.object_type,
                     object_id: req.tuple_key.object_id,
+                    condition: req.tuple_key.condition,
+                    context: req.tuple_key.context.map(proto::json_to_struct),
                 }),
                 contextual_tuples,
+                context: Some(proto::json_to_struct(req.context)),
+                resolution_depth: Some(req.resolution_metadata.depth),
             })
             .await?;
         let result = reply.into_inner();
@@ -62,8 +69,14 @@ impl Checker for RemoteChecker {
             .map(|rm| ResolutionMetadata {
                 depth: rm.depth,
                 datastore_query_count: rm.datastore_query_count,
+                superadmin: rm.superadmin,
+                ..Default::default()
             })
             .unwrap_or_default();
+        // the remote node made the queries, they still belong to the check that dispatched the request
+        if let Some(reads) = reads {
+            reads.add(resolution_metadata.datastore_query_count);
+        }
         Ok(CheckResult {
             allow: result.allow,
             resolution_metadata,
@@ -0,0 +1,216 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, Weak,
+    },
+};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures::{
+    future::{BoxFuture, Shared, WeakShared},
+    FutureExt,
+};
+use storage::RelationshipTupleReaderRef;
+use tracing::Instrument;
+
+use crate::{error::CheckerError, CheckRequest, CheckResult, Checker, CheckerRef, Generations, LocalChecker};
+
+/// The error of a flight, cloned to every request waiting on it.
+#[derive(Debug, Clone)]
+enum FlightError {
+    Checker(CheckerError),
+    Other(String),
+}
+
+impl From<anyhow::Error> for FlightError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<CheckerError>() {
+            Ok(err) => FlightError::Checker(err),
+            Err(err) => FlightError::Other(err.to_string()),
+        }
+    }
+}
+
+impl From<FlightError> for anyhow::Error {
+    fn from(err: FlightError) -> Self {
+        match err {
+            FlightError::Checker(err) => err.into(),
+            FlightError::Other(msg) => anyhow!(msg),
+        }
+    }
+}
+
+type FlightFuture = BoxFuture<'static, Result<CheckResult, FlightError>>;
+type Flight = Shared<FlightFuture>;
+
+/// A request in flight, with the path of the request leading it. The waiters own the flight, it is dropped along with
+/// the last of them instead of being parked here.
+struct InFlight {
+    id: u64,
+    flight: WeakShared<FlightFuture>,
+    visited_paths: HashSet<String>,
+}
+
+type Flights = Arc<Mutex<HashMap<String, InFlight>>>;
+
+/// Owned by the leader of a flight, takes the flight out of the in-flight ones when the leader is done with it, be it
+/// landed, dropped or panicked.
+struct Landing {
+    flights: Flights,
+    key: String,
+    id: u64,
+}
+
+impl Drop for Landing {
+    fn drop(&mut self) {
+        let mut flights = self.flights.lock().unwrap_or_else(|err| err.into_inner());
+        if flights.get(&self.key).is_some_and(|in_flight| in_flight.id == self.id) {
+            flights.remove(&self.key);
+        }
+    }
+}
+
+/// Coalesces the identical requests checked at the same time, keyed by `CheckRequest::cache_key` and the generation of
+/// the tenant, onto the one that came first, and hands its result to all of them.
+///
+/// A request only joins a flight whose path covers its own, the flight cuts the cycles back to the request then and
+/// can't wait on it. A result that was cut, or that ran out of depth, depends on the path of the leader, the request
+/// resolves it again on its own.
+pub struct SingleflightChecker {
+    delegate: CheckerRef,
+    flights: Flights,
+    next_id: AtomicU64,
+    generations: Generations,
+}
+
+enum Join {
+    Lead(Flight, Landing),
+    Follow(Flight),
+    Bypass,
+}
+
+#[async_trait]
+impl Checker for SingleflightChecker {
+    async fn check(&self, req: CheckRequest) -> Result<CheckResult> {
+        let span = trace_span!("singleflight-checker");
+        let key = format!("{}/{}", self.generations.get(&req.tenant_id), req.cache_key());
+        let join = {
+            let mut flights = self.flights.lock().unwrap();
+            let in_flight = flights
+                .get(&key)
+                .and_then(|in_flight| Some((in_flight.flight.upgrade()?, &in_flight.visited_paths)));
+            match in_flight {
+                Some((flight, visited_paths)) if req.visited_paths.is_subset(visited_paths) => Join::Follow(flight),
+                Some(_) => Join::Bypass,
+                None => {
+                    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                    let flight = self.flight(req.clone());
+                    flights.insert(
+                        key.clone(),
+                        InFlight {
+                            id,
+                            flight: flight.downgrade().unwrap(),
+                            visited_paths: req.visited_paths.clone(),
+                        },
+                    );
+                    let landing = Landing {
+                        flights: self.flights.clone(),
+                        key,
+                        id,
+                    };
+                    Join::Lead(flight, landing)
+                }
+            }
+        };
+        match join {
+            Join::Lead(flight, _landing) => Ok(flight.instrument(span).await?),
+            Join::Follow(flight) => {
+                trace!("join the flight of {}", &req.tuple_key);
+                match flight.instrument(span.clone()).await {
+                    Ok(result) if !result.resolution_metadata.cycle => Ok(result),
+                    Ok(_) | Err(FlightError::Checker(CheckerError::ResolutionTooComplex)) => {
+                        trace!("the flight of {} depends on its path, resolve it again", &req.tuple_key);
+                        self.delegate.check(req).instrument(span).await
+                    }
+                    Err(err) => Err(err.into()),
+                }
+            }
+            Join::Bypass => self.delegate.check(req).instrument(span).await,
+        }
+    }
+
+    async fn close(&self) {}
+
+    fn name(&self) -> &str {
+        "singleflight"
+    }
+}
+
+impl SingleflightChecker {
+    pub fn new(delegate: CheckerRef) -> Self {
+        Self {
+            delegate,
+            flights: Default::default(),
+            next_id: Default::default(),
+            generations: Default::default(),
+        }
+    }
+
+    /// Keys the flights by the generations too, a check made after a write then doesn't join a flight that may have
+    /// read before it.
+    pub fn with_generations(mut self, generations: Generations) -> Self {
+        self.generations = generations;
+        self
+    }
+
+    /// A local checker dispatching its sub-problems back through the singleflight, so that the checks sharing one
+    /// resolve it once.
+    pub fn new_local(
+        tuple_reader: RelationshipTupleReaderRef,
+        concurrency_limit: usize,
+        generations: Generations,
+    ) -> Arc<Self> {
+        Arc::new_cyclic(|me| {
+            let resolver: CheckerRef = Arc::new(Dispatcher(me.clone()));
+            Self::new(Arc::new(
+                LocalChecker::new(Some(resolver), tuple_reader).with_concurrency_limit(concurrency_limit),
+            ))
+            .with_generations(generations)
+        })
+    }
+
+    /// Resolves the request with the delegate, the flight owns both so that it can outlive its leader.
+    fn flight(&self, req: CheckRequest) -> Flight {
+        let delegate = self.delegate.clone();
+        async move { delegate.check(req).await.map_err(FlightError::from) }
+            .boxed()
+            .shared()
+    }
+
+    /// How many requests are in flight.
+    #[cfg(test)]
+    pub(crate) fn in_flight(&self) -> usize {
+        self.flights.lock().unwrap().len()
+    }
+}
+
+/// Hands the sub-problems of a local checker to the singleflight owning it.
+struct Dispatcher(Weak<SingleflightChecker>);
+
+#[async_trait]
+impl Checker for Dispatcher {
+    async fn check(&self, req: CheckRequest) -> Result<CheckResult> {
+        match self.0.upgrade() {
+            Some(checker) => checker.check(req).await,
+            None => Err(anyhow!("the singleflight checker was dropped")),
+        }
+    }
+
+    async fn close(&self) {}
+
+    fn name(&self) -> &str {
+        "singleflight"
+    }
+}
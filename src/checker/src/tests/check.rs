@@ -1,33 +1,50 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use protocol::TupleKey;
 use serde::{Deserialize, Serialize};
+use storage::RelationshipTupleReaderRef;
 
 use crate::{CheckRequest, CheckerRef, LocalChecker};
 
-use super::init;
+use super::{init, Model};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
-struct CheckCase {
+pub(super) struct CheckCase {
     tuple: TupleKey,
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
     allow: bool,
+    /// the check fails instead of answering
+    #[serde(default)]
+    error: bool,
 }
 
-#[tokio::test]
-async fn check_test() {
-    let (model, tuple_reader) = init().await;
-    let cases: Vec<CheckCase> = serde_json::from_str(include_str!("./check-cases.json")).unwrap();
-
-    let local_checker: CheckerRef = Arc::new(LocalChecker::new(None, tuple_reader.clone()));
+/// Checks every case with a local checker over the tuples of the reader.
+pub(super) async fn run_check_cases(model: &Model, tuple_reader: RelationshipTupleReaderRef, cases: Vec<CheckCase>) {
+    let local_checker: CheckerRef = Arc::new(LocalChecker::new(None, tuple_reader));
     for case in cases {
-        let model = model.clone();
+        let name = case.tuple.to_string();
         let req = CheckRequest {
             tenant_id: model.tenant_id.clone(),
             model_id: model.tenant_id.clone(),
             typesystem: model.typesystem.clone(),
+            conditions: model.conditions.clone(),
             tuple_key: case.tuple,
+            context: case.context,
             ..Default::default()
         };
-        assert_eq!(local_checker.check(req).await.unwrap_or_default().allow, case.allow);
+        let result = local_checker.check(req).await;
+        if case.error {
+            assert!(result.is_err(), "check {}", name);
+        } else {
+            assert_eq!(result.unwrap().allow, case.allow, "check {}", name);
+        }
     }
 }
+
+#[tokio::test]
+async fn check_test() {
+    let (model, tuple_reader) = init().await;
+    let cases: Vec<CheckCase> = serde_json::from_str(include_str!("./check-cases.json")).unwrap();
+    run_check_cases(&model, tuple_reader, cases).await;
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    check::{run_check_cases, CheckCase},
+    expand_objects::{self, run_expand_objects_cases},
+    expand_users::{self, run_expand_users_cases},
+    init_fixture,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+struct Cases {
+    check: Vec<CheckCase>,
+    objects: Vec<expand_objects::ExpandCase>,
+    users: Vec<expand_users::ExpandCase>,
+}
+
+#[tokio::test]
+async fn exclusion_test() {
+    let (model, storage) = init_fixture(1, include_str!("../fixtures/exclusion-tuples.json")).await;
+    let tuple_reader = Arc::new(storage);
+    let cases: Cases = serde_json::from_str(include_str!("./exclusion-cases.json")).unwrap();
+
+    run_check_cases(&model, tuple_reader.clone(), cases.check).await;
+    run_expand_objects_cases(&model, tuple_reader.clone(), cases.objects).await;
+    run_expand_users_cases(&model, tuple_reader, cases.users).await;
+}
@@ -1,10 +1,11 @@
-use std::{collections::HashSet, sync::Arc};
+use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
+use storage::RelationshipTupleReaderRef;
 
 use crate::expander::ObjectsExpander;
 
-use super::init;
+use super::{init, Model};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 struct Tuple {
@@ -16,33 +17,43 @@ struct Tuple {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
-struct ExpandCase {
+pub(super) struct ExpandCase {
     tuple: Tuple,
     object_ids: HashSet<String>,
 }
 
-#[tokio::test]
-async fn expand_objects_test() {
-    let (model, tuple_reader) = init().await;
-    let cases: Vec<ExpandCase> = serde_json::from_str(include_str!("./expand-objects.json")).unwrap();
-
-    let objects_expander = Arc::new(ObjectsExpander::new(tuple_reader.clone()));
+/// Lists the objects of every case over the tuples of the reader.
+pub(super) async fn run_expand_objects_cases(
+    model: &Model,
+    tuple_reader: RelationshipTupleReaderRef,
+    cases: Vec<ExpandCase>,
+) {
+    let objects_expander = ObjectsExpander::new(tuple_reader);
     for case in cases {
-        let model = model.clone();
+        let t = case.tuple;
+        let object_ids = objects_expander
+            .objects(
+                model.typesystem.clone(),
+                model.tenant_id.clone(),
+                t.relation.clone(),
+                t.object_type,
+                t.user_type,
+                t.user_id.clone(),
+                t.user_relation,
+            )
+            .await
+            .unwrap();
         assert_eq!(
-            objects_expander
-                .objects(
-                    model.typesystem.clone(),
-                    model.tenant_id,
-                    case.tuple.relation.clone(),
-                    case.tuple.object_type.clone(),
-                    case.tuple.user_type.clone(),
-                    case.tuple.user_id.clone(),
-                    case.tuple.user_relation.clone(),
-                )
-                .await
-                .unwrap(),
-            case.object_ids
+            object_ids, case.object_ids,
+            "objects {} of user {}",
+            t.relation, t.user_id
         );
     }
 }
+
+#[tokio::test]
+async fn expand_objects_test() {
+    let (model, tuple_reader) = init().await;
+    let cases: Vec<ExpandCase> = serde_json::from_str(include_str!("./expand-objects.json")).unwrap();
+    run_expand_objects_cases(&model, tuple_reader, cases).await;
+}
@@ -1,8 +1,12 @@
-use std::{collections::HashSet, sync::Arc};
+use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
+use storage::RelationshipTupleReaderRef;
 
-use crate::{expander::UsersExpander, tests::init};
+use crate::{
+    expander::UsersExpander,
+    tests::{init, Model},
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 struct Tuple {
@@ -14,33 +18,39 @@ struct Tuple {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
-struct ExpandCase {
+pub(super) struct ExpandCase {
     tuple: Tuple,
     user_ids: HashSet<String>,
 }
 
+/// Lists the users of every case over the tuples of the reader.
+pub(super) async fn run_expand_users_cases(
+    model: &Model,
+    tuple_reader: RelationshipTupleReaderRef,
+    cases: Vec<ExpandCase>,
+) {
+    let users_expander = UsersExpander::new(tuple_reader);
+    for case in cases {
+        let t = case.tuple;
+        let user_ids = users_expander
+            .users(
+                model.typesystem.clone(),
+                model.tenant_id.clone(),
+                t.relation.clone(),
+                t.object_type,
+                t.object_id,
+                t.user_type,
+                t.user_relation,
+            )
+            .await
+            .unwrap();
+        assert_eq!(user_ids, case.user_ids, "users {}", t.relation);
+    }
+}
+
 #[tokio::test]
 async fn expand_users_test() {
     let (model, tuple_reader) = init().await;
     let cases: Vec<ExpandCase> = serde_json::from_str(include_str!("./expand-users.json")).unwrap();
-
-    let users_expander = Arc::new(UsersExpander::new(tuple_reader.clone()));
-    for case in cases {
-        let model = model.clone();
-        assert_eq!(
-            users_expander
-                .users(
-                    model.typesystem.clone(),
-                    model.tenant_id,
-                    case.tuple.relation.clone(),
-                    case.tuple.object_type.clone(),
-                    case.tuple.object_id.clone(),
-                    case.tuple.user_type.clone(),
-                    case.tuple.user_relation.clone(),
-                )
-                .await
-                .unwrap(),
-            case.user_ids
-        );
-    }
+    run_expand_users_cases(&model, tuple_reader, cases).await;
 }
@@ -1,21 +1,42 @@
 mod check;
+mod exclusion;
 mod expand_objects;
 mod expand_users;
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::FutureExt;
 
-use protocol::Typesystem;
+use protocol::{Tuple, TupleKey, Typesystem};
 use sea_orm::{ConnectionTrait, Database, DbBackend, Schema};
 use serde::{Deserialize, Serialize};
 use storage::{
-    sea::{tuple::Entity as TupleEntity, Storage},
-    RelationshipTupleReaderRef, RelationshipTupleWriterRef,
+    sea::{authz_model::Entity as AuthzModelEntity, tuple::Entity as TupleEntity, Storage},
+    AuthzModelReader, AuthzModelWriterRef, Pagination, RelationshipTupleReader, RelationshipTupleReaderRef,
+    RelationshipTupleWriterRef, TupleFilter,
+};
+
+use crate::{
+    error::CheckerError,
+    expander::{ExpandTree, Expander, ObjectsExpander, UsersExpander},
+    intersection_check, union_check, CacheChecker, CheckRequest, CheckResult, Checker, CheckerRef, Generations,
+    LocalChecker, Memo, ResolutionMetadata, SingleflightChecker, DEFAULT_CONCURRENCY_LIMIT, DEFAULT_RESOLUTION_DEPTH,
 };
 
 #[derive(Clone)]
 struct Model {
     tenant_id: String,
     typesystem: Typesystem,
+    conditions: HashMap<String, schema::Condition>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
@@ -26,30 +47,46 @@ struct ModelJson {
 }
 
 async fn init() -> (Model, RelationshipTupleReaderRef) {
+    let (model, storage) = init_storage().await;
+    (model, Arc::new(storage))
+}
+
+async fn init_storage() -> (Model, Storage) {
+    init_fixture(0, include_str!("../fixtures/tuples.json")).await
+}
+
+/// Saves the model at `index` of the model fixtures along with the tuples into a new store.
+async fn init_fixture(index: usize, tuples: &str) -> (Model, Storage) {
     let models: Vec<ModelJson> = serde_json::from_str(include_str!("../fixtures/models.json")).unwrap();
-    let model = &models[0];
-    let tuples = serde_json::from_str(include_str!("../fixtures/tuples.json")).unwrap();
+    let model = &models[index];
+    let tuples = serde_json::from_str(tuples).unwrap();
 
-    let (schema, _) = schema::parse(&model.dsl).unwrap();
-    let authz_model = schema.to_typesystem();
+    let (authz_model, _) = schema::parse(&model.dsl).unwrap();
 
     let conn = Database::connect("sqlite::memory:").await.unwrap();
     let schema = Schema::new(DbBackend::Sqlite);
     let stmt = schema.create_table_from_entity(TupleEntity);
     conn.execute(conn.get_database_backend().build(&stmt)).await.unwrap();
+    let stmt = schema.create_table_from_entity(AuthzModelEntity);
+    conn.execute(conn.get_database_backend().build(&stmt)).await.unwrap();
 
     let storage = Storage::new(Arc::new(conn));
     let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
-    let tuple_reader: RelationshipTupleReaderRef = Arc::new(storage.clone());
+    let model_writer: AuthzModelWriterRef = Arc::new(storage.clone());
+    model_writer
+        .save(model.tenant_id.clone(), authz_model.clone(), false)
+        .await
+        .unwrap();
     tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
 
     (
         Model {
             tenant_id: model.tenant_id.clone(),
             // model_id: model.id.clone(),
-            typesystem: authz_model,
+            conditions: authz_model.condition_map(),
+            typesystem: authz_model.to_typesystem(),
         },
-        tuple_reader,
+        storage,
     )
 }
 
@@ -57,3 +94,1145 @@ async fn init() -> (Model, RelationshipTupleReaderRef) {
 async fn test_init() {
     let _ = init().await;
 }
+
+#[tokio::test]
+async fn test_save_conditional_tuple() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let tuple = |condition: &str, context: serde_json::Value| {
+        serde_json::from_value(serde_json::json!([{
+            "user_type": "user",
+            "user_id": "4",
+            "relation": "assignment",
+            "object_type": "block",
+            "object_id": "3",
+            "condition": condition,
+            "context": context,
+        }]))
+        .unwrap()
+    };
+
+    let err = tuple_writer
+        .save(&model.tenant_id, tuple("not_exists", serde_json::json!({})))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Not found condition not_exists in the latest authz model"
+    );
+
+    let err = tuple_writer
+        .save(&model.tenant_id, tuple("in_office", serde_json::json!({"cidr": 8})))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid context of condition in_office: invalid parameter `cidr`: expected string"
+    );
+
+    let err = tuple_writer
+        .save(&model.tenant_id, tuple("in_office", serde_json::json!({"port": 80})))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid context of condition in_office: unknown parameter `port`"
+    );
+
+    let mut unconditional: Vec<Tuple> = tuple("in_office", serde_json::json!({"ip": "10.0.0.1"}));
+    unconditional[0].condition = None;
+    let err = tuple_writer.save(&model.tenant_id, unconditional).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Context given to block:3#assignment without a condition"
+    );
+
+    tuple_writer
+        .save(
+            &model.tenant_id,
+            tuple("in_office", serde_json::json!({"ip": "10.0.0.1"})),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_conditional_tuple_context() {
+    let (model, tuple_reader) = init().await;
+    let checker = LocalChecker::new(None, tuple_reader);
+    // user 3 is assigned to block 2 with `in_office`, the tuple stores `cidr: 10.0.0.0/8`
+    let check = |context: serde_json::Value| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: model.typesystem.clone(),
+        conditions: model.conditions.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("3"),
+            relation: String::from("assignment"),
+            object_type: String::from("block"),
+            object_id: String::from("2"),
+            ..Default::default()
+        },
+        context: serde_json::from_value(context).unwrap(),
+        ..Default::default()
+    };
+
+    assert!(
+        checker
+            .check(check(serde_json::json!({"ip": "10.1.2.3"})))
+            .await
+            .unwrap()
+            .allow
+    );
+    assert!(
+        !checker
+            .check(check(serde_json::json!({"ip": "192.168.0.1"})))
+            .await
+            .unwrap()
+            .allow
+    );
+    // the context stored on the tuple wins over the request
+    assert!(
+        !checker
+            .check(check(
+                serde_json::json!({"ip": "192.168.0.1", "cidr": "192.168.0.0/16"})
+            ))
+            .await
+            .unwrap()
+            .allow
+    );
+    let err = checker.check(check(serde_json::json!({}))).await.unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<CheckerError>(),
+            Some(CheckerError::ConditionEvaluation { name, .. }) if name == "in_office"
+        ),
+        "{}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_expand_conditions() {
+    let (model, tuple_reader) = init().await;
+    // user 3 is assigned to block 2 with `in_office`, the tuple stores `cidr: 10.0.0.0/8`
+    for (ip, allow) in [("10.1.2.3", true), ("192.168.0.1", false)] {
+        let context: HashMap<String, serde_json::Value> =
+            serde_json::from_value(serde_json::json!({ "ip": ip })).unwrap();
+
+        let objects = ObjectsExpander::new(tuple_reader.clone())
+            .with_context(model.conditions.clone(), context.clone())
+            .objects(
+                model.typesystem.clone(),
+                model.tenant_id.clone(),
+                String::from("assignment"),
+                String::from("block"),
+                String::from("user"),
+                String::from("3"),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(objects.contains("2"), allow, "{}", ip);
+
+        let users = UsersExpander::new(tuple_reader.clone())
+            .with_context(model.conditions.clone(), context.clone())
+            .users(
+                model.typesystem.clone(),
+                model.tenant_id.clone(),
+                String::from("assignment"),
+                String::from("block"),
+                String::from("2"),
+                String::from("user"),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(users.contains("3"), allow, "{}", ip);
+
+        let tree = Expander::new(tuple_reader.clone())
+            .with_context(model.conditions.clone(), context)
+            .expand(
+                model.typesystem.clone(),
+                model.tenant_id.clone(),
+                String::from("assignment"),
+                String::from("block"),
+                String::from("2"),
+            )
+            .await
+            .unwrap();
+        let ExpandTree::This(nodes) = tree else {
+            panic!("unexpected tree: {:?}", tree);
+        };
+        assert_eq!(!nodes.is_empty(), allow, "{}", ip);
+    }
+
+    // a check with the same context fails on a missing parameter, so does the listing
+    let err = UsersExpander::new(tuple_reader)
+        .with_context(model.conditions.clone(), HashMap::new())
+        .users(
+            model.typesystem.clone(),
+            model.tenant_id.clone(),
+            String::from("assignment"),
+            String::from("block"),
+            String::from("2"),
+            String::from("user"),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<CheckerError>(),
+            Some(CheckerError::ConditionEvaluation { .. })
+        ),
+        "{}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_wildcard() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "user", "user_id": "*", "relation": "viewer", "object_type": "doc", "object_id": "public"},
+        {"user_type": "user", "user_id": "7", "relation": "viewer", "object_type": "doc", "object_id": "private"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    let checker = LocalChecker::new(None, Arc::new(storage));
+    let typesystem = schema::parse("type user {}\ntype doc {\n  relation viewer: user#*\n}")
+        .unwrap()
+        .0
+        .to_typesystem();
+    let check = |user_id: &str, object_id: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from(user_id),
+            relation: String::from("viewer"),
+            object_type: String::from("doc"),
+            object_id: String::from(object_id),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // the wildcard tuple relates every user, a tuple of one user does not fit `user#*`
+    assert!(checker.check(check("7", "public")).await.unwrap().allow);
+    assert!(checker.check(check("8", "public")).await.unwrap().allow);
+    assert!(!checker.check(check("7", "private")).await.unwrap().allow);
+}
+
+#[tokio::test]
+async fn test_save_model_orphans() {
+    let (model, storage) = init_storage().await;
+    let model_writer: AuthzModelWriterRef = Arc::new(storage.clone());
+    let models: Vec<ModelJson> = serde_json::from_str(include_str!("../fixtures/models.json")).unwrap();
+    let dsl = models[0].dsl.replace(
+        "relation viewer: user | user#* | group#member",
+        "relation viewer: user | user#*",
+    );
+    let (narrowed, _) = schema::parse(&dsl).unwrap();
+
+    let err = model_writer
+        .save(model.tenant_id.clone(), narrowed.clone(), false)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The authz model would orphan 1 existing tuples, save it with force to override"
+    );
+    let Some(storage::StorageError::OrphanedTuples { relations, sample, .. }) = err.downcast_ref() else {
+        panic!("unexpected error: {}", err);
+    };
+    assert_eq!(relations.get("folder#viewer"), Some(&1));
+    assert_eq!(sample[0].user_type, "group");
+    // nothing is saved when the check fails
+    let (_, latest) = storage.get_latest(model.tenant_id.clone()).await.unwrap();
+    assert_ne!(latest, narrowed);
+
+    // adding a relation orphans nothing
+    let (widened, _) = schema::parse(
+        &models[0]
+            .dsl
+            .replace("type user {}", "type user {\n  relation self: user\n}"),
+    )
+    .unwrap();
+    model_writer
+        .save(model.tenant_id.clone(), widened, false)
+        .await
+        .unwrap();
+
+    model_writer
+        .save(model.tenant_id.clone(), narrowed, true)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_get_latest_model() {
+    let (model, storage) = init_storage().await;
+    let model_writer: AuthzModelWriterRef = Arc::new(storage.clone());
+    let (first_id, _) = storage.get_latest(model.tenant_id.clone()).await.unwrap();
+    let (newest, _) = schema::parse("type user {}").unwrap();
+    model_writer
+        .save(model.tenant_id.clone(), newest.clone(), true)
+        .await
+        .unwrap();
+
+    let (id, latest) = storage.get_latest(model.tenant_id.clone()).await.unwrap();
+    assert_ne!(id, first_id);
+    assert_eq!(latest, newest);
+}
+
+#[tokio::test]
+async fn test_superadmin() {
+    let (model, storage) = init_storage().await;
+    let model_writer: AuthzModelWriterRef = Arc::new(storage.clone());
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let tuple_reader: RelationshipTupleReaderRef = Arc::new(storage.clone());
+    let models: Vec<ModelJson> = serde_json::from_str(include_str!("../fixtures/models.json")).unwrap();
+    let dsl = format!(
+        "superadmin system#admin\n{}\ntype system {{\n  relation admin: user\n}}",
+        models[0].dsl
+    );
+    let (authz_model, _) = schema::parse(&dsl).unwrap();
+    model_writer
+        .save(model.tenant_id.clone(), authz_model.clone(), false)
+        .await
+        .unwrap();
+    let admin = serde_json::from_value(serde_json::json!([{
+        "user_type": "user",
+        "user_id": "9",
+        "relation": "admin",
+        "object_type": "system",
+        "object_id": "global",
+    }]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, admin).await.unwrap();
+    let typesystem = authz_model.to_typesystem();
+
+    let checker: CheckerRef = Arc::new(LocalChecker::new(None, tuple_reader.clone()));
+    let check = |user_id: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: typesystem.clone(),
+        conditions: model.conditions.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from(user_id),
+            relation: String::from("viewer"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let result = checker.check(check("9")).await.unwrap();
+    assert!(result.allow);
+    assert!(result.resolution_metadata.superadmin);
+    assert_eq!(result.resolution_metadata.datastore_query_count, 1);
+    let result = checker.check(check("2")).await.unwrap();
+    assert!(result.allow);
+    assert!(!result.resolution_metadata.superadmin);
+    assert!(!checker.check(check("4")).await.unwrap().allow);
+
+    let objects = ObjectsExpander::new(tuple_reader.clone())
+        .objects(
+            typesystem.clone(),
+            model.tenant_id.clone(),
+            String::from("viewer"),
+            String::from("folder"),
+            String::from("user"),
+            String::from("9"),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(objects.contains("1"));
+
+    let users = UsersExpander::new(tuple_reader.clone())
+        .users(
+            typesystem.clone(),
+            model.tenant_id.clone(),
+            String::from("viewer"),
+            String::from("folder"),
+            String::from("1"),
+            String::from("user"),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(users.contains("9"));
+
+    let tree = Expander::new(tuple_reader)
+        .expand(
+            typesystem,
+            model.tenant_id.clone(),
+            String::from("viewer"),
+            String::from("folder"),
+            String::from("1"),
+        )
+        .await
+        .unwrap();
+    let ExpandTree::Union { children } = tree else {
+        panic!("unexpected tree: {:?}", tree);
+    };
+    let ExpandTree::Superadmin(nodes) = children[1].as_ref() else {
+        panic!("unexpected tree: {:?}", children[1]);
+    };
+    assert_eq!(
+        serde_json::to_value(nodes).unwrap(),
+        serde_json::json!([{"user_type": "user", "user_id": "9", "user_relation": null}])
+    );
+}
+
+#[tokio::test]
+async fn test_superadmin_objects() {
+    let (model, storage) = init_storage().await;
+    let model_writer: AuthzModelWriterRef = Arc::new(storage.clone());
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let dsl = "superadmin system#admin\ntype user {}\ntype system {\n  relation admin: user with in_office\n}\ntype doc {\n  relation viewer: user\n}\ncondition in_office(ip: ipaddress, cidr: string) {\n  ip.in_cidr(cidr)\n}";
+    let (authz_model, _) = schema::parse(dsl).unwrap();
+    model_writer
+        .save(model.tenant_id.clone(), authz_model.clone(), true)
+        .await
+        .unwrap();
+    let mut tuples = vec![serde_json::json!({
+        "user_type": "user",
+        "user_id": "9",
+        "relation": "admin",
+        "object_type": "system",
+        "object_id": "global",
+        "condition": "in_office",
+        "context": {"cidr": "10.0.0.0/8"},
+    })];
+    // more docs than a page of the listing
+    tuples.extend((0..1001).map(|i| {
+        serde_json::json!({
+            "user_type": "user",
+            "user_id": "1",
+            "relation": "viewer",
+            "object_type": "doc",
+            "object_id": i.to_string(),
+        })
+    }));
+    tuple_writer
+        .save(
+            &model.tenant_id,
+            serde_json::from_value(serde_json::Value::Array(tuples)).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let expander = ObjectsExpander::new(Arc::new(storage));
+    // the superadmin tuple holds only in the office
+    for (ip, count) in [("10.0.0.1", 1001), ("192.168.0.1", 0)] {
+        let objects = expander
+            .with_context(
+                authz_model.condition_map(),
+                HashMap::from([(String::from("ip"), serde_json::json!(ip))]),
+            )
+            .objects(
+                authz_model.clone().to_typesystem(),
+                model.tenant_id.clone(),
+                String::from("viewer"),
+                String::from("doc"),
+                String::from("user"),
+                String::from("9"),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(objects.len(), count, "{}", ip);
+    }
+}
+
+#[tokio::test]
+async fn test_contextual_tuples() {
+    let (model, tuple_reader) = init().await;
+    let contextual_tuples: Vec<TupleKey> = serde_json::from_value(serde_json::json!([{
+        "user_type": "group",
+        "user_id": "7",
+        "user_relation": "member",
+        "relation": "viewer",
+        "object_type": "folder",
+        "object_id": "1",
+    }, {
+        "user_type": "user",
+        "user_id": "4",
+        "user_relation": "",
+        "relation": "member",
+        "object_type": "group",
+        "object_id": "7",
+    }]))
+    .unwrap();
+
+    let checker: CheckerRef = Arc::new(LocalChecker::new(None, tuple_reader.clone()));
+    let check = |contextual_tuples: Vec<TupleKey>| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: model.typesystem.clone(),
+        conditions: model.conditions.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("4"),
+            relation: String::from("viewer"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        contextual_tuples,
+        ..Default::default()
+    };
+    assert!(!checker.check(check(vec![])).await.unwrap().allow);
+    assert!(checker.check(check(contextual_tuples.clone())).await.unwrap().allow);
+
+    let contextual_tuples: Vec<Tuple> = contextual_tuples.into_iter().map(Tuple::from).collect();
+    let objects = ObjectsExpander::new(tuple_reader.clone())
+        .with_contextual_tuples(contextual_tuples.clone())
+        .objects(
+            model.typesystem.clone(),
+            model.tenant_id.clone(),
+            String::from("viewer"),
+            String::from("folder"),
+            String::from("user"),
+            String::from("4"),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(objects.contains("1"));
+
+    let users = UsersExpander::new(tuple_reader)
+        .with_contextual_tuples(contextual_tuples)
+        .users(
+            model.typesystem.clone(),
+            model.tenant_id.clone(),
+            String::from("viewer"),
+            String::from("folder"),
+            String::from("1"),
+            String::from("user"),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(users.contains("4"));
+}
+
+#[test]
+fn test_tuple_filter_matches() {
+    let tuple: Tuple = serde_json::from_value(serde_json::json!({
+        "user_type": "group",
+        "user_id": "7",
+        "user_relation": "member",
+        "relation": "viewer",
+        "object_type": "folder",
+        "object_id": "1",
+    }))
+    .unwrap();
+    let filter = TupleFilter {
+        object_type_eq: Some(String::from("folder")),
+        object_id_in: Some(vec![String::from("1"), String::from("2")]),
+        ..Default::default()
+    };
+    assert!(filter.matches(&tuple));
+    let filter = TupleFilter {
+        user_relation_is_null: Some(true),
+        ..filter
+    };
+    assert!(!filter.matches(&tuple));
+    let filter = TupleFilter {
+        or: Some(vec![
+            TupleFilter {
+                relation_eq: Some(String::from("owner")),
+                ..Default::default()
+            },
+            TupleFilter {
+                user_relation_eq: Some(String::from("member")),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    assert!(filter.matches(&tuple));
+}
+
+#[tokio::test]
+async fn test_resolution_depth_and_cycles() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let tuple_reader: RelationshipTupleReaderRef = Arc::new(storage.clone());
+    let dsl = "type user {}\ntype folder {\n  relation parent: folder\n  relation viewer: user\n  permission view: viewer + parent#view\n}";
+    let (authz_model, _) = schema::parse(dsl).unwrap();
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "folder", "user_id": "2", "relation": "parent", "object_type": "folder", "object_id": "1"},
+        {"user_type": "folder", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "2"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "2"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+    let typesystem = authz_model.to_typesystem();
+
+    let checker: CheckerRef = Arc::new(LocalChecker::new(None, tuple_reader));
+    let check = |user_id: &str, depth: u32| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from(user_id),
+            relation: String::from("view"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        resolution_metadata: ResolutionMetadata {
+            depth,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(checker.check(check("1", DEFAULT_RESOLUTION_DEPTH)).await.unwrap().allow);
+    // the parents of folder 1 lead back to it, the cycle is cut instead of resolved until the limit
+    assert!(!checker.check(check("2", DEFAULT_RESOLUTION_DEPTH)).await.unwrap().allow);
+
+    let err = checker.check(check("1", 2)).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<CheckerError>(),
+        Some(CheckerError::ResolutionTooComplex)
+    ));
+}
+
+#[tokio::test]
+async fn test_tuple_to_userset_operands() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "folder", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "2"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "1"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    let checker = LocalChecker::new(None, Arc::new(storage));
+    // `parent#viewer` is the deprecated form of `parent->viewer`, both are the viewers of the parents
+    for rewrite in ["parent->viewer", "parent#viewer"] {
+        let dsl = format!(
+            "type user {{}}\ntype folder {{\n  relation parent: folder\n  relation viewer: user\n  permission view: {}\n}}",
+            rewrite
+        );
+        let typesystem = schema::parse(&dsl).unwrap().0.to_typesystem();
+        let check = |object_id: &str| CheckRequest {
+            tenant_id: model.tenant_id.clone(),
+            typesystem: typesystem.clone(),
+            tuple_key: TupleKey {
+                user_type: String::from("user"),
+                user_id: String::from("1"),
+                relation: String::from("view"),
+                object_type: String::from("folder"),
+                object_id: String::from(object_id),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(checker.check(check("2")).await.unwrap().allow, "{}", rewrite);
+        assert!(!checker.check(check("1")).await.unwrap().allow, "{}", rewrite);
+    }
+}
+
+#[tokio::test]
+async fn test_tuple_to_userset_dispatch() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let dsl = "type user {}\ntype org {}\ntype team {\n  relation member: user\n  relation viewer: user\n}\ntype folder {\n  relation parent: folder | org | team#member\n  relation viewer: user\n  permission view: viewer + parent->viewer\n}";
+    let typesystem = schema::parse(dsl).unwrap().0.to_typesystem();
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "1"},
+        {"user_type": "folder", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "2"},
+        {"user_type": "org", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "3"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "team", "object_id": "1"},
+        {"user_type": "team", "user_id": "1", "user_relation": "member", "relation": "parent", "object_type": "folder", "object_id": "4"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    // a permission has no directly related types, it is resolved through its rewrite only
+    let view = typesystem.get_relation("folder", "view").unwrap();
+    assert!(view.type_info.directly_related_user_types.is_empty());
+
+    let checker = LocalChecker::new(None, Arc::new(storage));
+    let check = |object_id: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from("view"),
+            object_type: String::from("folder"),
+            object_id: String::from(object_id),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // the user of the request is checked on the parent, not the parent on itself
+    assert!(checker.check(check("2")).await.unwrap().allow);
+    // an org has no viewer, the parent is skipped instead of failing the check
+    assert!(!checker.check(check("3")).await.unwrap().allow);
+    // a userset relates no object to follow, though the team has the user as viewer
+    assert!(!checker.check(check("4")).await.unwrap().allow);
+}
+
+#[tokio::test]
+async fn test_exclusion_cycles() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let dsl = "type user {}\ntype folder {\n  relation parent: folder\n  relation viewer: user\n  relation blocked: user\n  permission banned: blocked + parent->banned\n  permission view: viewer - banned\n}";
+    let typesystem = schema::parse(dsl).unwrap().0.to_typesystem();
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "folder", "user_id": "2", "relation": "parent", "object_type": "folder", "object_id": "1"},
+        {"user_type": "folder", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "2"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "1"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "3"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    let checker = LocalChecker::new(None, Arc::new(storage));
+    let check = |object_id: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from("view"),
+            object_type: String::from("folder"),
+            object_id: String::from(object_id),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // banned loops through the parents of folder 1, a cut cycle must not read as not banned
+    assert!(!checker.check(check("1")).await.unwrap().allow);
+    assert!(checker.check(check("3")).await.unwrap().allow);
+}
+
+#[tokio::test]
+async fn test_set_operation_short_circuit() {
+    let allow = |allow: bool| async move { Ok(CheckResult::new_dqc(allow, 1)) };
+    let pending = || async { futures::future::pending::<Result<CheckResult>>().await };
+    let union = union_check(3, 2, |i| match i {
+        0 => pending().boxed(),
+        1 => allow(false).boxed(),
+        _ => allow(true).boxed(),
+    })
+    .await
+    .unwrap();
+    assert!(union.allow);
+    assert_eq!(union.resolution_metadata.datastore_query_count, 2);
+
+    let intersection = intersection_check(2, 2, |i| match i {
+        0 => pending().boxed(),
+        _ => allow(false).boxed(),
+    })
+    .await
+    .unwrap();
+    assert!(!intersection.allow);
+}
+
+#[tokio::test]
+async fn test_datastore_query_count() {
+    let (model, storage) = init_fixture(1, include_str!("../fixtures/exclusion-tuples.json")).await;
+    let checker = LocalChecker::new(None, Arc::new(storage)).with_concurrency_limit(1);
+    let req = CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: model.typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("3"),
+            relation: String::from("edit"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // editor through group 1 (2), viewer (1), blocked (1)
+    let result = checker.check(req).await.unwrap();
+    assert!(result.allow);
+    assert_eq!(result.resolution_metadata.datastore_query_count, 4);
+}
+
+/// Reads through the storage slowly, `slow` relations more so, keeping track of the most reads in flight.
+struct SlowReader {
+    storage: Storage,
+    in_flight: AtomicUsize,
+    most: AtomicUsize,
+}
+
+#[async_trait]
+impl RelationshipTupleReader for SlowReader {
+    async fn list(
+        &self,
+        tenant_id: &str,
+        filter: TupleFilter,
+        page: Option<Pagination>,
+    ) -> Result<(Vec<Tuple>, Option<u64>)> {
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.most.fetch_max(in_flight, Ordering::SeqCst);
+        let millis = if filter.relation_eq.as_deref() == Some("slow") {
+            100
+        } else {
+            10
+        };
+        tokio::time::sleep(Duration::from_millis(millis)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        RelationshipTupleReader::list(&self.storage, tenant_id, filter, page).await
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_reads() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let dsl = "type user {}\ntype group {\n  relation member: user\n}\ntype doc {\n  relation a: group#member\n  relation b: group#member\n  relation fast: user\n  relation slow: user\n  permission view: a + b\n  permission either: fast + slow\n}";
+    let typesystem = schema::parse(dsl).unwrap().0.to_typesystem();
+    let mut tuples: Vec<serde_json::Value> = (1..=8)
+        .map(|group| {
+            let relation = if group <= 4 { "a" } else { "b" };
+            serde_json::json!({"user_type": "group", "user_id": group.to_string(), "user_relation": "member", "relation": relation, "object_type": "doc", "object_id": "1"})
+        })
+        .collect();
+    tuples.push(
+        serde_json::json!({"user_type": "user", "user_id": "1", "relation": "fast", "object_type": "doc", "object_id": "1"}),
+    );
+    let tuples = serde_json::from_value(serde_json::Value::Array(tuples)).unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    let reader = Arc::new(SlowReader {
+        storage,
+        in_flight: AtomicUsize::new(0),
+        most: AtomicUsize::new(0),
+    });
+    let checker = LocalChecker::new(None, reader.clone()).with_concurrency_limit(2);
+    let check = |relation: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from(relation),
+            object_type: String::from("doc"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // both operands fan out to their groups, two at a time each, the check as a whole still reads two at a time
+    let result = checker.check(check("view")).await.unwrap();
+    assert!(!result.allow);
+    assert_eq!(result.resolution_metadata.datastore_query_count, 10);
+    assert_eq!(reader.most.load(Ordering::SeqCst), 2);
+
+    // the slow read was dropped once fast allowed, it still counts
+    let result = checker.check(check("either")).await.unwrap();
+    assert!(result.allow);
+    assert_eq!(result.resolution_metadata.datastore_query_count, 2);
+}
+
+#[tokio::test]
+async fn test_memo() {
+    let (model, storage) = init_fixture(1, include_str!("../fixtures/exclusion-tuples.json")).await;
+    let checker = LocalChecker::new(None, Arc::new(storage));
+    let memo = Memo::default();
+    let check = |relation: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: model.typesystem.clone(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("3"),
+            relation: String::from(relation),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        memo: Some(memo.clone()),
+        ..Default::default()
+    };
+    let result = checker.check(check("edit")).await.unwrap();
+    assert!(result.allow);
+    assert_eq!(result.resolution_metadata.datastore_query_count, 4);
+    // viewer, editor and blocked were resolved by edit
+    let result = checker.check(check("view")).await.unwrap();
+    assert!(result.allow);
+    assert_eq!(result.resolution_metadata.datastore_query_count, 0);
+}
+
+#[tokio::test]
+async fn test_memo_skips_cut_branches() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let dsl = "type user {}\ntype folder {\n  relation parent: folder\n  relation viewer: user\n  permission view: parent#view + viewer\n}";
+    let (authz_model, _) = schema::parse(dsl).unwrap();
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "folder", "user_id": "2", "relation": "parent", "object_type": "folder", "object_id": "1"},
+        {"user_type": "folder", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "2"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "1"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    // one child at a time, the parent of folder 1 is resolved before its viewers
+    let checker = LocalChecker::new(None, Arc::new(storage)).with_concurrency_limit(1);
+    let memo = Memo::default();
+    let check = |object_id: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: authz_model.clone().to_typesystem(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from("view"),
+            object_type: String::from("folder"),
+            object_id: String::from(object_id),
+            ..Default::default()
+        },
+        memo: Some(memo.clone()),
+        ..Default::default()
+    };
+    assert!(checker.check(check("1")).await.unwrap().allow);
+    // folder 2 was denied while folder 1 was being resolved, that answer must not be remembered
+    assert!(checker.check(check("2")).await.unwrap().allow);
+}
+
+/// Counts the checks reaching it, each one takes a while so that the concurrent ones overlap.
+#[derive(Default)]
+struct CountingChecker(AtomicUsize);
+
+#[async_trait]
+impl Checker for CountingChecker {
+    async fn check(&self, req: CheckRequest) -> Result<CheckResult> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok(CheckResult::new(req.tuple_key.user_id == "1"))
+    }
+
+    async fn close(&self) {}
+
+    fn name(&self) -> &str {
+        "counting"
+    }
+}
+
+#[tokio::test]
+async fn test_singleflight() {
+    let counting = Arc::new(CountingChecker::default());
+    let checker = SingleflightChecker::new(counting.clone());
+    let check = |user_id: &str| CheckRequest {
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from(user_id),
+            relation: String::from("viewer"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let results = futures::future::join_all((0..5).map(|_| checker.check(check("1")))).await;
+    assert!(results.into_iter().all(|result| result.unwrap().allow));
+    assert_eq!(counting.0.load(Ordering::SeqCst), 1);
+
+    let (one, two) = futures::join!(checker.check(check("1")), checker.check(check("2")));
+    assert!(one.unwrap().allow);
+    assert!(!two.unwrap().allow);
+    assert_eq!(counting.0.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_singleflight_dropped_flights() {
+    let counting = Arc::new(CountingChecker::default());
+    let checker = SingleflightChecker::new(counting.clone());
+    let check = CheckRequest {
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from("viewer"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // the union is decided by its second child while the flight of the first is still reading
+    let union = union_check(2, 2, |i| match i {
+        0 => checker.check(check.clone()),
+        _ => async { Ok(CheckResult::new(true)) }.boxed(),
+    })
+    .await
+    .unwrap();
+    assert!(union.allow);
+    assert_eq!(counting.0.load(Ordering::SeqCst), 1);
+    assert_eq!(checker.in_flight(), 0);
+
+    let checker = Arc::new(SingleflightChecker::new(Arc::new(PanickingChecker::default())));
+    let panicked = tokio::spawn({
+        let checker = checker.clone();
+        let check = check.clone();
+        async move { checker.check(check).await }
+    })
+    .await;
+    assert!(panicked.unwrap_err().is_panic());
+    assert_eq!(checker.in_flight(), 0);
+    assert!(checker.check(check).await.unwrap().allow);
+}
+
+/// Panics on the first check it gets, allows the others.
+#[derive(Default)]
+struct PanickingChecker(AtomicBool);
+
+#[async_trait]
+impl Checker for PanickingChecker {
+    async fn check(&self, _req: CheckRequest) -> Result<CheckResult> {
+        if !self.0.swap(true, Ordering::SeqCst) {
+            panic!("the first check panics");
+        }
+        Ok(CheckResult::new(true))
+    }
+
+    async fn close(&self) {}
+
+    fn name(&self) -> &str {
+        "panicking"
+    }
+}
+
+#[tokio::test]
+async fn test_singleflight_cycles() {
+    let (model, storage) = init_storage().await;
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let dsl = "type user {}\ntype folder {\n  relation parent: folder\n  relation viewer: user\n  permission view: parent#view + viewer\n}";
+    let (authz_model, _) = schema::parse(dsl).unwrap();
+    let tuples = serde_json::from_value(serde_json::json!([
+        {"user_type": "folder", "user_id": "2", "relation": "parent", "object_type": "folder", "object_id": "1"},
+        {"user_type": "folder", "user_id": "1", "relation": "parent", "object_type": "folder", "object_id": "2"},
+        {"user_type": "user", "user_id": "1", "relation": "viewer", "object_type": "folder", "object_id": "1"},
+    ]))
+    .unwrap();
+    tuple_writer.save(&model.tenant_id, tuples).await.unwrap();
+
+    let checker = SingleflightChecker::new_local(Arc::new(storage), DEFAULT_CONCURRENCY_LIMIT, Generations::default());
+    let check = |user_id: &str, object_id: &str| CheckRequest {
+        tenant_id: model.tenant_id.clone(),
+        typesystem: authz_model.clone().to_typesystem(),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from(user_id),
+            relation: String::from("view"),
+            object_type: String::from("folder"),
+            object_id: String::from(object_id),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // each folder waits on the other as its parent, joining those flights would wait forever
+    for user_id in ["1", "2"] {
+        let checks = futures::future::join_all(
+            ["1", "2", "1", "2"]
+                .into_iter()
+                .map(|object_id| checker.check(check(user_id, object_id))),
+        );
+        let results = tokio::time::timeout(Duration::from_secs(5), checks).await.unwrap();
+        assert!(results
+            .into_iter()
+            .all(|result| result.unwrap().allow == (user_id == "1")));
+    }
+}
+
+#[tokio::test]
+async fn test_cache_invalidation() {
+    let counting = Arc::new(CountingChecker::default());
+    let generations = Generations::default();
+    let checker = CacheChecker::new(counting.clone()).with_generations(generations.clone());
+    let check = |tenant_id: &str| CheckRequest {
+        tenant_id: String::from(tenant_id),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from("viewer"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    for tenant_id in ["1", "1", "2"] {
+        assert!(checker.check(check(tenant_id)).await.unwrap().allow);
+    }
+    assert_eq!(counting.0.load(Ordering::SeqCst), 2);
+
+    generations.bump("1");
+    for tenant_id in ["1", "2"] {
+        assert!(checker.check(check(tenant_id)).await.unwrap().allow);
+    }
+    assert_eq!(counting.0.load(Ordering::SeqCst), 3);
+
+    // the write lands while the check is reading, its result must not be hit afterwards
+    generations.bump("2");
+    let (result, _) = futures::join!(checker.check(check("2")), async {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        generations.bump("2");
+    });
+    assert!(result.unwrap().allow);
+    assert_eq!(counting.0.load(Ordering::SeqCst), 4);
+    assert!(checker.check(check("2")).await.unwrap().allow);
+    assert_eq!(counting.0.load(Ordering::SeqCst), 5);
+}
+
+/// Allows until revoked, as read when a check starts, each check takes a while so that the concurrent ones overlap.
+#[derive(Default)]
+struct RevocableChecker(AtomicBool);
+
+#[async_trait]
+impl Checker for RevocableChecker {
+    async fn check(&self, _req: CheckRequest) -> Result<CheckResult> {
+        let allow = !self.0.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        Ok(CheckResult::new(allow))
+    }
+
+    async fn close(&self) {}
+
+    fn name(&self) -> &str {
+        "revocable"
+    }
+}
+
+#[tokio::test]
+async fn test_cache_invalidation_in_flight() {
+    let revocable = Arc::new(RevocableChecker::default());
+    let generations = Generations::default();
+    let singleflight = SingleflightChecker::new(revocable.clone()).with_generations(generations.clone());
+    let checker = CacheChecker::new(Arc::new(singleflight)).with_generations(generations.clone());
+    let check = || CheckRequest {
+        tenant_id: String::from("1"),
+        tuple_key: TupleKey {
+            user_type: String::from("user"),
+            user_id: String::from("1"),
+            relation: String::from("viewer"),
+            object_type: String::from("folder"),
+            object_id: String::from("1"),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // the access is revoked while the first check reads, the check made after must not join its flight
+    let (before, after) = futures::join!(checker.check(check()), async {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        revocable.0.store(true, Ordering::SeqCst);
+        generations.bump("1");
+        checker.check(check()).await
+    });
+    assert!(before.unwrap().allow);
+    assert!(!after.unwrap().allow);
+    assert!(!checker.check(check()).await.unwrap().allow);
+}
@@ -1,11 +1,22 @@
-use clap::{Parser, Subcommand};
+mod model_test;
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
 use migration::{
     run_migrate,
     sea_orm::{ConnectOptions, Database},
 };
 use sea_orm_cli::MigrateSubcommands;
 use server::{
-    config::{Config, Datasource, GrpcConfig, HttpConfig},
+    config::{CacheConfig, CheckConfig, Config, Datasource, GrpcConfig, HttpConfig},
     Servers,
 };
 
@@ -37,17 +48,60 @@ enum Commands {
         // http_timeout: Option<Duration>,
         #[arg(default_value_t = grpc_default_addr(), short='g', long)]
         grpc_addr: String,
+        /// most dispatches of a check, requests may ask for less
+        #[arg(default_value_t = checker::DEFAULT_RESOLUTION_DEPTH, long)]
+        resolution_depth: u32,
+        /// datastore queries of a check in flight at the same time
+        #[arg(default_value_t = checker::DEFAULT_CONCURRENCY_LIMIT, long)]
+        concurrency_limit: usize,
+        /// most check results kept in the cache
+        #[arg(default_value_t = checker::DEFAULT_CACHE_CAPACITY, long)]
+        cache_capacity: u64,
+        /// how long a check result is cached, such as `30s`, until a write of its tenant when unset
+        #[arg(long, value_parser = humantime::parse_duration)]
+        cache_ttl: Option<Duration>,
     },
     Migration {
         #[command(subcommand)]
         command: Option<MigrateSubcommands>,
     },
+    /// Validate a model and the files it imports, print the composed model as dsl
+    Validate {
+        file: PathBuf,
+        /// directory imports are resolved from, defaults to the directory of the model
+        #[arg(short = 'b', long)]
+        base_dir: Option<PathBuf>,
+    },
+    Schema {
+        #[command(subcommand)]
+        command: SchemaCommands,
+    },
+    /// Run the assertions of a model test file against an in memory store
+    Test { file: PathBuf },
+}
+
+#[derive(Debug, Subcommand)]
+enum SchemaCommands {
+    /// Translate the model of another authorization system, print it as dsl
+    Convert {
+        file: PathBuf,
+        #[arg(short = 'f', long, value_enum)]
+        from: SchemaFormat,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum SchemaFormat {
+    /// SpiceDB schema language (.zed)
+    Spicedb,
+    /// OpenFGA authorization model json
+    Openfga,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Cli::parse();
-    let url = args.database_url.expect("Environment variable 'DATABASE_URL' not set");
+    let url = || args.database_url.expect("Environment variable 'DATABASE_URL' not set");
     let schema = args.database_schema.unwrap_or_else(|| "public".to_owned());
 
     match args.command {
@@ -55,12 +109,24 @@ async fn main() -> anyhow::Result<()> {
             http_addr,
             // http_timeout,
             grpc_addr,
+            resolution_depth,
+            concurrency_limit,
+            cache_capacity,
+            cache_ttl,
         } => {
             // env_logger::init();
             tracing_subscriber::fmt::init();
 
             let mut config = Config {
-                datasource: Datasource { uri: url, schema },
+                datasource: Datasource { uri: url(), schema },
+                check: CheckConfig {
+                    resolution_depth,
+                    concurrency_limit,
+                },
+                cache: CacheConfig {
+                    capacity: cache_capacity,
+                    ttl: cache_ttl,
+                },
                 ..Default::default()
             };
             config.http = Some(HttpConfig {
@@ -75,7 +141,7 @@ async fn main() -> anyhow::Result<()> {
             servers.start().await?;
         }
         Commands::Migration { command } => {
-            let connect_options = ConnectOptions::new(url).set_schema_search_path(schema).to_owned();
+            let connect_options = ConnectOptions::new(url()).set_schema_search_path(schema).to_owned();
             let db = &Database::connect(connect_options)
                 .await
                 .expect("Fail to acquire database connection");
@@ -84,10 +150,93 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .map_err(|err| anyhow::anyhow!(err.to_string()))?;
         }
+        Commands::Validate { file, base_dir } => validate(file, base_dir)?,
+        Commands::Schema {
+            command: SchemaCommands::Convert { file, from },
+        } => convert(file, from)?,
+        Commands::Test { file } => test(file).await?,
+    }
+    Ok(())
+}
+
+fn validate(file: PathBuf, base_dir: Option<PathBuf>) -> anyhow::Result<()> {
+    let source = fs::read_to_string(&file)?;
+    let base_dir = base_dir.unwrap_or_else(|| file.parent().map(Path::to_path_buf).unwrap_or_default());
+    let name = file.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    let model = load_model(&name, &source, &base_dir)?;
+    print!("{}", schema::format(&model));
+    Ok(())
+}
+
+/// Parses and validates a model with its imports, warnings and errors are printed.
+fn load_model(name: &str, source: &str, base_dir: &Path) -> anyhow::Result<schema::Schema> {
+    let (model, sources) = schema::parse_with_imports(name, source, |path| {
+        fs::read_to_string(base_dir.join(path)).map_err(|err| err.to_string())
+    })
+    .map_err(report)?;
+    for warning in &model.warnings {
+        eprintln!("{}: {}\n", warning.severity, warning);
+    }
+    let diagnostics = schema::validate(&model);
+    if !diagnostics.is_empty() {
+        return Err(report(diagnostics.into_iter().map(|d| sources.locate(d)).collect()));
+    }
+    Ok(model)
+}
+
+async fn test(file: PathBuf) -> anyhow::Result<()> {
+    let test = model_test::ModelTest::from_source(&file, &fs::read_to_string(&file)?)?;
+    let dir = file.parent().map(Path::to_path_buf).unwrap_or_default();
+    let model = match (&test.model, &test.model_file) {
+        (Some(dsl), None) => load_model(&file.to_string_lossy(), dsl, &dir)?,
+        (None, Some(model_file)) => {
+            let path = dir.join(model_file);
+            let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+            let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+            load_model(&name, &fs::read_to_string(&path)?, &base_dir)?
+        }
+        _ => anyhow::bail!("a model test needs one of `model` or `model_file`"),
+    };
+    let outcomes = model_test::run(model, &test).await?;
+    let failed = outcomes.iter().filter(|o| o.failure.is_some()).count();
+    for outcome in &outcomes {
+        println!("{}", outcome);
+    }
+    println!("\n{} passed, {} failed", outcomes.len() - failed, failed);
+    if failed > 0 {
+        anyhow::bail!("{} assertion(s) failed", failed);
     }
     Ok(())
 }
 
+fn convert(file: PathBuf, from: SchemaFormat) -> anyhow::Result<()> {
+    let source = fs::read_to_string(&file)?;
+    let name = file.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    let model = match from {
+        SchemaFormat::Spicedb => schema::spicedb::from_spicedb(&source),
+        SchemaFormat::Openfga => schema::openfga::from_openfga(&serde_json::from_str(&source)?),
+    }
+    .map_err(|diagnostics| report(diagnostics.into_iter().map(|d| d.in_file(name.as_ref())).collect()))?;
+    let diagnostics = schema::validate(&model);
+    if !diagnostics.is_empty() {
+        return Err(report(
+            diagnostics
+                .into_iter()
+                .map(|d| d.with_source(&source).in_file(name.as_ref()))
+                .collect(),
+        ));
+    }
+    print!("{}", schema::format(&model));
+    Ok(())
+}
+
+fn report(diagnostics: Vec<schema::Diagnostic>) -> anyhow::Error {
+    for diagnostic in &diagnostics {
+        eprintln!("{}: {}\n", diagnostic.severity, diagnostic);
+    }
+    anyhow::anyhow!("{} error(s) in the model", diagnostics.len())
+}
+
 fn http_default_addr() -> String {
     String::from("0.0.0.0:5555")
 }
@@ -0,0 +1,260 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use checker::{
+    expander::{ObjectsExpander, UsersExpander},
+    CheckRequest, Checker, LocalChecker,
+};
+use migration::{
+    sea_orm::{Database, DbErr},
+    MigratorTrait,
+};
+use protocol::{Tuple, TupleKey};
+use schema::Schema;
+use serde::Deserialize;
+use storage::{sea::Storage, AuthzModelWriterRef, RelationshipTupleReaderRef, RelationshipTupleWriterRef};
+
+const TENANT_ID: &str = "test";
+
+/// A model test file, the model with the tuples it is tested against and the expected answers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelTest {
+    /// dsl of the model
+    #[serde(default)]
+    pub model: Option<String>,
+    /// path of the model, relative to the test file, its imports are resolved from its directory
+    #[serde(default)]
+    pub model_file: Option<PathBuf>,
+    #[serde(default)]
+    pub tuples: Vec<Tuple>,
+    #[serde(default)]
+    pub check: Vec<CheckAssertion>,
+    #[serde(default)]
+    pub list_objects: Vec<ListObjectsAssertion>,
+    #[serde(default)]
+    pub list_users: Vec<ListUsersAssertion>,
+}
+
+impl ModelTest {
+    /// Reads a test file, `.yaml` and `.yml` files are yaml, any other is json.
+    pub fn from_source(path: &Path, source: &str) -> anyhow::Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => Ok(serde_yaml::from_str(source)?),
+            _ => Ok(serde_json::from_str(source)?),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckAssertion {
+    pub tuple: CheckTuple,
+    #[serde(default)]
+    pub context: HashMap<String, serde_json::Value>,
+    pub allow: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckTuple {
+    pub user_type: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub user_relation: Option<String>,
+    pub relation: String,
+    pub object_type: String,
+    pub object_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListObjectsAssertion {
+    pub tuple: ListObjectsTuple,
+    #[serde(default)]
+    pub context: HashMap<String, serde_json::Value>,
+    pub object_ids: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListObjectsTuple {
+    pub relation: String,
+    pub object_type: String,
+    pub user_type: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub user_relation: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListUsersAssertion {
+    pub tuple: ListUsersTuple,
+    #[serde(default)]
+    pub context: HashMap<String, serde_json::Value>,
+    pub user_ids: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListUsersTuple {
+    pub relation: String,
+    pub object_type: String,
+    pub object_id: String,
+    pub user_type: String,
+    #[serde(default)]
+    pub user_relation: Option<String>,
+}
+
+/// The result of one assertion, `failure` tells what differs from the expectation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    pub name: String,
+    pub failure: Option<String>,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.failure {
+            None => write!(f, "ok   {}", self.name),
+            Some(failure) => write!(f, "FAIL {}\n     {}", self.name, failure),
+        }
+    }
+}
+
+/// Loads the model and the tuples into an in memory store and runs every assertion of the test against it, the
+/// assertions that fail are reported as outcomes, not as errors.
+pub async fn run(model: Schema, test: &ModelTest) -> anyhow::Result<Vec<Outcome>> {
+    let conn = Database::connect("sqlite::memory:").await?;
+    migration::Migrator::up(&conn, None)
+        .await
+        .map_err(|err: DbErr| anyhow::anyhow!(err.to_string()))?;
+    let storage = Storage::new(Arc::new(conn));
+    let model_writer: AuthzModelWriterRef = Arc::new(storage.clone());
+    let tuple_writer: RelationshipTupleWriterRef = Arc::new(storage.clone());
+    let tuple_reader: RelationshipTupleReaderRef = Arc::new(storage);
+    model_writer.save(TENANT_ID.to_string(), model.clone(), false).await?;
+    if !test.tuples.is_empty() {
+        tuple_writer.save(TENANT_ID, test.tuples.clone()).await?;
+    }
+
+    let conditions = model.condition_map();
+    let typesystem = model.to_typesystem();
+    let mut outcomes = vec![];
+
+    let checker = LocalChecker::new(None, tuple_reader.clone());
+    for assertion in &test.check {
+        let t = &assertion.tuple;
+        let tuple_key = TupleKey {
+            user_type: t.user_type.clone(),
+            user_id: t.user_id.clone(),
+            user_relation: t.user_relation.clone().unwrap_or_default(),
+            relation: t.relation.clone(),
+            object_type: t.object_type.clone(),
+            object_id: t.object_id.clone(),
+            ..Default::default()
+        };
+        let name = format!("check {}", tuple_key);
+        let req = CheckRequest {
+            tenant_id: TENANT_ID.to_string(),
+            typesystem: typesystem.clone(),
+            conditions: conditions.clone(),
+            tuple_key,
+            context: assertion.context.clone(),
+            ..Default::default()
+        };
+        let failure = match checker.check(req).await {
+            Ok(result) if result.allow == assertion.allow => None,
+            Ok(result) => Some(format!(
+                "expected {}, got {}",
+                allow(assertion.allow),
+                allow(result.allow)
+            )),
+            Err(err) => Some(format!("error: {}", err)),
+        };
+        outcomes.push(Outcome { name, failure });
+    }
+
+    let objects_expander = ObjectsExpander::new(tuple_reader.clone());
+    for assertion in &test.list_objects {
+        let t = &assertion.tuple;
+        let name = format!(
+            "list objects {}#{} for {}:{}{}",
+            t.object_type,
+            t.relation,
+            t.user_type,
+            t.user_id,
+            user_relation(&t.user_relation)
+        );
+        let result = objects_expander
+            .with_context(conditions.clone(), assertion.context.clone())
+            .objects(
+                typesystem.clone(),
+                TENANT_ID.to_string(),
+                t.relation.clone(),
+                t.object_type.clone(),
+                t.user_type.clone(),
+                t.user_id.clone(),
+                t.user_relation.clone(),
+            )
+            .await;
+        outcomes.push(Outcome {
+            name,
+            failure: compare(&assertion.object_ids, result),
+        });
+    }
+
+    let users_expander = UsersExpander::new(tuple_reader);
+    for assertion in &test.list_users {
+        let t = &assertion.tuple;
+        let name = format!(
+            "list users {}{} of {}:{}#{}",
+            t.user_type,
+            user_relation(&t.user_relation),
+            t.object_type,
+            t.object_id,
+            t.relation
+        );
+        let result = users_expander
+            .with_context(conditions.clone(), assertion.context.clone())
+            .users(
+                typesystem.clone(),
+                TENANT_ID.to_string(),
+                t.relation.clone(),
+                t.object_type.clone(),
+                t.object_id.clone(),
+                t.user_type.clone(),
+                t.user_relation.clone(),
+            )
+            .await;
+        outcomes.push(Outcome {
+            name,
+            failure: compare(&assertion.user_ids, result),
+        });
+    }
+
+    Ok(outcomes)
+}
+
+fn allow(allow: bool) -> &'static str {
+    if allow {
+        "allow"
+    } else {
+        "deny"
+    }
+}
+
+fn user_relation(relation: &Option<String>) -> String {
+    relation.as_ref().map(|r| format!("#{}", r)).unwrap_or_default()
+}
+
+fn compare(expected: &BTreeSet<String>, result: anyhow::Result<HashSet<String>>) -> Option<String> {
+    let actual: BTreeSet<String> = match result {
+        Ok(ids) => ids.into_iter().collect(),
+        Err(err) => return Some(format!("error: {}", err)),
+    };
+    if actual.eq(expected) {
+        return None;
+    }
+    let missing: Vec<_> = expected.difference(&actual).collect();
+    let unexpected: Vec<_> = actual.difference(expected).collect();
+    Some(format!("missing {:?}, unexpected {:?}", missing, unexpected))
+}
@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use crate::model_test::{run, ModelTest};
+
+fn load() -> (schema::Schema, ModelTest) {
+    load_file("model-test.json", include_str!("./model-test.json"))
+}
+
+fn load_file(name: &str, source: &str) -> (schema::Schema, ModelTest) {
+    let test = ModelTest::from_source(Path::new(name), source).unwrap();
+    let (model, _) = schema::parse(test.model.as_deref().unwrap()).unwrap();
+    (model, test)
+}
+
+#[tokio::test]
+async fn test_model_test_pass() {
+    for (model, test) in [load(), load_file("model-test.yaml", include_str!("./model-test.yaml"))] {
+        let outcomes = run(model, &test).await.unwrap();
+        assert_eq!(
+            outcomes.iter().map(|o| o.to_string()).collect::<Vec<_>>(),
+            vec![
+                "ok   check folder:docs-viewer-user:1",
+                "ok   check folder:docs-viewer-user:2",
+                "ok   list objects folder#viewer for user:1",
+                "ok   list users user of folder:docs#owner",
+            ]
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_model_test_fail() {
+    let (model, mut test) = load();
+    test.check[1].allow = true;
+    test.list_objects[0].object_ids = ["other".to_string()].into();
+    test.list_users[0].tuple.relation = "missing".to_string();
+    let outcomes = run(model, &test).await.unwrap();
+    let failures: Vec<_> = outcomes.iter().filter_map(|o| o.failure.as_deref()).collect();
+    assert_eq!(
+        failures,
+        vec![
+            "expected allow, got deny",
+            "missing [\"other\"], unexpected [\"docs\"]",
+            "error: Not found relation by relation: missing",
+        ]
+    );
+}
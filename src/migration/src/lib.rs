@@ -6,6 +6,7 @@ use sea_orm_migration::sea_orm::{DatabaseBackend, DbBackend, DbConn};
 
 mod m20220101_000001_pg_snowid;
 mod m20240423_011759_init_tables;
+mod m20240520_000001_add_tuple_condition;
 
 pub struct Migrator;
 
@@ -22,6 +23,7 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20220101_000001_pg_snowid::Migration),
             Box::new(m20240423_011759_init_tables::Migration),
+            Box::new(m20240520_000001_add_tuple_condition::Migration),
         ]
     }
 }
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // one column per statement, sqlite can not add several columns at once
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RelationTuples::Table)
+                    .add_column_if_not_exists(ColumnDef::new(RelationTuples::ConditionName).string_len(255))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RelationTuples::Table)
+                    .add_column_if_not_exists(ColumnDef::new(RelationTuples::ConditionContext).json_binary())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RelationTuples::Table)
+                    .drop_column(RelationTuples::ConditionContext)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RelationTuples::Table)
+                    .drop_column(RelationTuples::ConditionName)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RelationTuples {
+    Table,
+    ConditionName,
+    ConditionContext,
+}
@@ -2,7 +2,10 @@ mod error;
 mod tuple;
 mod typesystem;
 
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -55,8 +58,57 @@ pub struct RelationMetadata {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub enum RelationReference {
     Direct(String),
-    Relation { r#type: String, relation: String },
+    Relation {
+        r#type: String,
+        relation: String,
+    },
     Wildcard(String),
+    Conditional {
+        reference: Box<RelationReference>,
+        condition: String,
+    },
+}
+
+/// What a relation reference relates, its conditions stripped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subject<'a> {
+    Direct(&'a str),
+    Relation { r#type: &'a str, relation: &'a str },
+    Wildcard(&'a str),
+}
+
+impl RelationReference {
+    /// The subject of the reference, through its conditions.
+    pub fn subject(&self) -> Subject<'_> {
+        match self {
+            RelationReference::Direct(typ) => Subject::Direct(typ),
+            RelationReference::Relation { r#type, relation } => Subject::Relation { r#type, relation },
+            RelationReference::Wildcard(typ) => Subject::Wildcard(typ),
+            RelationReference::Conditional { reference, .. } => reference.subject(),
+        }
+    }
+
+    pub fn condition(&self) -> Option<&str> {
+        match self {
+            RelationReference::Conditional { condition, .. } => Some(condition),
+            _ => None,
+        }
+    }
+
+    /// Whether the user of a stored tuple is related through this reference, conditions are not considered.
+    pub fn matches(&self, tuple: &Tuple) -> bool {
+        match self.subject() {
+            Subject::Direct(typ) => {
+                typ.eq(&tuple.user_type) && tuple.user_relation.is_none() && !tuple.user_id.eq(WILDCARD)
+            }
+            Subject::Relation { r#type, relation } => {
+                r#type.eq(&tuple.user_type) && tuple.user_relation.as_ref().is_some_and(|r| r.eq(relation))
+            }
+            Subject::Wildcard(typ) => {
+                typ.eq(&tuple.user_type) && tuple.user_relation.is_none() && tuple.user_id.eq(WILDCARD)
+            }
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
@@ -67,11 +119,15 @@ pub struct TupleKey {
     pub relation: String,
     pub object_type: String,
     pub object_id: String,
+    /// name of the condition that must hold for this tuple to take effect
+    pub condition: Option<String>,
+    /// partial condition context, takes precedence over the context of a check request
+    pub context: Option<HashMap<String, serde_json::Value>>,
 }
 
 impl fmt::Display for TupleKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.user_relation.eq("") {
+        if self.user_relation.is_empty() {
             write!(
                 f,
                 "{}:{}-{}-{}:{}",
@@ -89,10 +145,20 @@ impl fmt::Display for TupleKey {
 
 impl TupleKey {
     pub fn cache_key(&self) -> String {
-        format!(
+        let mut key = format!(
             "{}{}-{}-{}{}{}",
             &self.object_type, &self.object_id, &self.relation, &self.user_type, &self.user_id, &self.user_relation
-        )
+        );
+        if let Some(condition) = &self.condition {
+            // BTreeMap keeps the serialized context stable
+            let context: Option<BTreeMap<_, _>> = self.context.as_ref().map(|c| c.iter().collect());
+            key.push_str(&format!(
+                "[{}{}]",
+                condition,
+                serde_json::to_string(&context).unwrap_or_default()
+            ));
+        }
+        key
     }
 }
 
@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::TupleKey;
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub struct Tuple {
     pub user_type: String,
@@ -9,5 +13,25 @@ pub struct Tuple {
     pub relation: String,
     pub object_type: String,
     pub object_id: String,
+    /// name of the condition that must hold for this tuple to take effect
+    pub condition: Option<String>,
+    /// partial condition context, takes precedence over the context of a check request
+    pub context: Option<HashMap<String, serde_json::Value>>,
     // pub created_at:
 }
+
+/// A tuple key written as a tuple, such as a contextual tuple of a check, an empty `user_relation` is none.
+impl From<TupleKey> for Tuple {
+    fn from(tk: TupleKey) -> Self {
+        Self {
+            user_type: tk.user_type,
+            user_id: tk.user_id,
+            user_relation: Some(tk.user_relation).filter(|r| !r.is_empty()),
+            relation: tk.relation,
+            object_type: tk.object_type,
+            object_id: tk.object_id,
+            condition: tk.condition,
+            context: tk.context,
+        }
+    }
+}
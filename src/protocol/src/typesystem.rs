@@ -3,30 +3,37 @@ use std::collections::HashMap;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
-use crate::{error::ModelError, Relation, RelationReference, RelationTypeInfo, Type};
+use crate::{error::ModelError, ObjectRelation, Relation, RelationReference, RelationTypeInfo, Subject, Type};
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
-pub struct Typesystem(pub HashMap<String, Type>);
+pub struct Typesystem {
+    pub types: HashMap<String, Type>,
+    /// `object` is the type of the system objects, subjects of `relation` on any of them are superadmins
+    #[serde(default)]
+    pub superadmins: Vec<ObjectRelation>,
+}
 
 impl Typesystem {
     pub fn get_relation(&self, object_type: &str, relation: &str) -> Result<Relation> {
         let typ = self
-            .0
+            .types
             .get(object_type)
             .ok_or(ModelError::NotFoundRelations(String::from(object_type)))?;
         let rr = typ
             .relations
             .get(relation)
             .context(ModelError::NotFoundRelation(String::from(relation)))?;
-        let m = typ
+        // permissions relate no subject directly, they have no metadata
+        let directly_related_user_types = typ
             .metadata
             .get(relation)
-            .context(ModelError::NotFoundRelation(String::from(relation)))?;
+            .map(|m| m.directly_related_user_types.clone())
+            .unwrap_or_default();
 
         Ok(Relation {
             rewrite: rr.to_owned(),
             type_info: RelationTypeInfo {
-                directly_related_user_types: m.directly_related_user_types.clone(),
+                directly_related_user_types,
             },
         })
     }
@@ -35,10 +42,9 @@ impl Typesystem {
         let refs = self.get_directly_related_types(object_type, relation)?;
         Ok(refs
             .iter()
-            .filter_map(|rr| match rr {
-                RelationReference::Direct(_) => None,
-                RelationReference::Relation { .. } => Some(rr.to_owned()),
-                RelationReference::Wildcard(_) => Some(rr.to_owned()),
+            .filter_map(|rr| match rr.subject() {
+                Subject::Direct(_) => None,
+                Subject::Relation { .. } | Subject::Wildcard(_) => Some(rr.to_owned()),
             })
             .collect())
     }
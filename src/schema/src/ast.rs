@@ -4,57 +4,124 @@ mod r#type;
 use std::{collections::HashMap, vec};
 
 pub use condition::*;
-use protocol::{RelationMetadata, RelationReference, Type as ProtocolType, Typesystem, Userset};
+use protocol::{ObjectRelation, RelationMetadata, Type as ProtocolType, Typesystem, Userset};
 pub use r#type::*;
 use schemars::JsonSchema;
 use sea_orm::FromJsonQueryResult;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Clone, FromJsonQueryResult, Deserialize, Serialize, Default, JsonSchema)]
+use crate::{flatten, Diagnostic, Loc};
+
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, Default, JsonSchema)]
 pub struct Schema {
     pub types: Vec<Type>,
+    /// relations and permissions inherited by the types that extend them, flattened into the types by `flatten`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mixins: Vec<Type>,
     pub conditions: Vec<Condition>,
+    /// subjects of these relations are allowed on every object of the tenant
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub superadmins: Vec<Superadmin>,
+    /// files to compose with, resolved by `parse_with_imports`, a composed model has none
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub imports: Vec<Import>,
+    /// deprecated syntax found by the parser, the model is still valid
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub warnings: Vec<Diagnostic>,
+}
+
+impl PartialEq for Schema {
+    fn eq(&self, other: &Self) -> bool {
+        self.types == other.types
+            && self.mixins == other.mixins
+            && self.conditions == other.conditions
+            && self.superadmins == other.superadmins
+            && self.imports == other.imports
+    }
+}
+
+/// `import "path.authz"`
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub path: String,
+    pub loc: Loc,
+}
+
+impl PartialEq for Import {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+/// `superadmin system#admin`, the subjects related by `admin` to any `system` object
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct Superadmin {
+    pub r#type: String,
+    pub relation: String,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
+}
+
+impl PartialEq for Superadmin {
+    fn eq(&self, other: &Self) -> bool {
+        self.r#type == other.r#type && self.relation == other.relation
+    }
 }
 
 impl Schema {
     pub fn new(units: Vec<SchemaUnit>) -> Self {
         let mut types = vec![];
+        let mut mixins = vec![];
         let mut conditions = vec![];
+        let mut superadmins = vec![];
+        let mut imports = vec![];
         for unit in units {
             match unit {
                 SchemaUnit::Type(typ) => types.push(typ),
+                SchemaUnit::Mixin(mixin) => mixins.push(mixin),
                 SchemaUnit::Condition(cond) => conditions.push(cond),
+                SchemaUnit::Superadmin(superadmin) => superadmins.push(superadmin),
+                SchemaUnit::Import(import) => imports.push(import),
+                SchemaUnit::Error => {}
             }
         }
-        Self { types, conditions }
+        Self {
+            types,
+            mixins,
+            conditions,
+            superadmins,
+            imports,
+            warnings: vec![],
+        }
+    }
+
+    pub fn condition_map(&self) -> HashMap<String, Condition> {
+        self.conditions.iter().map(|c| (c.name.clone(), c.clone())).collect()
     }
 
+    /// Types that extend others are flattened first, mixins are not types of their own.
     pub fn to_typesystem(self) -> Typesystem {
+        let superadmins = self
+            .superadmins
+            .iter()
+            .map(|s| ObjectRelation {
+                object: s.r#type.clone(),
+                relation: s.relation.clone(),
+            })
+            .collect();
         let mut ts = HashMap::new();
-        for typ in self.types {
+        for typ in flatten(&self).types {
             let mut relations = HashMap::new();
             let mut metadata = HashMap::new();
 
             for rel in &typ.relations {
                 let mut directly_related_user_types = vec![];
                 for sub in &rel.subjects {
-                    match sub {
-                        RelationshipSet::Single(ref user) => {
-                            relations.insert(String::from(&rel.name), Userset::This);
-                            directly_related_user_types.push(RelationReference::Direct(String::from(user)));
-                        }
-                        RelationshipSet::Set(ref user, ref relation) => {
-                            relations.insert(String::from(&rel.name), Userset::This);
-                            if relation.eq("*") {
-                                directly_related_user_types.push(RelationReference::Wildcard(String::from(user)));
-                            } else {
-                                directly_related_user_types.push(RelationReference::Relation {
-                                    r#type: String::from(user),
-                                    relation: String::from(relation),
-                                });
-                            }
-                        }
-                    }
+                    relations.insert(String::from(&rel.name), Userset::This);
+                    directly_related_user_types.push(sub.to_relation_reference());
                 }
                 metadata.insert(
                     String::from(&rel.name),
@@ -77,12 +144,17 @@ impl Schema {
             ts.insert(typ.name, t);
         }
 
-        Typesystem(ts)
+        Typesystem { types: ts, superadmins }
     }
 }
 
 #[derive(Debug, PartialEq)]
 pub enum SchemaUnit {
     Type(Type),
+    Mixin(Type),
     Condition(Condition),
+    Superadmin(Superadmin),
+    Import(Import),
+    /// a unit that failed to parse, the error is recorded by the parser
+    Error,
 }
@@ -1,15 +1,32 @@
+use std::{collections::HashMap, fmt};
+
 use schemars::JsonSchema;
 use sea_orm::FromJsonQueryResult;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-#[allow(unused)]
-pub use evalexpr::{Node, Operator, Value};
+use crate::{pos::CodeLocation, Loc};
 
-#[derive(Debug, Clone, PartialEq, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
 pub struct Condition {
     pub name: String,
     pub args: Vec<ConditionArg>,
-    pub body: Vec<ConditionExpression>,
+    pub body: ConditionExpression,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
+}
+
+impl PartialEq for Condition {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.args == other.args && self.body == other.body
+    }
+}
+
+impl CodeLocation for Condition {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
@@ -34,49 +51,428 @@ pub enum ConditionType {
     IPaddress,
 }
 
+impl ConditionType {
+    fn is_numeric(&self) -> bool {
+        matches!(self, ConditionType::Int | ConditionType::Uint | ConditionType::Double)
+    }
+
+    fn accepts(&self, other: &ConditionType) -> bool {
+        match (self, other) {
+            (ConditionType::Any, _) | (_, ConditionType::Any) => true,
+            (ConditionType::List(l), ConditionType::List(r)) | (ConditionType::Map(l), ConditionType::Map(r)) => {
+                l.accepts(r)
+            }
+            (l, r) => l == r,
+        }
+    }
+
+    /// result type of an arithmetic between two numeric types, mixing with double yields double, int with uint yields int
+    fn numeric_join(&self, other: &ConditionType) -> ConditionType {
+        match (self, other) {
+            (l, r) if l == r => l.clone(),
+            (ConditionType::Double, _) | (_, ConditionType::Double) => ConditionType::Double,
+            _ => ConditionType::Int,
+        }
+    }
+}
+
+impl fmt::Display for ConditionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConditionType::Int => f.write_str("int"),
+            ConditionType::Uint => f.write_str("uint"),
+            ConditionType::Double => f.write_str("double"),
+            ConditionType::Bool => f.write_str("bool"),
+            ConditionType::Bytes => f.write_str("bytes"),
+            ConditionType::String => f.write_str("string"),
+            ConditionType::Duration => f.write_str("duration"),
+            ConditionType::Timestamp => f.write_str("timestamp"),
+            ConditionType::Any => f.write_str("any"),
+            ConditionType::List(t) => write!(f, "list<{}>", t),
+            ConditionType::Map(t) => write!(f, "map<{}>", t),
+            ConditionType::IPaddress => f.write_str("ipaddress"),
+        }
+    }
+}
+
+/// A node of the condition body, the location is only kept for diagnostics and is neither
+/// persisted nor compared.
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct ConditionExpression {
+    pub kind: ExpressionKind,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
+}
+
+impl PartialEq for ConditionExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl CodeLocation for ConditionExpression {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
-pub enum ConditionExpression {
-    Identifier(String),    // identifier
-    Colon,                 // :
-    LBracket,              // (
-    RBracket,              // )
-    Caret,                 // ^
-    And,                   // &
-    Or,                    // |
-    Add,                   // +
-    Sub,                   // -
-    YulArrow,              // ->
-    Sharp,                 // #
-    Newline,               // \n
-    LBrace,                // {
-    RBrace,                // }
-    Star,                  // \*
-    Slash,                 // /
-    Percent,               // %
-    Comma,                 // ,
-    Semicolon,             // ;
-    Eq,                    // =
-    ExclamationMark,       // (nothing)!
-    Gt,                    // >
-    Lt,                    // <
-    Condition,             // condition
-    Int,                   // int
-    Uint,                  // uint
-    Double,                // double
-    Bool,                  // bool
-    Bytes,                 // bytes
-    String,                // string
-    Duration,              // duration
-    Timestamp,             // timestamp
-    Any,                   // any
-    List,                  // list
-    Map,                   // map
-    IPaddress,             // ipaddress
-    StringLiteral(String), // string literal
-    IntLiteral(i64),       // int literal
-    DoubleLiteral(f64),    // double literal
-    Dollar,                // $
-    GraveAccent,           // `
-    WhiteSpace,            // space
-    Point,                 // .
+#[serde(rename_all = "snake_case")]
+pub enum ExpressionKind {
+    Literal(Literal),
+    Identifier(String),
+    /// `timestamp("2024-01-01T00:00:00Z")`, `int(x)`
+    Cast {
+        r#type: ConditionType,
+        expr: Box<ConditionExpression>,
+    },
+    /// `user_ip.in_cidr(allowed_range)`
+    MethodCall {
+        receiver: Box<ConditionExpression>,
+        method: String,
+        args: Vec<ConditionExpression>,
+    },
+    Unary {
+        op: UnaryOperator,
+        operand: Box<ConditionExpression>,
+    },
+    Binary {
+        op: BinaryOperator,
+        left: Box<ConditionExpression>,
+        right: Box<ConditionExpression>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Literal {
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    String(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UnaryOperator {
+    Not,
+    Neg,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryOperator {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl fmt::Display for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnaryOperator::Not => f.write_str("!"),
+            UnaryOperator::Neg => f.write_str("-"),
+        }
+    }
+}
+
+impl fmt::Display for BinaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryOperator::Or => f.write_str("||"),
+            BinaryOperator::And => f.write_str("&&"),
+            BinaryOperator::Eq => f.write_str("=="),
+            BinaryOperator::Ne => f.write_str("!="),
+            BinaryOperator::Lt => f.write_str("<"),
+            BinaryOperator::Le => f.write_str("<="),
+            BinaryOperator::Gt => f.write_str(">"),
+            BinaryOperator::Ge => f.write_str(">="),
+            BinaryOperator::Add => f.write_str("+"),
+            BinaryOperator::Sub => f.write_str("-"),
+            BinaryOperator::Mul => f.write_str("*"),
+            BinaryOperator::Div => f.write_str("/"),
+            BinaryOperator::Rem => f.write_str("%"),
+        }
+    }
+}
+
+impl ConditionExpression {
+    pub fn new(kind: ExpressionKind, loc: Loc) -> Self {
+        Self { kind, loc }
+    }
+
+    pub fn unary(op: UnaryOperator, operand: ConditionExpression, loc: Loc) -> Self {
+        Self::new(
+            ExpressionKind::Unary {
+                op,
+                operand: Box::new(operand),
+            },
+            loc,
+        )
+    }
+
+    pub fn binary(op: BinaryOperator, left: ConditionExpression, right: ConditionExpression, loc: Loc) -> Self {
+        Self::new(
+            ExpressionKind::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            loc,
+        )
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ConditionError {
+    #[error("duplicate argument `{0}`")]
+    DuplicateArgument(String),
+    #[error("undefined variable `{0}`")]
+    UndefinedVariable(String),
+    #[error("mismatched types: expected {expected}, found {found}")]
+    MismatchedTypes { expected: String, found: ConditionType },
+    #[error("cannot apply `{op}` to {operand}")]
+    InvalidUnaryOperand { op: UnaryOperator, operand: ConditionType },
+    #[error("cannot apply `{op}` to {left} and {right}")]
+    InvalidOperands {
+        op: BinaryOperator,
+        left: ConditionType,
+        right: ConditionType,
+    },
+    #[error("cannot cast {from} to {to}")]
+    InvalidCast { from: ConditionType, to: ConditionType },
+    #[error("no method `{method}` on {receiver}")]
+    UnknownMethod { receiver: ConditionType, method: String },
+    #[error("method `{method}` takes {expected} argument(s) but {found} were supplied")]
+    ArgumentCount {
+        method: String,
+        expected: usize,
+        found: usize,
+    },
+    #[error("unknown parameter `{0}`")]
+    UnknownParameter(String),
+    #[error("missing parameter `{0}`")]
+    MissingParameter(String),
+    #[error("invalid parameter `{name}`: expected {expected}")]
+    InvalidParameter { name: String, expected: ConditionType },
+    #[error("{0}")]
+    Evaluation(String),
+}
+
+impl Condition {
+    /// Type checks the body against the declared arguments, the body must evaluate to a bool.
+    pub fn type_check(&self) -> Vec<(Loc, ConditionError)> {
+        let mut errors = vec![];
+        let mut scope = HashMap::new();
+        for arg in &self.args {
+            if scope.insert(arg.name.as_str(), &arg.r#type).is_some() {
+                errors.push((self.body.loc, ConditionError::DuplicateArgument(arg.name.clone())));
+            }
+        }
+        if let Some(typ) = infer(&self.body, &scope, &mut errors) {
+            if !ConditionType::Bool.accepts(&typ) {
+                errors.push((
+                    self.body.loc,
+                    ConditionError::MismatchedTypes {
+                        expected: "bool".into(),
+                        found: typ,
+                    },
+                ));
+            }
+        }
+        errors
+    }
+}
+
+/// Infers the type of the expression, returns `None` when an error was already reported for it.
+fn infer(
+    expr: &ConditionExpression,
+    scope: &HashMap<&str, &ConditionType>,
+    errors: &mut Vec<(Loc, ConditionError)>,
+) -> Option<ConditionType> {
+    match &expr.kind {
+        ExpressionKind::Literal(literal) => Some(match literal {
+            Literal::Bool(_) => ConditionType::Bool,
+            Literal::Int(_) => ConditionType::Int,
+            Literal::Double(_) => ConditionType::Double,
+            Literal::String(_) => ConditionType::String,
+        }),
+        ExpressionKind::Identifier(name) => match scope.get(name.as_str()) {
+            Some(typ) => Some((*typ).clone()),
+            None => {
+                errors.push((expr.loc, ConditionError::UndefinedVariable(name.clone())));
+                None
+            }
+        },
+        ExpressionKind::Cast { r#type, expr: inner } => {
+            let from = infer(inner, scope, errors)?;
+            let castable = match r#type {
+                ConditionType::Int | ConditionType::Uint | ConditionType::Double => {
+                    from.is_numeric() || from == ConditionType::String
+                }
+                ConditionType::String => true,
+                ConditionType::Bool => matches!(from, ConditionType::Bool | ConditionType::String),
+                ConditionType::Timestamp | ConditionType::Duration | ConditionType::IPaddress => {
+                    from == ConditionType::String || &from == r#type
+                }
+                _ => false,
+            };
+            if castable || from == ConditionType::Any {
+                Some(r#type.clone())
+            } else {
+                errors.push((
+                    expr.loc,
+                    ConditionError::InvalidCast {
+                        from,
+                        to: r#type.clone(),
+                    },
+                ));
+                None
+            }
+        }
+        ExpressionKind::MethodCall { receiver, method, args } => {
+            let receiver_type = infer(receiver, scope, errors);
+            let arg_types: Vec<_> = args.iter().map(|arg| infer(arg, scope, errors)).collect();
+            let receiver_type = receiver_type?;
+            let (params, ret) = match method_signature(&receiver_type, method) {
+                Some(signature) => signature,
+                None => {
+                    errors.push((
+                        expr.loc,
+                        ConditionError::UnknownMethod {
+                            receiver: receiver_type,
+                            method: method.clone(),
+                        },
+                    ));
+                    return None;
+                }
+            };
+            if receiver_type == ConditionType::Any {
+                return Some(ret);
+            }
+            if params.len() != args.len() {
+                errors.push((
+                    expr.loc,
+                    ConditionError::ArgumentCount {
+                        method: method.clone(),
+                        expected: params.len(),
+                        found: args.len(),
+                    },
+                ));
+                return None;
+            }
+            let mut ok = true;
+            for ((param, arg), arg_type) in params.iter().zip(args).zip(arg_types) {
+                match arg_type {
+                    Some(arg_type) if !param.accepts(&arg_type) => {
+                        errors.push((
+                            arg.loc,
+                            ConditionError::MismatchedTypes {
+                                expected: param.to_string(),
+                                found: arg_type,
+                            },
+                        ));
+                        ok = false;
+                    }
+                    Some(_) => {}
+                    None => ok = false,
+                }
+            }
+            ok.then_some(ret)
+        }
+        ExpressionKind::Unary { op, operand } => {
+            let typ = infer(operand, scope, errors)?;
+            let result = match (op, &typ) {
+                (_, ConditionType::Any) => Some(ConditionType::Any),
+                (UnaryOperator::Not, ConditionType::Bool) => Some(ConditionType::Bool),
+                (UnaryOperator::Neg, ConditionType::Uint) => Some(ConditionType::Int),
+                (UnaryOperator::Neg, ConditionType::Int | ConditionType::Double | ConditionType::Duration) => {
+                    Some(typ.clone())
+                }
+                _ => None,
+            };
+            if result.is_none() {
+                errors.push((expr.loc, ConditionError::InvalidUnaryOperand { op: *op, operand: typ }));
+            }
+            result
+        }
+        ExpressionKind::Binary { op, left, right } => {
+            let l = infer(left, scope, errors);
+            let r = infer(right, scope, errors);
+            let (l, r) = (l?, r?);
+            let result = binary_type(*op, &l, &r);
+            if result.is_none() {
+                errors.push((
+                    expr.loc,
+                    ConditionError::InvalidOperands {
+                        op: *op,
+                        left: l,
+                        right: r,
+                    },
+                ));
+            }
+            result
+        }
+    }
+}
+
+fn binary_type(op: BinaryOperator, l: &ConditionType, r: &ConditionType) -> Option<ConditionType> {
+    use ConditionType::*;
+
+    let any = *l == Any || *r == Any;
+    match op {
+        BinaryOperator::Or | BinaryOperator::And => (Bool.accepts(l) && Bool.accepts(r)).then_some(Bool),
+        BinaryOperator::Eq | BinaryOperator::Ne => (l.accepts(r) || (l.is_numeric() && r.is_numeric())).then_some(Bool),
+        BinaryOperator::Lt | BinaryOperator::Le | BinaryOperator::Gt | BinaryOperator::Ge => {
+            let comparable = any
+                || (l.is_numeric() && r.is_numeric())
+                || (l == r && matches!(l, String | Bytes | Duration | Timestamp));
+            comparable.then_some(Bool)
+        }
+        _ if any => Some(Any),
+        BinaryOperator::Add => match (l, r) {
+            _ if l.is_numeric() && r.is_numeric() => Some(l.numeric_join(r)),
+            (String, String) | (Bytes, Bytes) | (Duration, Duration) => Some(l.clone()),
+            (List(_), List(_)) if l.accepts(r) => Some(l.clone()),
+            (Timestamp, Duration) | (Duration, Timestamp) => Some(Timestamp),
+            _ => None,
+        },
+        BinaryOperator::Sub => match (l, r) {
+            _ if l.is_numeric() && r.is_numeric() => Some(l.numeric_join(r)),
+            (Duration, Duration) | (Timestamp, Timestamp) => Some(Duration),
+            (Timestamp, Duration) => Some(Timestamp),
+            _ => None,
+        },
+        BinaryOperator::Mul | BinaryOperator::Div | BinaryOperator::Rem => {
+            (l.is_numeric() && r.is_numeric()).then(|| l.numeric_join(r))
+        }
+    }
+}
+
+/// Methods callable on a value of the receiver type: (parameter types, return type).
+fn method_signature(receiver: &ConditionType, method: &str) -> Option<(Vec<ConditionType>, ConditionType)> {
+    use ConditionType::*;
+
+    match (receiver, method) {
+        (Any, _) => Some((vec![], Any)),
+        (String | Bytes | List(_) | Map(_), "size") => Some((vec![], Int)),
+        (String, "contains" | "starts_with" | "ends_with") => Some((vec![String], Bool)),
+        (List(t), "contains") => Some((vec![*t.clone()], Bool)),
+        (Map(_), "contains_key") => Some((vec![String], Bool)),
+        (Map(t), "get") => Some((vec![String], *t.clone())),
+        (IPaddress, "in_cidr") => Some((vec![String], Bool)),
+        _ => None,
+    }
 }
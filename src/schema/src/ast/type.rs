@@ -1,49 +1,125 @@
-use protocol::{ObjectRelation, TupleToUserset, Userset};
+use protocol::{ObjectRelation, RelationReference, TupleToUserset, Userset, WILDCARD};
 use schemars::JsonSchema;
 use sea_orm::FromJsonQueryResult;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+use crate::{pos::CodeLocation, Loc};
+
+/// Locations are only kept for diagnostics, they are neither persisted nor compared, a model read back from json
+/// has empty spans.
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
 pub struct Type {
     pub name: String,
+    /// types and mixins whose relations and permissions are inherited, the ones declared here override them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
     pub relations: Vec<Relation>,
     pub permissions: Vec<Permission>,
+    /// the `///` comments before the node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
+}
+
+impl PartialEq for Type {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.extends == other.extends
+            && self.relations == other.relations
+            && self.permissions == other.permissions
+            && self.description == other.description
+    }
+}
+
+impl CodeLocation for Type {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
 }
 
 impl Type {
-    pub fn new(name: String, rops: Vec<RelationOrPermission>) -> Self {
+    pub fn new(name: String, rops: Vec<RelationOrPermission>, loc: Loc) -> Self {
         let mut relations = vec![];
         let mut permissions = vec![];
         for rop in rops {
             match rop {
                 RelationOrPermission::Relation(r) => relations.push(r),
                 RelationOrPermission::Permission(p) => permissions.push(p),
+                RelationOrPermission::Error => {}
             }
         }
         Self {
             name,
+            extends: vec![],
             relations,
             permissions,
+            description: None,
+            loc,
         }
     }
+
+    pub fn with_extends(mut self, extends: Vec<String>) -> Self {
+        self.extends = extends;
+        self
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum RelationOrPermission {
     Relation(Relation),
     Permission(Permission),
+    /// a line that failed to parse, the error is recorded by the parser
+    Error,
 }
 
-#[derive(Debug, PartialEq, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
 pub struct Relation {
     pub name: String,
     pub subjects: Vec<RelationshipSet>,
+    /// the `///` comments before the node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
 }
 
-#[derive(Debug, PartialEq, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+impl PartialEq for Relation {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.subjects == other.subjects && self.description == other.description
+    }
+}
+
+impl CodeLocation for Relation {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+}
+
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
 pub struct Permission {
     pub name: String,
     pub permission: Relationship,
+    /// the `///` comments before the node
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
+}
+
+impl PartialEq for Permission {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.permission == other.permission && self.description == other.description
+    }
+}
+
+impl CodeLocation for Permission {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
@@ -129,24 +205,24 @@ impl Relationship {
         }
     }
 
+    /// The relationship sets of the expression from left to right, `compute` keeps this order.
+    pub fn sets(&self) -> Vec<&RelationshipSet> {
+        match self {
+            Relationship::Set(s) => vec![s],
+            Relationship::Union { children } | Relationship::Intersection { children } => {
+                children.iter().flat_map(|child| child.sets()).collect()
+            }
+            Relationship::Difference { base, subtract } => {
+                let mut sets = base.sets();
+                sets.extend(subtract.sets());
+                sets
+            }
+        }
+    }
+
     pub(crate) fn to_userset(&self) -> Userset {
         match self {
-            Relationship::Set(s) => match s {
-                RelationshipSet::Single(rel) => Userset::Computed(ObjectRelation {
-                    object: "".into(),
-                    relation: rel.into(),
-                }),
-                RelationshipSet::Set(rel, obj) => Userset::TupleTo(TupleToUserset {
-                    tupleset: ObjectRelation {
-                        object: "".into(),
-                        relation: obj.into(),
-                    },
-                    computed_userset: ObjectRelation {
-                        object: "".into(),
-                        relation: rel.into(),
-                    },
-                }),
-            },
+            Relationship::Set(s) => s.to_userset(),
             Relationship::Union { children } => Userset::Union {
                 children: children.iter().map(|child| Box::new(child.to_userset())).collect(),
             },
@@ -161,9 +237,96 @@ impl Relationship {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+/// A relation subject or an operand of a permission, serialized as its kind alone.
+#[derive(Debug, Clone, FromJsonQueryResult, Deserialize, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct RelationshipSet {
+    pub kind: SetKind,
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub loc: Loc,
+}
+
+impl PartialEq for RelationshipSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl CodeLocation for RelationshipSet {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+}
+
+impl From<SetKind> for RelationshipSet {
+    fn from(kind: SetKind) -> Self {
+        Self {
+            kind,
+            loc: Loc::default(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-pub enum RelationshipSet {
+pub enum SetKind {
     Single(String),
     Set(String, String),
+    /// `user with cond`, only allowed as a relation subject
+    Conditional(Box<RelationshipSet>, String),
+}
+
+impl RelationshipSet {
+    pub fn new(kind: SetKind, loc: Loc) -> Self {
+        Self { kind, loc }
+    }
+
+    pub fn single(name: impl Into<String>) -> Self {
+        SetKind::Single(name.into()).into()
+    }
+
+    pub fn set(typ: impl Into<String>, relation: impl Into<String>) -> Self {
+        SetKind::Set(typ.into(), relation.into()).into()
+    }
+
+    pub fn conditional(set: RelationshipSet, condition: impl Into<String>) -> Self {
+        SetKind::Conditional(Box::new(set), condition.into()).into()
+    }
+
+    pub(crate) fn to_userset(&self) -> Userset {
+        match &self.kind {
+            SetKind::Single(rel) => Userset::Computed(ObjectRelation {
+                object: "".into(),
+                relation: rel.into(),
+            }),
+            // `parent#viewer`: the viewer of the objects related by parent
+            SetKind::Set(tupleset, computed) => Userset::TupleTo(TupleToUserset {
+                tupleset: ObjectRelation {
+                    object: "".into(),
+                    relation: tupleset.into(),
+                },
+                computed_userset: ObjectRelation {
+                    object: "".into(),
+                    relation: computed.into(),
+                },
+            }),
+            SetKind::Conditional(set, _) => set.to_userset(),
+        }
+    }
+
+    pub(crate) fn to_relation_reference(&self) -> RelationReference {
+        match &self.kind {
+            SetKind::Single(user) => RelationReference::Direct(String::from(user)),
+            SetKind::Set(user, relation) if relation.eq(WILDCARD) => RelationReference::Wildcard(String::from(user)),
+            SetKind::Set(user, relation) => RelationReference::Relation {
+                r#type: String::from(user),
+                relation: String::from(relation),
+            },
+            SetKind::Conditional(set, condition) => RelationReference::Conditional {
+                reference: Box::new(set.to_relation_reference()),
+                condition: String::from(condition),
+            },
+        }
+    }
 }
@@ -0,0 +1,146 @@
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::Loc;
+
+/// A problem found in a model, `loc` is `None` when the node carries no source span.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Diagnostic {
+    pub loc: Option<Loc>,
+    pub message: String,
+    #[serde(default)]
+    pub severity: Severity,
+    /// the file `loc` belongs to when the model is composed of several files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// 1-based position of `loc`, filled by `with_source`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    /// the source line of `loc` with a caret under the span, filled by `with_source`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+/// Warnings do not prevent a model from being saved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => f.write_str("error"),
+            Severity::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Converts a byte offset of `input` to a line and a column counted in chars.
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let offset = floor_char_boundary(input, offset);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Diagnostic {
+    pub fn new(loc: Option<Loc>, message: impl Into<String>) -> Self {
+        Self {
+            loc,
+            message: message.into(),
+            severity: Severity::Error,
+            file: None,
+            position: None,
+            snippet: None,
+        }
+    }
+
+    pub fn warning(loc: Option<Loc>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::new(loc, message)
+        }
+    }
+
+    /// Resolves the position and renders the snippet of the span against the source it was reported on.
+    pub fn with_source(mut self, input: &str) -> Self {
+        if let Some((start, end)) = self.loc {
+            let position = Position::from_offset(input, start);
+            self.snippet = Some(render_snippet(input, position, start, end));
+            self.position = Some(position);
+        }
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+            if self.position.is_none() {
+                f.write_str(" ")?;
+            }
+        }
+        if let Some(Position { line, column }) = self.position {
+            write!(f, "{}:{}: ", line, column)?;
+        }
+        f.write_str(&self.message)?;
+        if let Some(snippet) = &self.snippet {
+            write!(f, "\n{}", snippet)?;
+        }
+        Ok(())
+    }
+}
+
+/// ```text
+/// 3 |   relation : user
+///   |            ^
+/// ```
+fn render_snippet(input: &str, position: Position, start: usize, end: usize) -> String {
+    let start = floor_char_boundary(input, start);
+    let line_start = input[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = input[start..].find('\n').map(|i| start + i).unwrap_or(input.len());
+    let line = &input[line_start..line_end];
+    let end = floor_char_boundary(input, end.clamp(start, line_end));
+    let width = input[start..end].chars().count().max(1);
+    let number = position.line.to_string();
+    let gutter = " ".repeat(number.len());
+    format!(
+        "{} | {}\n{} | {}{}",
+        number,
+        line,
+        gutter,
+        " ".repeat(position.column - 1),
+        "^".repeat(width)
+    )
+}
+
+fn floor_char_boundary(input: &str, offset: usize) -> usize {
+    let mut offset = offset.min(input.len());
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{flatten, Condition, Permission, Relation, Relationship, Schema, Type};
+
+/// One difference between two models.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub impact: Impact,
+    /// `doc`, `doc#viewer`, the relation of a superadmin or the name of a condition
+    pub target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    TypeAdded,
+    TypeRemoved,
+    RelationAdded,
+    RelationRemoved,
+    /// a type allowed as subject of a relation
+    DirectTypeAdded,
+    DirectTypeRemoved,
+    PermissionAdded,
+    PermissionRemoved,
+    PermissionChanged,
+    ConditionAdded,
+    ConditionRemoved,
+    ConditionChanged,
+    SuperadminAdded,
+    SuperadminRemoved,
+}
+
+/// Breaking changes can make stored tuples meaningless or revoke access that was granted before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Impact {
+    Additive,
+    Breaking,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, impact: Impact, target: String) -> Self {
+        Self {
+            kind,
+            impact,
+            target,
+            from: None,
+            to: None,
+        }
+    }
+
+    fn from(mut self, from: impl ToString) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    fn to(mut self, to: impl ToString) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.impact == Impact::Breaking
+    }
+}
+
+/// Lists what changes from `old` to `new`, types, relations and conditions in the order of `old` then the ones
+/// added by `new`. Inherited members are compared as if they were declared by the types.
+pub fn diff(old: &Schema, new: &Schema) -> Vec<Change> {
+    let mut changes = vec![];
+    let (old, new) = (&flatten(old), &flatten(new));
+
+    let new_types: HashMap<&str, &Type> = new.types.iter().map(|t| (t.name.as_str(), t)).collect();
+    for typ in &old.types {
+        match new_types.get(typ.name.as_str()) {
+            Some(new_type) => diff_type(typ, new_type, &mut changes),
+            None => changes.push(Change::new(ChangeKind::TypeRemoved, Impact::Breaking, typ.name.clone())),
+        }
+    }
+    for typ in &new.types {
+        if !old.types.iter().any(|t| t.name.eq(&typ.name)) {
+            changes.push(Change::new(ChangeKind::TypeAdded, Impact::Additive, typ.name.clone()));
+            for rel in &typ.relations {
+                changes.push(relation_added(typ, rel));
+            }
+            for perm in &typ.permissions {
+                changes.push(permission_added(typ, perm));
+            }
+        }
+    }
+
+    let new_conditions: HashMap<&str, &Condition> = new.conditions.iter().map(|c| (c.name.as_str(), c)).collect();
+    for cond in &old.conditions {
+        match new_conditions.get(cond.name.as_str()) {
+            Some(new_cond) if cond.eq(new_cond) => {}
+            Some(new_cond) => changes.push(
+                Change::new(ChangeKind::ConditionChanged, Impact::Breaking, cond.name.clone())
+                    .from(cond)
+                    .to(new_cond),
+            ),
+            None => {
+                changes.push(Change::new(ChangeKind::ConditionRemoved, Impact::Breaking, cond.name.clone()).from(cond))
+            }
+        }
+    }
+    for cond in &new.conditions {
+        if !old.conditions.iter().any(|c| c.name.eq(&cond.name)) {
+            changes.push(Change::new(ChangeKind::ConditionAdded, Impact::Additive, cond.name.clone()).to(cond));
+        }
+    }
+
+    for superadmin in &old.superadmins {
+        if !new.superadmins.contains(superadmin) {
+            changes.push(Change::new(
+                ChangeKind::SuperadminRemoved,
+                Impact::Breaking,
+                format!("{}#{}", superadmin.r#type, superadmin.relation),
+            ));
+        }
+    }
+    for superadmin in &new.superadmins {
+        if !old.superadmins.contains(superadmin) {
+            changes.push(Change::new(
+                ChangeKind::SuperadminAdded,
+                Impact::Additive,
+                format!("{}#{}", superadmin.r#type, superadmin.relation),
+            ));
+        }
+    }
+
+    changes
+}
+
+fn diff_type(old: &Type, new: &Type, changes: &mut Vec<Change>) {
+    for rel in &old.relations {
+        match new.relations.iter().find(|r| r.name.eq(&rel.name)) {
+            Some(new_rel) => {
+                let target = format!("{}#{}", old.name, rel.name);
+                for sub in &rel.subjects {
+                    if !new_rel.subjects.contains(sub) {
+                        changes.push(
+                            Change::new(ChangeKind::DirectTypeRemoved, Impact::Breaking, target.clone()).from(sub),
+                        );
+                    }
+                }
+                for sub in &new_rel.subjects {
+                    if !rel.subjects.contains(sub) {
+                        changes
+                            .push(Change::new(ChangeKind::DirectTypeAdded, Impact::Additive, target.clone()).to(sub));
+                    }
+                }
+            }
+            None => changes.push(
+                Change::new(
+                    ChangeKind::RelationRemoved,
+                    Impact::Breaking,
+                    format!("{}#{}", old.name, rel.name),
+                )
+                .from(rel),
+            ),
+        }
+    }
+    for rel in &new.relations {
+        if !old.relations.iter().any(|r| r.name.eq(&rel.name)) {
+            changes.push(relation_added(new, rel));
+        }
+    }
+
+    for perm in &old.permissions {
+        match new.permissions.iter().find(|p| p.name.eq(&perm.name)) {
+            Some(new_perm) if perm.permission.eq(&new_perm.permission) => {}
+            Some(new_perm) => {
+                let impact = if widens(&perm.permission, &new_perm.permission) {
+                    Impact::Additive
+                } else {
+                    Impact::Breaking
+                };
+                changes.push(
+                    Change::new(
+                        ChangeKind::PermissionChanged,
+                        impact,
+                        format!("{}#{}", old.name, perm.name),
+                    )
+                    .from(&perm.permission)
+                    .to(&new_perm.permission),
+                );
+            }
+            None => changes.push(
+                Change::new(
+                    ChangeKind::PermissionRemoved,
+                    Impact::Breaking,
+                    format!("{}#{}", old.name, perm.name),
+                )
+                .from(&perm.permission),
+            ),
+        }
+    }
+    for perm in &new.permissions {
+        if !old.permissions.iter().any(|p| p.name.eq(&perm.name)) {
+            changes.push(permission_added(new, perm));
+        }
+    }
+}
+
+fn relation_added(typ: &Type, rel: &Relation) -> Change {
+    Change::new(
+        ChangeKind::RelationAdded,
+        Impact::Additive,
+        format!("{}#{}", typ.name, rel.name),
+    )
+    .to(rel)
+}
+
+fn permission_added(typ: &Type, perm: &Permission) -> Change {
+    Change::new(
+        ChangeKind::PermissionAdded,
+        Impact::Additive,
+        format!("{}#{}", typ.name, perm.name),
+    )
+    .to(&perm.permission)
+}
+
+/// `viewer` to `viewer + owner` only grants more, any other rewrite may revoke access
+fn widens(old: &Relationship, new: &Relationship) -> bool {
+    let Relationship::Union { children: new_children } = new else {
+        return false;
+    };
+    match old {
+        Relationship::Union { children } => children.iter().all(|c| new_children.contains(c)),
+        old => new_children.iter().any(|c| c.as_ref().eq(old)),
+    }
+}
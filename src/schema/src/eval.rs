@@ -0,0 +1,505 @@
+use std::{cmp::Ordering, collections::HashMap, fmt, net::IpAddr};
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    BinaryOperator, Condition, ConditionError, ConditionExpression, ConditionType, ExpressionKind, Literal,
+    UnaryOperator,
+};
+
+/// Runtime value of a condition expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionValue {
+    Bool(bool),
+    Int(i64),
+    Uint(u64),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    Duration(Duration),
+    Timestamp(DateTime<Utc>),
+    IPaddress(IpAddr),
+    List(Vec<ConditionValue>),
+    Map(HashMap<String, ConditionValue>),
+}
+
+impl Condition {
+    /// Evaluates the condition with the given context, every declared argument must be present in the context.
+    pub fn evaluate(&self, context: &HashMap<String, JsonValue>) -> Result<bool, ConditionError> {
+        let mut scope = HashMap::new();
+        for arg in &self.args {
+            let value = context
+                .get(&arg.name)
+                .ok_or_else(|| ConditionError::MissingParameter(arg.name.clone()))?;
+            let value =
+                ConditionValue::from_json(value, &arg.r#type).ok_or_else(|| ConditionError::InvalidParameter {
+                    name: arg.name.clone(),
+                    expected: arg.r#type.clone(),
+                })?;
+            scope.insert(arg.name.as_str(), value);
+        }
+        match eval(&self.body, &scope)? {
+            ConditionValue::Bool(allow) => Ok(allow),
+            value => Err(evaluation(format!(
+                "condition evaluates to {}, not bool",
+                value.type_name()
+            ))),
+        }
+    }
+
+    /// Checks a partial context, as stored on a tuple, against the declared arguments.
+    pub fn validate_context(&self, context: &HashMap<String, JsonValue>) -> Result<(), ConditionError> {
+        for (name, value) in context {
+            let arg = self
+                .args
+                .iter()
+                .find(|arg| arg.name.eq(name))
+                .ok_or_else(|| ConditionError::UnknownParameter(name.clone()))?;
+            if ConditionValue::from_json(value, &arg.r#type).is_none() {
+                return Err(ConditionError::InvalidParameter {
+                    name: name.clone(),
+                    expected: arg.r#type.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ConditionValue {
+    /// Converts a json value into the declared type, `None` if the value does not fit.
+    pub fn from_json(value: &JsonValue, typ: &ConditionType) -> Option<ConditionValue> {
+        match typ {
+            ConditionType::Int => value.as_i64().map(ConditionValue::Int),
+            ConditionType::Uint => value.as_u64().map(ConditionValue::Uint),
+            ConditionType::Double => value.as_f64().map(ConditionValue::Double),
+            ConditionType::Bool => value.as_bool().map(ConditionValue::Bool),
+            ConditionType::Bytes => value.as_str().map(|s| ConditionValue::Bytes(s.as_bytes().to_vec())),
+            ConditionType::String => value.as_str().map(|s| ConditionValue::String(s.to_owned())),
+            ConditionType::Duration => value.as_str().and_then(parse_duration).map(ConditionValue::Duration),
+            ConditionType::Timestamp => value.as_str().and_then(parse_timestamp).map(ConditionValue::Timestamp),
+            ConditionType::IPaddress => value.as_str()?.parse().ok().map(ConditionValue::IPaddress),
+            ConditionType::List(t) => value
+                .as_array()?
+                .iter()
+                .map(|v| ConditionValue::from_json(v, t))
+                .collect::<Option<_>>()
+                .map(ConditionValue::List),
+            ConditionType::Map(t) => value
+                .as_object()?
+                .iter()
+                .map(|(k, v)| ConditionValue::from_json(v, t).map(|v| (k.to_owned(), v)))
+                .collect::<Option<_>>()
+                .map(ConditionValue::Map),
+            ConditionType::Any => match value {
+                JsonValue::Null => None,
+                JsonValue::Bool(b) => Some(ConditionValue::Bool(*b)),
+                JsonValue::Number(n) => n
+                    .as_i64()
+                    .map(ConditionValue::Int)
+                    .or_else(|| n.as_u64().map(ConditionValue::Uint))
+                    .or_else(|| n.as_f64().map(ConditionValue::Double)),
+                JsonValue::String(s) => Some(ConditionValue::String(s.to_owned())),
+                JsonValue::Array(_) => {
+                    ConditionValue::from_json(value, &ConditionType::List(Box::new(ConditionType::Any)))
+                }
+                JsonValue::Object(_) => {
+                    ConditionValue::from_json(value, &ConditionType::Map(Box::new(ConditionType::Any)))
+                }
+            },
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ConditionValue::Bool(_) => "bool",
+            ConditionValue::Int(_) => "int",
+            ConditionValue::Uint(_) => "uint",
+            ConditionValue::Double(_) => "double",
+            ConditionValue::Bytes(_) => "bytes",
+            ConditionValue::String(_) => "string",
+            ConditionValue::Duration(_) => "duration",
+            ConditionValue::Timestamp(_) => "timestamp",
+            ConditionValue::IPaddress(_) => "ipaddress",
+            ConditionValue::List(_) => "list",
+            ConditionValue::Map(_) => "map",
+        }
+    }
+}
+
+impl fmt::Display for ConditionValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConditionValue::Bool(b) => write!(f, "{}", b),
+            ConditionValue::Int(i) => write!(f, "{}", i),
+            ConditionValue::Uint(u) => write!(f, "{}", u),
+            ConditionValue::Double(d) => write!(f, "{}", d),
+            ConditionValue::Bytes(b) => f.write_str(&String::from_utf8_lossy(b)),
+            ConditionValue::String(s) => f.write_str(s),
+            ConditionValue::Duration(d) => write!(f, "{}s", d.num_milliseconds() as f64 / 1000.0),
+            ConditionValue::Timestamp(t) => f.write_str(&t.to_rfc3339()),
+            ConditionValue::IPaddress(ip) => write!(f, "{}", ip),
+            ConditionValue::List(list) => {
+                f.write_str("[")?;
+                for (i, v) in list.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+                f.write_str("]")
+            }
+            ConditionValue::Map(map) => {
+                let mut keys: Vec<_> = map.keys().collect();
+                keys.sort();
+                f.write_str("{")?;
+                for (i, k) in keys.into_iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}: {}", k, map[k])?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+fn evaluation(message: impl Into<String>) -> ConditionError {
+    ConditionError::Evaluation(message.into())
+}
+
+fn eval(expr: &ConditionExpression, scope: &HashMap<&str, ConditionValue>) -> Result<ConditionValue, ConditionError> {
+    match &expr.kind {
+        ExpressionKind::Literal(literal) => Ok(match literal {
+            Literal::Bool(b) => ConditionValue::Bool(*b),
+            Literal::Int(i) => ConditionValue::Int(*i),
+            Literal::Double(d) => ConditionValue::Double(*d),
+            Literal::String(s) => ConditionValue::String(s.to_owned()),
+        }),
+        ExpressionKind::Identifier(name) => scope
+            .get(name.as_str())
+            .cloned()
+            .ok_or_else(|| ConditionError::UndefinedVariable(name.to_owned())),
+        ExpressionKind::Cast { r#type, expr } => cast(eval(expr, scope)?, r#type),
+        ExpressionKind::MethodCall { receiver, method, args } => {
+            let receiver = eval(receiver, scope)?;
+            let args = args.iter().map(|arg| eval(arg, scope)).collect::<Result<Vec<_>, _>>()?;
+            call_method(receiver, method, args)
+        }
+        ExpressionKind::Unary { op, operand } => {
+            let value = eval(operand, scope)?;
+            match (op, value) {
+                (UnaryOperator::Not, ConditionValue::Bool(b)) => Ok(ConditionValue::Bool(!b)),
+                (UnaryOperator::Neg, ConditionValue::Int(i)) => {
+                    i.checked_neg().map(ConditionValue::Int).ok_or_else(overflow)
+                }
+                (UnaryOperator::Neg, ConditionValue::Uint(u)) => i64::try_from(u)
+                    .ok()
+                    .and_then(i64::checked_neg)
+                    .map(ConditionValue::Int)
+                    .ok_or_else(overflow),
+                (UnaryOperator::Neg, ConditionValue::Double(d)) => Ok(ConditionValue::Double(-d)),
+                (UnaryOperator::Neg, ConditionValue::Duration(d)) => Ok(ConditionValue::Duration(-d)),
+                (op, value) => Err(evaluation(format!("cannot apply `{}` to {}", op, value.type_name()))),
+            }
+        }
+        ExpressionKind::Binary { op, left, right } => match op {
+            BinaryOperator::Or | BinaryOperator::And => {
+                let short_circuit = *op == BinaryOperator::Or;
+                if as_bool(eval(left, scope)?, *op)? == short_circuit {
+                    return Ok(ConditionValue::Bool(short_circuit));
+                }
+                as_bool(eval(right, scope)?, *op).map(ConditionValue::Bool)
+            }
+            _ => binary(*op, eval(left, scope)?, eval(right, scope)?),
+        },
+    }
+}
+
+fn as_bool(value: ConditionValue, op: BinaryOperator) -> Result<bool, ConditionError> {
+    match value {
+        ConditionValue::Bool(b) => Ok(b),
+        value => Err(evaluation(format!("cannot apply `{}` to {}", op, value.type_name()))),
+    }
+}
+
+fn overflow() -> ConditionError {
+    evaluation("integer overflow")
+}
+
+fn binary(op: BinaryOperator, l: ConditionValue, r: ConditionValue) -> Result<ConditionValue, ConditionError> {
+    use ConditionValue::*;
+
+    let invalid = |l: &ConditionValue, r: &ConditionValue| {
+        evaluation(format!(
+            "cannot apply `{}` to {} and {}",
+            op,
+            l.type_name(),
+            r.type_name()
+        ))
+    };
+    match op {
+        BinaryOperator::Eq => Ok(Bool(values_eq(&l, &r))),
+        BinaryOperator::Ne => Ok(Bool(!values_eq(&l, &r))),
+        BinaryOperator::Lt | BinaryOperator::Le | BinaryOperator::Gt | BinaryOperator::Ge => {
+            let ordering = compare(&l, &r).ok_or_else(|| invalid(&l, &r))?;
+            Ok(Bool(match op {
+                BinaryOperator::Lt => ordering == Ordering::Less,
+                BinaryOperator::Le => ordering != Ordering::Greater,
+                BinaryOperator::Gt => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            }))
+        }
+        _ => match (&l, &r) {
+            (Int(_) | Uint(_) | Double(_), Int(_) | Uint(_) | Double(_)) => arithmetic(op, &l, &r),
+            (String(a), String(b)) if op == BinaryOperator::Add => Ok(String(format!("{}{}", a, b))),
+            (Bytes(a), Bytes(b)) if op == BinaryOperator::Add => Ok(Bytes([a.as_slice(), b.as_slice()].concat())),
+            (List(a), List(b)) if op == BinaryOperator::Add => Ok(List([a.as_slice(), b.as_slice()].concat())),
+            (Duration(a), Duration(b)) => match op {
+                BinaryOperator::Add => a.checked_add(b).map(Duration).ok_or_else(overflow),
+                BinaryOperator::Sub => a.checked_sub(b).map(Duration).ok_or_else(overflow),
+                _ => Err(invalid(&l, &r)),
+            },
+            (Timestamp(t), Duration(d)) | (Duration(d), Timestamp(t)) if op == BinaryOperator::Add => {
+                t.checked_add_signed(*d).map(Timestamp).ok_or_else(overflow)
+            }
+            (Timestamp(t), Duration(d)) if op == BinaryOperator::Sub => {
+                t.checked_sub_signed(*d).map(Timestamp).ok_or_else(overflow)
+            }
+            (Timestamp(a), Timestamp(b)) if op == BinaryOperator::Sub => Ok(Duration(a.signed_duration_since(*b))),
+            _ => Err(invalid(&l, &r)),
+        },
+    }
+}
+
+fn arithmetic(op: BinaryOperator, l: &ConditionValue, r: &ConditionValue) -> Result<ConditionValue, ConditionError> {
+    use ConditionValue::*;
+
+    match (l, r) {
+        (Double(_), _) | (_, Double(_)) => {
+            let (a, b) = (to_f64(l), to_f64(r));
+            Ok(Double(match op {
+                BinaryOperator::Add => a + b,
+                BinaryOperator::Sub => a - b,
+                BinaryOperator::Mul => a * b,
+                BinaryOperator::Div => a / b,
+                _ => a % b,
+            }))
+        }
+        (Uint(a), Uint(b)) => {
+            let result = match op {
+                BinaryOperator::Add => a.checked_add(*b),
+                BinaryOperator::Sub => a.checked_sub(*b),
+                BinaryOperator::Mul => a.checked_mul(*b),
+                BinaryOperator::Div => a.checked_div(*b),
+                _ => a.checked_rem(*b),
+            };
+            result.map(Uint).ok_or_else(|| division_or_overflow(op, *b == 0))
+        }
+        _ => {
+            let a = to_i64(l).ok_or_else(overflow)?;
+            let b = to_i64(r).ok_or_else(overflow)?;
+            let result = match op {
+                BinaryOperator::Add => a.checked_add(b),
+                BinaryOperator::Sub => a.checked_sub(b),
+                BinaryOperator::Mul => a.checked_mul(b),
+                BinaryOperator::Div => a.checked_div(b),
+                _ => a.checked_rem(b),
+            };
+            result.map(Int).ok_or_else(|| division_or_overflow(op, b == 0))
+        }
+    }
+}
+
+fn division_or_overflow(op: BinaryOperator, zero_divisor: bool) -> ConditionError {
+    if zero_divisor && matches!(op, BinaryOperator::Div | BinaryOperator::Rem) {
+        evaluation("division by zero")
+    } else {
+        overflow()
+    }
+}
+
+fn to_f64(value: &ConditionValue) -> f64 {
+    match value {
+        ConditionValue::Int(i) => *i as f64,
+        ConditionValue::Uint(u) => *u as f64,
+        ConditionValue::Double(d) => *d,
+        _ => f64::NAN,
+    }
+}
+
+fn to_i64(value: &ConditionValue) -> Option<i64> {
+    match value {
+        ConditionValue::Int(i) => Some(*i),
+        ConditionValue::Uint(u) => i64::try_from(*u).ok(),
+        _ => None,
+    }
+}
+
+fn compare(l: &ConditionValue, r: &ConditionValue) -> Option<Ordering> {
+    use ConditionValue::*;
+
+    match (l, r) {
+        (Int(a), Int(b)) => Some(a.cmp(b)),
+        (Uint(a), Uint(b)) => Some(a.cmp(b)),
+        (Int(a), Uint(b)) => Some(i128::from(*a).cmp(&i128::from(*b))),
+        (Uint(a), Int(b)) => Some(i128::from(*a).cmp(&i128::from(*b))),
+        (Int(_) | Uint(_) | Double(_), Int(_) | Uint(_) | Double(_)) => to_f64(l).partial_cmp(&to_f64(r)),
+        (String(a), String(b)) => Some(a.cmp(b)),
+        (Bytes(a), Bytes(b)) => Some(a.cmp(b)),
+        (Duration(a), Duration(b)) => Some(a.cmp(b)),
+        (Timestamp(a), Timestamp(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+fn values_eq(l: &ConditionValue, r: &ConditionValue) -> bool {
+    use ConditionValue::*;
+
+    match (l, r) {
+        (Int(_) | Uint(_) | Double(_), Int(_) | Uint(_) | Double(_)) => compare(l, r) == Some(Ordering::Equal),
+        (List(a), List(b)) => a.len() == b.len() && a.iter().zip(b).all(|(a, b)| values_eq(a, b)),
+        (Map(a), Map(b)) => a.len() == b.len() && a.iter().all(|(k, v)| b.get(k).is_some_and(|w| values_eq(v, w))),
+        _ => l == r,
+    }
+}
+
+fn cast(value: ConditionValue, typ: &ConditionType) -> Result<ConditionValue, ConditionError> {
+    use ConditionValue::*;
+
+    let from = value.type_name();
+    let result = match (typ, value) {
+        (ConditionType::Int, Int(i)) => Some(Int(i)),
+        (ConditionType::Int, Uint(u)) => i64::try_from(u).ok().map(Int),
+        (ConditionType::Int, Double(d)) => {
+            (d.is_finite() && d >= i64::MIN as f64 && d < i64::MAX as f64).then_some(Int(d as i64))
+        }
+        (ConditionType::Int, String(s)) => s.trim().parse().ok().map(Int),
+        (ConditionType::Uint, Uint(u)) => Some(Uint(u)),
+        (ConditionType::Uint, Int(i)) => u64::try_from(i).ok().map(Uint),
+        (ConditionType::Uint, Double(d)) => {
+            (d.is_finite() && d >= 0.0 && d < u64::MAX as f64).then_some(Uint(d as u64))
+        }
+        (ConditionType::Uint, String(s)) => s.trim().parse().ok().map(Uint),
+        (ConditionType::Double, v @ (Int(_) | Uint(_) | Double(_))) => Some(Double(to_f64(&v))),
+        (ConditionType::Double, String(s)) => s.trim().parse().ok().map(Double),
+        (ConditionType::String, v) => Some(String(v.to_string())),
+        (ConditionType::Bool, Bool(b)) => Some(Bool(b)),
+        (ConditionType::Bool, String(s)) => s.parse().ok().map(Bool),
+        (ConditionType::Duration, v @ Duration(_)) => Some(v),
+        (ConditionType::Duration, String(s)) => parse_duration(&s).map(Duration),
+        (ConditionType::Timestamp, v @ Timestamp(_)) => Some(v),
+        (ConditionType::Timestamp, String(s)) => parse_timestamp(&s).map(Timestamp),
+        (ConditionType::IPaddress, v @ IPaddress(_)) => Some(v),
+        (ConditionType::IPaddress, String(s)) => s.parse().ok().map(IPaddress),
+        _ => None,
+    };
+    result.ok_or_else(|| evaluation(format!("cannot cast {} to {}", from, typ)))
+}
+
+fn call_method(
+    receiver: ConditionValue,
+    method: &str,
+    args: Vec<ConditionValue>,
+) -> Result<ConditionValue, ConditionError> {
+    use ConditionValue::*;
+
+    let result = match (&receiver, method, args.as_slice()) {
+        (String(s), "size", []) => Some(Int(s.chars().count() as i64)),
+        (Bytes(b), "size", []) => Some(Int(b.len() as i64)),
+        (List(l), "size", []) => Some(Int(l.len() as i64)),
+        (Map(m), "size", []) => Some(Int(m.len() as i64)),
+        (String(s), "contains", [String(sub)]) => Some(Bool(s.contains(sub.as_str()))),
+        (String(s), "starts_with", [String(prefix)]) => Some(Bool(s.starts_with(prefix.as_str()))),
+        (String(s), "ends_with", [String(suffix)]) => Some(Bool(s.ends_with(suffix.as_str()))),
+        (List(l), "contains", [v]) => Some(Bool(l.iter().any(|x| values_eq(x, v)))),
+        (Map(m), "contains_key", [String(k)]) => Some(Bool(m.contains_key(k))),
+        (Map(m), "get", [String(k)]) => {
+            return m
+                .get(k)
+                .cloned()
+                .ok_or_else(|| evaluation(format!("no such key `{}`", k)))
+        }
+        (IPaddress(ip), "in_cidr", [String(cidr)]) => return in_cidr(ip, cidr).map(Bool),
+        _ => None,
+    };
+    result.ok_or_else(|| {
+        evaluation(format!(
+            "no method `{}` on {} with argument(s) ({})",
+            method,
+            receiver.type_name(),
+            args.iter().map(|a| a.type_name()).collect::<Vec<_>>().join(", ")
+        ))
+    })
+}
+
+fn in_cidr(ip: &IpAddr, cidr: &str) -> Result<bool, ConditionError> {
+    let invalid = || evaluation(format!("invalid cidr `{}`", cidr));
+    let (network, prefix) = match cidr.split_once('/') {
+        Some((network, prefix)) => (
+            network.parse::<IpAddr>().map_err(|_| invalid())?,
+            Some(prefix.parse::<u32>().map_err(|_| invalid())?),
+        ),
+        None => (cidr.parse::<IpAddr>().map_err(|_| invalid())?, None),
+    };
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let prefix = prefix.unwrap_or(32);
+            if prefix > 32 {
+                return Err(invalid());
+            }
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            Ok(u32::from(*ip) & mask == u32::from(network) & mask)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let prefix = prefix.unwrap_or(128);
+            if prefix > 128 {
+                return Err(invalid());
+            }
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            Ok(u128::from(*ip) & mask == u128::from(network) & mask)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s).ok().map(|t| t.with_timezone(&Utc))
+}
+
+/// Parses durations such as `1h30m`, `1.5s`, `-20ms`, supported units are `h`, `m`, `s`, `ms`, `us` and `ns`.
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let (negative, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total = Duration::zero();
+    while !rest.is_empty() {
+        let number_end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+        if number_end == 0 {
+            return None;
+        }
+        let amount: f64 = rest[..number_end].parse().ok()?;
+        rest = &rest[number_end..];
+        let unit_end = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let nanos: f64 = match &rest[..unit_end] {
+            "h" => 3_600_000_000_000.0,
+            "m" => 60_000_000_000.0,
+            "s" => 1_000_000_000.0,
+            "ms" => 1_000_000.0,
+            "us" | "µs" => 1_000.0,
+            "ns" => 1.0,
+            _ => return None,
+        };
+        total = total.checked_add(&Duration::nanoseconds((amount * nanos) as i64))?;
+        rest = &rest[unit_end..];
+    }
+    Some(if negative { -total } else { total })
+}
@@ -0,0 +1,383 @@
+use std::fmt::{self, Write};
+
+use protocol::WILDCARD;
+
+use crate::{
+    BinaryOperator, Condition, ConditionExpression, ExpressionKind, Literal, Loc, Permission, Relation, Relationship,
+    RelationshipSet, Schema, SetKind, Superadmin, Type,
+};
+
+const INDENT: &str = "  ";
+
+/// Prints the model as canonical dsl, `parse(&format(&s))` yields the same model.
+pub fn format(schema: &Schema) -> String {
+    format_with_comments(schema, &[], "")
+}
+
+/// Like `format`, the comments collected by the `Lexer` from `input` are printed on their own line before the node
+/// that follows them in the source, or at the end of the line they trail. Units and members keep their source order,
+/// models read back from json carry no locations and are printed superadmins first, then mixins and types.
+pub fn format_with_comments(schema: &Schema, comments: &[(Loc, String)], input: &str) -> String {
+    let mut printer = Printer {
+        out: String::new(),
+        comments: comments.iter().collect(),
+        input,
+    };
+    printer.comments.sort_by_key(|(loc, _)| *loc);
+    printer.comments.reverse();
+    printer.schema(schema);
+    printer.out
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format(self))
+    }
+}
+
+enum Unit<'a> {
+    Type(&'a Type),
+    Mixin(&'a Type),
+    Condition(&'a Condition),
+    Superadmin(&'a Superadmin),
+}
+
+enum Member<'a> {
+    Relation(&'a Relation),
+    Permission(&'a Permission),
+}
+
+struct Printer<'a> {
+    out: String,
+    /// pending comments, the next one is the last
+    comments: Vec<&'a (Loc, String)>,
+    /// the source of the comments
+    input: &'a str,
+}
+
+impl<'a> Printer<'a> {
+    fn schema(&mut self, schema: &Schema) {
+        let mut units: Vec<(Loc, Unit)> = schema
+            .superadmins
+            .iter()
+            .map(|s| (s.loc, Unit::Superadmin(s)))
+            .collect();
+        units.extend(schema.mixins.iter().map(|m| (m.loc, Unit::Mixin(m))));
+        units.extend(schema.types.iter().map(|t| (t.loc, Unit::Type(t))));
+        units.extend(schema.conditions.iter().map(|c| (c.loc, Unit::Condition(c))));
+        units.sort_by_key(|(loc, _)| loc.0);
+        for import in &schema.imports {
+            self.comments_before(import.loc.0, "");
+            let _ = writeln!(self.out, "import {}", Quoted(&import.path));
+        }
+        let mut previous: Option<&Unit> = None;
+        for (loc, unit) in &units {
+            // consecutive superadmins are kept together like imports
+            let grouped = matches!((previous, unit), (Some(Unit::Superadmin(_)), Unit::Superadmin(_)));
+            if (previous.is_some() || !schema.imports.is_empty()) && !grouped {
+                self.out.push('\n');
+            }
+            self.comments_before(loc.0, "");
+            match unit {
+                Unit::Type(typ) => self.typ("type", typ),
+                Unit::Mixin(mixin) => self.typ("mixin", mixin),
+                Unit::Condition(cond) => self.condition(cond),
+                Unit::Superadmin(superadmin) => {
+                    let _ = writeln!(self.out, "{}", superadmin);
+                }
+            }
+            previous = Some(unit);
+        }
+        self.comments_before(usize::MAX, "");
+    }
+
+    fn typ(&mut self, keyword: &str, typ: &Type) {
+        let mut members: Vec<(Loc, Member)> = typ.relations.iter().map(|r| (r.loc, Member::Relation(r))).collect();
+        members.extend(typ.permissions.iter().map(|p| (p.loc, Member::Permission(p))));
+        members.sort_by_key(|(loc, _)| loc.0);
+        self.description(typ.description.as_deref(), "");
+        let _ = write!(self.out, "{} {}", keyword, typ.name);
+        if !typ.extends.is_empty() {
+            let _ = write!(self.out, " extends {}", typ.extends.join(", "));
+        }
+        if members.is_empty() {
+            self.out.push_str(" {}\n");
+            return;
+        }
+        self.out.push_str(" {\n");
+        for (loc, member) in members {
+            self.comments_before(loc.0, INDENT);
+            let _ = match member {
+                Member::Relation(rel) => {
+                    self.description(rel.description.as_deref(), INDENT);
+                    writeln!(self.out, "{}{}", INDENT, rel)
+                }
+                Member::Permission(perm) => {
+                    self.description(perm.description.as_deref(), INDENT);
+                    writeln!(self.out, "{}{}", INDENT, perm)
+                }
+            };
+        }
+        self.comments_before(typ.loc.1, INDENT);
+        self.out.push_str("}\n");
+    }
+
+    fn condition(&mut self, cond: &Condition) {
+        let _ = writeln!(self.out, "{} {{", ConditionSignature(cond));
+        self.comments_before(cond.body.loc.0, INDENT);
+        let _ = writeln!(self.out, "{}{}", INDENT, cond.body);
+        self.comments_before(cond.loc.1, INDENT);
+        self.out.push_str("}\n");
+    }
+
+    fn description(&mut self, description: Option<&str>, indent: &str) {
+        for line in description.into_iter().flat_map(str::lines) {
+            let _ = writeln!(self.out, "{}{}", indent, format!("/// {}", line).trim_end());
+        }
+    }
+
+    fn comments_before(&mut self, offset: usize, indent: &str) {
+        while let Some((loc, comment)) = self.comments.last() {
+            if loc.0 >= offset {
+                break;
+            }
+            let line_start = self.input[..loc.0].rfind('\n').map_or(0, |i| i + 1);
+            if self.input[line_start..loc.0].trim().is_empty() {
+                let _ = writeln!(self.out, "{}{}", indent, comment.trim_end());
+            } else {
+                // the comment trails a line, it goes back to the end of the last line printed
+                let end = self.out.trim_end_matches('\n').len();
+                self.out.insert_str(end, &format!(" {}", comment.trim_end()));
+            }
+            self.comments.pop();
+        }
+    }
+}
+
+struct ConditionSignature<'a>(&'a Condition);
+
+impl fmt::Display for ConditionSignature<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "condition {}(", self.0.name)?;
+        for (i, arg) in self.0.args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: {}", arg.name, arg.r#type)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// single line form, `condition c(a: int) { a > 1 }`
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{ {} }}", ConditionSignature(self), self.body)
+    }
+}
+
+impl fmt::Display for Superadmin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "superadmin {}#{}", self.r#type, self.relation)
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "relation {}: ", self.name)?;
+        for (i, sub) in self.subjects.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            write!(f, "{}", sub)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "permission {}: {}", self.name, self.permission)
+    }
+}
+
+impl fmt::Display for RelationshipSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SetKind::Single(name) => f.write_str(name),
+            SetKind::Set(typ, relation) => write!(f, "{}#{}", typ, relation),
+            SetKind::Conditional(set, condition) => write!(f, "{} with {}", set, condition),
+        }
+    }
+}
+
+// binding power of the permission grammar: `+` and `-` < `&` < a relationship set
+const REL_UNION: u8 = 1;
+const REL_INTERSECTION: u8 = 2;
+const REL_SET: u8 = 3;
+
+impl Relationship {
+    fn precedence(&self) -> u8 {
+        match self {
+            Relationship::Set(_) => REL_SET,
+            Relationship::Union { .. } | Relationship::Difference { .. } => REL_UNION,
+            Relationship::Intersection { .. } => REL_INTERSECTION,
+        }
+    }
+
+    /// operators are left associative, only the leftmost operand may sit at the same level
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>, min: u8) -> fmt::Result {
+        if self.precedence() < min {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Relationship::Set(set) => match &set.kind {
+                SetKind::Set(tupleset, relation) if relation.ne(WILDCARD) => write!(f, "{}->{}", tupleset, relation),
+                _ => write!(f, "{}", set),
+            },
+            Relationship::Union { children } => {
+                for (i, child) in children.iter().enumerate() {
+                    if i == 0 {
+                        child.write_operand(f, REL_UNION)?;
+                    } else {
+                        f.write_str(" + ")?;
+                        child.write_operand(f, REL_INTERSECTION)?;
+                    }
+                }
+                Ok(())
+            }
+            Relationship::Intersection { children } => {
+                for (i, child) in children.iter().enumerate() {
+                    if i == 0 {
+                        child.write_operand(f, REL_INTERSECTION)?;
+                    } else {
+                        f.write_str(" & ")?;
+                        child.write_operand(f, REL_SET)?;
+                    }
+                }
+                Ok(())
+            }
+            Relationship::Difference { base, subtract } => {
+                base.write_operand(f, REL_UNION)?;
+                f.write_str(" - ")?;
+                subtract.write_operand(f, REL_INTERSECTION)
+            }
+        }
+    }
+}
+
+// binding power of the condition grammar, comparisons do not chain
+const EXPR_OR: u8 = 1;
+const EXPR_AND: u8 = 2;
+const EXPR_COMPARE: u8 = 3;
+const EXPR_ADD: u8 = 4;
+const EXPR_MUL: u8 = 5;
+const EXPR_UNARY: u8 = 6;
+const EXPR_POSTFIX: u8 = 7;
+const EXPR_PRIMARY: u8 = 8;
+
+fn binary_precedence(op: BinaryOperator) -> u8 {
+    match op {
+        BinaryOperator::Or => EXPR_OR,
+        BinaryOperator::And => EXPR_AND,
+        BinaryOperator::Eq
+        | BinaryOperator::Ne
+        | BinaryOperator::Lt
+        | BinaryOperator::Le
+        | BinaryOperator::Gt
+        | BinaryOperator::Ge => EXPR_COMPARE,
+        BinaryOperator::Add | BinaryOperator::Sub => EXPR_ADD,
+        BinaryOperator::Mul | BinaryOperator::Div | BinaryOperator::Rem => EXPR_MUL,
+    }
+}
+
+impl ConditionExpression {
+    fn precedence(&self) -> u8 {
+        match &self.kind {
+            ExpressionKind::Literal(_) | ExpressionKind::Identifier(_) | ExpressionKind::Cast { .. } => EXPR_PRIMARY,
+            ExpressionKind::MethodCall { .. } => EXPR_POSTFIX,
+            ExpressionKind::Unary { .. } => EXPR_UNARY,
+            ExpressionKind::Binary { op, .. } => binary_precedence(*op),
+        }
+    }
+
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>, min: u8) -> fmt::Result {
+        if self.precedence() < min {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for ConditionExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ExpressionKind::Literal(literal) => write!(f, "{}", literal),
+            ExpressionKind::Identifier(name) => f.write_str(name),
+            ExpressionKind::Cast { r#type, expr } => write!(f, "{}({})", r#type, expr),
+            ExpressionKind::MethodCall { receiver, method, args } => {
+                receiver.write_operand(f, EXPR_POSTFIX)?;
+                write!(f, ".{}(", method)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                f.write_str(")")
+            }
+            ExpressionKind::Unary { op, operand } => {
+                write!(f, "{}", op)?;
+                operand.write_operand(f, EXPR_UNARY)
+            }
+            ExpressionKind::Binary { op, left, right } => {
+                let precedence = binary_precedence(*op);
+                let left_min = if precedence == EXPR_COMPARE {
+                    EXPR_ADD
+                } else {
+                    precedence
+                };
+                left.write_operand(f, left_min)?;
+                write!(f, " {} ", op)?;
+                right.write_operand(f, precedence + 1)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Literal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Literal::Bool(b) => write!(f, "{}", b),
+            Literal::Int(i) => write!(f, "{}", i),
+            // the grammar reads neither exponents nor a double without a decimal point
+            Literal::Double(d) if d.fract() == 0.0 => write!(f, "{}.0", d),
+            Literal::Double(d) => write!(f, "{}", d),
+            Literal::String(s) => write!(f, "{}", Quoted(s)),
+        }
+    }
+}
+
+/// A string literal, quotes and backslashes escaped so that `lexer::unescape` reads back the string.
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for ch in self.0.chars() {
+            if matches!(ch, '"' | '\\') {
+                f.write_char('\\')?;
+            }
+            f.write_char(ch)?;
+        }
+        f.write_char('"')
+    }
+}
@@ -1,6 +1,7 @@
 // auto-generated: "lalrpop 0.20.2"
-// sha3: 1f48c3c5dd53e35fa3588fe3350650227fbb87e102b0caf323ad61a2bd361a04
-use crate::{ token::Token, lexer::LexicalError, ast::* };
+// sha3: f5856d1c77b22ef3c76d1620b72efd2b5cbfbbc34d688e0270c0fd9a8e8a865b
+use crate::{ token::Token, lexer::{LexicalError, unescape}, ast::*, Diagnostic };
+use lalrpop_util::ErrorRecovery;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -10,9 +11,10 @@ extern crate alloc;
 
 #[rustfmt::skip]
 #[allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::never_loop, clippy::match_single_binding, clippy::needless_raw_string_hashes)]
-mod __parse__Schema {
+mod __parse__ConditionBody {
 
-    use crate::{ token::Token, lexer::LexicalError, ast::* };
+    use crate::{ token::Token, lexer::{LexicalError, unescape}, ast::*, Diagnostic };
+    use lalrpop_util::ErrorRecovery;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
@@ -27,314 +29,208 @@ mod __parse__Schema {
         Variant1(f64),
         Variant2(&'input str),
         Variant3(i64),
-        Variant4(core::option::Option<Token<'input>>),
-        Variant5((Token<'input>, ConditionArg)),
-        Variant6(alloc::vec::Vec<(Token<'input>, ConditionArg)>),
-        Variant7((Token<'input>, RelationOrPermission)),
-        Variant8(alloc::vec::Vec<(Token<'input>, RelationOrPermission)>),
-        Variant9((Token<'input>, RelationshipSet)),
-        Variant10(alloc::vec::Vec<(Token<'input>, RelationshipSet)>),
-        Variant11(usize),
-        Variant12(Condition),
-        Variant13(ConditionArg),
-        Variant14(Vec<ConditionArg>),
+        Variant4(__lalrpop_util::ErrorRecovery<usize, Token<'input>, LexicalError>),
+        Variant5(alloc::vec::Vec<Token<'input>>),
+        Variant6(core::option::Option<Token<'input>>),
+        Variant7((Token<'input>, ConditionArg)),
+        Variant8(alloc::vec::Vec<(Token<'input>, ConditionArg)>),
+        Variant9((Token<'input>, &'input str)),
+        Variant10(alloc::vec::Vec<(Token<'input>, &'input str)>),
+        Variant11((Token<'input>, RelationOrPermission)),
+        Variant12(alloc::vec::Vec<(Token<'input>, RelationOrPermission)>),
+        Variant13((Token<'input>, RelationshipSet)),
+        Variant14(alloc::vec::Vec<(Token<'input>, RelationshipSet)>),
         Variant15(ConditionExpression),
         Variant16(alloc::vec::Vec<ConditionExpression>),
-        Variant17(ConditionType),
-        Variant18(RelationOrPermission),
-        Variant19(Vec<RelationOrPermission>),
-        Variant20(Permission),
-        Variant21(RelationshipSet),
-        Variant22(Relation),
-        Variant23(Relationship),
-        Variant24(Vec<RelationshipSet>),
-        Variant25(Schema),
-        Variant26(SchemaUnit),
-        Variant27(alloc::vec::Vec<SchemaUnit>),
+        Variant17(usize),
+        Variant18(ConditionType),
+        Variant19(Vec<ConditionExpression>),
+        Variant20(BinaryOperator),
+        Variant21(Condition),
+        Variant22(ConditionArg),
+        Variant23(Vec<ConditionArg>),
+        Variant24(core::option::Option<ConditionExpression>),
+        Variant25(Vec<String>),
+        Variant26(RelationOrPermission),
+        Variant27(Vec<RelationOrPermission>),
         Variant28(Type),
+        Variant29(Permission),
+        Variant30(RelationshipSet),
+        Variant31(Relation),
+        Variant32(Relationship),
+        Variant33(Vec<RelationshipSet>),
+        Variant34(Schema),
+        Variant35(SchemaUnit),
+        Variant36(alloc::vec::Vec<SchemaUnit>),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        30, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        45, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0,
+        -67, 0, 46, 0, 0, 0, 0, -67, 0, -67, 0, 11, -67, 12, 0, 0, 0, 0, 0, 47, 48, 0, 49, 50, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 45, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0,
+        30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 5
-        10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0,
+        30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 56, 57, 0, 0, 58, 59, 60, 61, 62, 63, 0, 0, 64, 65, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 45, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 10
-        15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 12
-        71, 72, 72, 73, 74, 75, 75, 76, 77, 78, 79, 79, 80, 81, 81, 82, 83, 84, 85, 85, 86, 87, 87, 88, 88, 89, 89, 90, 90, 91, 92, 93, 94, 95, 0, 0, 96, 97, 98, 99, 100, 101, 0, 0, 102, 103, 0, 104, 0, 105, 106, 107, 108, 109, 110, 111,
+        18, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 45, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0,
+        19, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 45, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 17
-        71, 72, 72, 73, 74, 75, 75, 76, 77, 78, 79, 79, 80, 81, 81, 82, 83, 84, 85, 85, 86, 87, 87, 88, 88, 89, 89, 90, 90, 91, 92, 93, 94, 95, 0, 0, 96, 97, 98, 99, 100, 101, 0, 0, 102, 103, 0, 104, 0, 105, 120, 107, 108, 109, 110, 111,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 56, 57, 0, 0, 58, 59, 60, 61, 62, 63, 0, 0, 64, 65, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 56, 57, 0, 0, 58, 59, 60, 61, 62, 63, 0, 0, 64, 65, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, -43, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 45, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 0, 6, -45, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 32, 33, 0, 34, 0, 35, 36, 0, 0, 0, 0, 0, 37, 0, 38, 39, 0, 40, 0, 0, 0, 0, 0, 41, 42, 43, 44, 0, 0,
         // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, -102, 0, 0, 0, 0, 0,
+        -70, 0, 0, 0, 0, 0, 0, 13, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        -58, 0, 0, 0, 0, 0, 0, -58, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        -55, 0, -55, 0, 0, 15, 0, -55, 0, -55, 16, -55, -55, -55, 0, 0, 17, 0, 0, -55, -55, 0, -55, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        -101, 0, -101, 0, 0, -101, 0, -101, 0, -101, -101, -101, -101, -101, 0, 53, -101, 0, 0, -101, -101, 0, -101, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0,
+        -78, 0, -78, 0, 0, -78, 0, -78, 0, -78, -78, -78, -78, -78, 0, -78, -78, 0, 0, -78, -78, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -76, 0, -76, 0, 0, -76, 0, -76, 0, -76, -76, -76, -76, -76, 0, 0, -76, 0, 0, -76, -76, 0, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -3, -3, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, -3, -3, 0, -3, 0, -3, -3, 0, 0, 0, 0, 0, -3, 0, -3, -3, 0, -3, 0, 0, 0, 0, 0, -3, -3, -3, -3, 0, 0,
         // State 30
-        35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0,
+        -81, 0, -81, 0, 0, -81, 0, -81, 0, -81, -81, -81, -81, -81, 0, -81, -81, 0, 0, -81, -81, 0, -81, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -80, 0, -80, 0, 0, -80, 0, -80, 0, -80, -80, -80, -80, -80, 0, -80, -80, 0, 0, -80, -80, 0, -80, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0,
+        -84, 0, -84, 0, 0, -84, 0, -84, 0, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, -84, -84, 0, -84, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0,
+        -79, 0, -79, 0, 0, -79, 0, -79, 0, -79, -79, -79, -79, -79, 0, -79, -79, 0, 0, -79, -79, 0, -79, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0,
+        -83, 0, -83, 0, 0, -83, 0, -83, 0, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, -83, -83, 0, -83, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0,
+        -82, 0, -82, 0, 0, -82, 0, -82, 0, -82, -82, -82, -82, -82, 0, -82, -82, 0, 0, -82, -82, 0, -82, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0,
+        -4, -4, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, -4, -4, 0, -4, 0, -4, -4, 0, 0, 0, 0, 0, -4, 0, -4, -4, 0, -4, 0, 0, 0, 0, 0, -4, -4, -4, -4, 0, 0,
         // State 45
-        -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -47, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, -47, -47, 0, -47, 0, -47, -47, 0, 0, 0, 0, 0, -47, 0, -47, -47, 0, -47, 0, 0, 0, 0, 0, -47, -47, -47, -47, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 12, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -48, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, -48, -48, 0, -48, 0, -48, -48, 0, 0, 0, 0, 0, -48, 0, -48, -48, 0, -48, 0, 0, 0, 0, 0, -48, -48, -48, -48, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0,
+        0, -49, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, -49, -49, 0, -49, 0, -49, -49, 0, 0, 0, 0, 0, -49, 0, -49, -49, 0, -49, 0, 0, 0, 0, 0, -49, -49, -49, -49, 0, 0,
         // State 48
-        14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0,
+        0, -46, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, -46, -46, 0, -46, 0, -46, -46, 0, 0, 0, 0, 0, -46, 0, -46, -46, 0, -46, 0, 0, 0, 0, 0, -46, -46, -46, -46, 0, 0,
         // State 49
-        -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -50, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, -50, 0, -50, 0, -50, -50, 0, 0, 0, 0, 0, -50, 0, -50, -50, 0, -50, 0, 0, 0, 0, 0, -50, -50, -50, -50, 0, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -51, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, -51, 0, -51, 0, -51, -51, 0, 0, 0, 0, 0, -51, 0, -51, -51, 0, -51, 0, 0, 0, 0, 0, -51, -51, -51, -51, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, 0, 0, -6, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -99, 0, -99, 0, 0, -99, 0, -99, 0, -99, -99, -99, -99, -99, 0, 0, -99, 0, 0, -99, -99, 0, -99, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -100, 0, -100, 0, 0, -100, 0, -100, 0, -100, -100, -100, -100, -100, 0, 0, -100, 0, 0, -100, -100, 0, -100, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -66, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 11, -66, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -53, 0, -53, 0, 0, 15, 0, -53, 0, -53, 16, -53, -53, -53, 0, 0, 17, 0, 0, -53, -53, 0, -53, -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -54, 0, -54, 0, 0, 15, 0, -54, 0, -54, 16, -54, -54, -54, 0, 0, 17, 0, 0, -54, -54, 0, -54, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0,
         // State 61
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -57, 0, 0, 0, 0, 0, 0, -57, 0, -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -69, 0, 0, 0, 0, 0, 0, 13, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -75, 0, -75, 0, 0, -75, 0, -75, 0, -75, -75, -75, -75, -75, 0, 0, -75, 0, 0, -75, -75, 0, -75, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -73, 0, -73, 0, 0, -73, 0, -73, 0, -73, -73, -73, -73, -73, 0, 0, -73, 0, 0, -73, -73, 0, -73, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -74, 0, -74, 0, 0, -74, 0, -74, 0, -74, -74, -74, -74, -74, 0, 0, -74, 0, 0, -74, -74, 0, -74, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0,
+        -86, 0, -86, 0, 0, -86, 0, -86, 0, -86, -86, -86, -86, -86, 0, -86, -86, 0, 0, -86, -86, 0, -86, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, -7, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -85, 0, -85, 0, 0, -85, 0, -85, 0, -85, -85, -85, -85, -85, 0, -85, -85, 0, 0, -85, -85, 0, -85, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, 0, -67, -67, -67, -67, -67, -67, 0, 0, -67, -67, 0, -67, 0, -67, -67, -67, -67, -67, -67, -67,
+        -56, 0, 0, 0, 0, 0, 0, -56, 0, -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, 0, 0, -36, -36, -36, -36, -36, -36, 0, 0, -36, -36, 0, -36, 0, -36, -36, -36, -36, -36, -36, -36,
+        -68, 0, 0, 0, 0, 0, 0, 13, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, 0, -43, -43, -43, -43, -43, -43, 0, 0, -43, -43, 0, -43, 0, -43, -43, -43, -43, -43, -43, -43,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, 0, 0, -35, -35, -35, -35, -35, -35, 0, 0, -35, -35, 0, -35, 0, -35, -35, -35, -35, -35, -35, -35,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
         // State 73
-        -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, 0, 0, -61, -61, -61, -61, -61, -61, 0, 0, -61, -61, 0, -61, 0, -61, -61, -61, -61, -61, -61, -61,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
         // State 74
-        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, 0, -39, -39, -39, -39, -39, -39, 0, 0, -39, -39, 0, -39, 0, -39, -39, -39, -39, -39, -39, -39,
+        -77, 0, -77, 0, 0, -77, 0, -77, 0, -77, -77, -77, -77, -77, 0, -77, -77, 0, 0, -77, -77, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, 0, 0, -30, -30, -30, -30, -30, -30, 0, 0, -30, -30, 0, -30, 0, -30, -30, -30, -30, -30, -30, -30,
+        0, -30, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, -30, 0, -30, 0, -30, -30, 0, 0, 0, 0, 0, -30, 0, -30, -30, 0, -30, 0, 0, 0, 0, 0, -30, -30, -30, -30, 0, 0,
         // State 76
-        -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, -27, -27, -27, -27, -27, 0, 0, -27, -27, 0, -27, 0, -27, -27, -27, -27, -27, -27, -27,
-        // State 77
-        -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, 0, 0, -28, -28, -28, -28, -28, -28, 0, 0, -28, -28, 0, -28, 0, -28, -28, -28, -28, -28, -28, -28,
-        // State 78
-        -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, 0, -37, -37, -37, -37, -37, -37, 0, 0, -37, -37, 0, -37, 0, -37, -37, -37, -37, -37, -37, -37,
-        // State 79
-        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, 0, 0, -32, -32, -32, -32, -32, -32, 0, 0, -32, -32, 0, -32, 0, -32, -32, -32, -32, -32, -32, -32,
-        // State 80
-        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, 0, 0, -40, -40, -40, -40, -40, -40, 0, 0, -40, -40, 0, -40, 0, -40, -40, -40, -40, -40, -40, -40,
-        // State 81
-        -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, 0, 0, -33, -33, -33, -33, -33, -33, 0, 0, -33, -33, 0, -33, 0, -33, -33, -33, -33, -33, -33, -33,
-        // State 82
-        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, 0, 0, -34, -34, -34, -34, -34, -34, 0, 0, -34, -34, 0, -34, 0, -34, -34, -34, -34, -34, -34, -34,
-        // State 83
-        -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, 0, 0, -64, -64, -64, -64, -64, -64, 0, 0, -64, -64, 0, -64, 0, -64, -64, -64, -64, -64, -64, -64,
-        // State 84
-        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, 0, 0, -38, -38, -38, -38, -38, -38, 0, 0, -38, -38, 0, -38, 0, -38, -38, -38, -38, -38, -38, -38,
-        // State 85
-        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, 0, 0, -26, -26, -26, -26, -26, -26, 0, 0, -26, -26, 0, -26, 0, -26, -26, -26, -26, -26, -26, -26,
-        // State 86
-        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, 0, -41, -41, -41, -41, -41, -41, 0, 0, -41, -41, 0, -41, 0, -41, -41, -41, -41, -41, -41, -41,
-        // State 87
-        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, 0, -45, -45, -45, -45, -45, -45, 0, 0, -45, -45, 0, -45, 0, -45, -45, -45, -45, -45, -45, -45,
-        // State 88
-        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, 0, -42, -42, -42, -42, -42, -42, 0, 0, -42, -42, 0, -42, 0, -42, -42, -42, -42, -42, -42, -42,
-        // State 89
-        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, 0, -44, -44, -44, -44, -44, -44, 0, 0, -44, -44, 0, -44, 0, -44, -44, -44, -44, -44, -44, -44,
-        // State 90
-        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, 0, 0, -29, -29, -29, -29, -29, -29, 0, 0, -29, -29, 0, -29, 0, -29, -29, -29, -29, -29, -29, -29,
-        // State 91
-        -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, 0, 0, -62, -62, -62, -62, -62, -62, 0, 0, -62, -62, 0, -62, 0, -62, -62, -62, -62, -62, -62, -62,
-        // State 92
-        -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, 0, -54, -54, -54, -54, -54, -54, 0, 0, -54, -54, 0, -54, 0, -54, -54, -54, -54, -54, -54, -54,
-        // State 93
-        -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, 0, -49, -49, -49, -49, -49, -49, 0, 0, -49, -49, 0, -49, 0, -49, -49, -49, -49, -49, -49, -49,
-        // State 94
-        -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, 0, -50, -50, -50, -50, -50, -50, 0, 0, -50, -50, 0, -50, 0, -50, -50, -50, -50, -50, -50, -50,
-        // State 95
-        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, 0, -48, -48, -48, -48, -48, -48, 0, 0, -48, -48, 0, -48, 0, -48, -48, -48, -48, -48, -48, -48,
-        // State 96
-        -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, 0, -52, -52, -52, -52, -52, -52, 0, 0, -52, -52, 0, -52, 0, -52, -52, -52, -52, -52, -52, -52,
-        // State 97
-        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, 0, -46, -46, -46, -46, -46, -46, 0, 0, -46, -46, 0, -46, 0, -46, -46, -46, -46, -46, -46, -46,
-        // State 98
-        -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0, 0, -57, -57, -57, -57, -57, -57, 0, 0, -57, -57, 0, -57, 0, -57, -57, -57, -57, -57, -57, -57,
-        // State 99
-        -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, 0, -55, -55, -55, -55, -55, -55, 0, 0, -55, -55, 0, -55, 0, -55, -55, -55, -55, -55, -55, -55,
-        // State 100
-        -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, 0, -56, -56, -56, -56, -56, -56, 0, 0, -56, -56, 0, -56, 0, -56, -56, -56, -56, -56, -56, -56,
-        // State 101
-        -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, 0, -51, -51, -51, -51, -51, -51, 0, 0, -51, -51, 0, -51, 0, -51, -51, -51, -51, -51, -51, -51,
-        // State 102
-        -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, 0, -53, -53, -53, -53, -53, -53, 0, 0, -53, -53, 0, -53, 0, -53, -53, -53, -53, -53, -53, -53,
-        // State 103
-        -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, 0, -47, -47, -47, -47, -47, -47, 0, 0, -47, -47, 0, -47, 0, -47, -47, -47, -47, -47, -47, -47,
-        // State 104
-        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, -31, -31, -31, -31, -31, 0, 0, -31, -31, 0, -31, 0, -31, -31, -31, -31, -31, -31, -31,
-        // State 105
-        -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 106
-        -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, 0, 0, -60, -60, -60, -60, -60, -60, 0, 0, -60, -60, 0, -60, 0, -60, -60, -60, -60, -60, -60, -60,
-        // State 107
-        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, 0, 0, -25, -25, -25, -25, -25, -25, 0, 0, -25, -25, 0, -25, 0, -25, -25, -25, -25, -25, -25, -25,
-        // State 108
-        -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, 0, -59, -59, -59, -59, -59, -59, 0, 0, -59, -59, 0, -59, 0, -59, -59, -59, -59, -59, -59, -59,
-        // State 109
-        -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, 0, -58, -58, -58, -58, -58, -58, 0, 0, -58, -58, 0, -58, 0, -58, -58, -58, -58, -58, -58, -58,
-        // State 110
-        -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, 0, 0, -63, -63, -63, -63, -63, -63, 0, 0, -63, -63, 0, -63, 0, -63, -63, -63, -63, -63, -63, -63,
-        // State 111
-        -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, 0, 0, 0, 0,
-        // State 112
-        -100, 0, 0, 0, 0, 0, 0, -100, 0, -100, 0, 0, -100, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0,
-        // State 113
-        -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0,
-        // State 114
-        -97, 0, 0, 0, 0, 0, 0, 26, 0, -97, 0, 0, -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0,
-        // State 115
-        -99, 0, 0, 0, 0, 0, 0, -99, 0, -99, 0, 0, -99, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0,
-        // State 116
-        -92, 0, 0, 124, 0, 0, 0, -92, 0, -92, 0, 0, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0, 0,
-        // State 117
-        -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0,
-        // State 118
-        -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 0, 0, -68, -68, -68, -68, -68, -68, 0, 0, -68, -68, 0, -68, 0, -68, -68, -68, -68, -68, -68, -68,
-        // State 119
-        -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 120
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 121
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 122
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 24, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 123
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0,
-        // State 124
-        -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, -103, 0, 0, 0, 0, 0,
-        // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 126
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 127
-        -95, 0, 0, 0, 0, 0, 0, 26, 0, -95, 0, 0, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0,
-        // State 128
-        -96, 0, 0, 0, 0, 0, 0, 26, 0, -96, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0,
-        // State 129
-        -98, 0, 0, 0, 0, 0, 0, -98, 0, -98, 0, 0, -98, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0,
-        // State 130
-        -101, 0, 0, 0, 0, 0, 0, -101, 0, -101, 0, 0, -101, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0,
-        // State 131
-        -93, 0, 0, 0, 0, 0, 0, -93, 0, -93, 0, 0, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0, 0,
-        // State 132
-        -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, -16, 0, 0, 0, 0, 0,
-        // State 133
-        -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, -17, 0, 0, 0, 0, 0,
+        0, -31, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, -31, 0, -31, 0, -31, -31, 0, 0, 0, 0, 0, -31, 0, -31, -31, 0, -31, 0, 0, 0, 0, 0, -31, -31, -31, -31, 0, 0,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 56 + integer]
+        __ACTION[(state as usize) * 61 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
-        -104,
+        0,
         // State 1
-        -105,
-        // State 2
         0,
+        // State 2
+        -67,
         // State 3
-        0,
+        -62,
         // State 4
         0,
         // State 5
@@ -342,7 +238,7 @@ mod __parse__Schema {
         // State 6
         0,
         // State 7
-        0,
+        -64,
         // State 8
         0,
         // State 9
@@ -372,53 +268,53 @@ mod __parse__Schema {
         // State 21
         0,
         // State 22
-        0,
+        -70,
         // State 23
-        0,
+        -154,
         // State 24
-        0,
+        -58,
         // State 25
-        0,
+        -55,
         // State 26
-        0,
+        -101,
         // State 27
-        0,
+        -78,
         // State 28
-        -107,
+        -76,
         // State 29
-        -116,
+        -3,
         // State 30
-        -111,
+        0,
         // State 31
-        -106,
+        0,
         // State 32
         0,
         // State 33
-        0,
+        -81,
         // State 34
-        -108,
+        0,
         // State 35
-        -112,
+        0,
         // State 36
         0,
         // State 37
-        -115,
-        // State 38
         0,
+        // State 38
+        -80,
         // State 39
         0,
         // State 40
-        0,
+        -84,
         // State 41
-        0,
+        -79,
         // State 42
-        0,
+        -83,
         // State 43
-        0,
+        -82,
         // State 44
-        0,
+        -4,
         // State 45
-        -114,
+        0,
         // State 46
         0,
         // State 47
@@ -426,49 +322,49 @@ mod __parse__Schema {
         // State 48
         0,
         // State 49
-        -113,
+        0,
         // State 50
         0,
         // State 51
-        0,
+        -63,
         // State 52
         0,
         // State 53
-        0,
+        -99,
         // State 54
         0,
         // State 55
-        0,
+        -100,
         // State 56
-        0,
+        -65,
         // State 57
         0,
         // State 58
-        0,
+        -66,
         // State 59
-        0,
+        -53,
         // State 60
-        0,
+        -54,
         // State 61
-        0,
+        -57,
         // State 62
-        0,
+        -69,
         // State 63
-        0,
+        -75,
         // State 64
-        0,
+        -73,
         // State 65
-        0,
+        -74,
         // State 66
         0,
         // State 67
-        0,
+        -86,
         // State 68
-        0,
+        -85,
         // State 69
-        0,
+        -56,
         // State 70
-        0,
+        -68,
         // State 71
         0,
         // State 72
@@ -476,220 +372,90 @@ mod __parse__Schema {
         // State 73
         0,
         // State 74
-        0,
+        -77,
         // State 75
         0,
         // State 76
         0,
-        // State 77
-        0,
-        // State 78
-        0,
-        // State 79
-        0,
-        // State 80
-        0,
-        // State 81
-        0,
-        // State 82
-        0,
-        // State 83
-        0,
-        // State 84
-        0,
-        // State 85
-        0,
-        // State 86
-        0,
-        // State 87
-        0,
-        // State 88
-        0,
-        // State 89
-        0,
-        // State 90
-        0,
-        // State 91
-        0,
-        // State 92
-        0,
-        // State 93
-        0,
-        // State 94
-        0,
-        // State 95
-        0,
-        // State 96
-        0,
-        // State 97
-        0,
-        // State 98
-        0,
-        // State 99
-        0,
-        // State 100
-        0,
-        // State 101
-        0,
-        // State 102
-        0,
-        // State 103
-        0,
-        // State 104
-        0,
-        // State 105
-        -20,
-        // State 106
-        0,
-        // State 107
-        0,
-        // State 108
-        0,
-        // State 109
-        0,
-        // State 110
-        0,
-        // State 111
-        0,
-        // State 112
-        0,
-        // State 113
-        0,
-        // State 114
-        0,
-        // State 115
-        0,
-        // State 116
-        0,
-        // State 117
-        0,
-        // State 118
-        0,
-        // State 119
-        -21,
-        // State 120
-        0,
-        // State 121
-        0,
-        // State 122
-        0,
-        // State 123
-        0,
-        // State 124
-        0,
-        // State 125
-        0,
-        // State 126
-        0,
-        // State 127
-        0,
-        // State 128
-        0,
-        // State 129
-        0,
-        // State 130
-        0,
-        // State 131
-        0,
-        // State 132
-        0,
-        // State 133
-        0,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            3 => 46,
-            6 => match state {
-                10 => 67,
-                _ => 48,
-            },
-            9 => 124,
-            12 => 28,
-            13 => match state {
-                7 => 52,
-                11 => 68,
-                _ => 4,
-            },
-            14 => 38,
-            15 => match state {
-                17 => 118,
-                _ => 69,
-            },
-            17 => 17,
-            18 => match state {
-                18 => 120,
-                19 => 121,
-                _ => 53,
+            1 => match state {
+                3 => 51,
+                7 => 56,
+                _ => 1,
             },
-            19 => match state {
-                3 => 5,
-                6 => 10,
-                13 | 20 => 111,
-                _ => 66,
-            },
-            20 => 40,
-            21 => 41,
-            22 => match state {
-                16 => 22,
-                26 => 132,
-                27 => 133,
-                _ => 112,
-            },
-            23 => 42,
+            17 => 20,
+            20 => 21,
+            21 => 71,
+            22 => 9,
             24 => match state {
-                21 => 122,
-                _ => 113,
+                9 => 58,
+                _ => 2,
             },
             25 => match state {
-                23 => 127,
-                24 => 128,
-                _ => 114,
-            },
-            26 => match state {
-                25 => 129,
-                _ => 115,
+                13 => 62,
+                18 => 70,
+                _ => 22,
             },
-            27 => 117,
-            28 => 29,
+            28 => 23,
             29 => match state {
-                1 => 35,
-                _ => 30,
+                12 => 61,
+                17 => 69,
+                _ => 24,
+            },
+            30 => match state {
+                1 => 7,
+                5 => 54,
+                8 => 57,
+                19 => 72,
+                20 => 73,
+                _ => 3,
+            },
+            32 => match state {
+                10 => 59,
+                11 => 60,
+                _ => 25,
+            },
+            33 => 26,
+            34 => 27,
+            36 => match state {
+                4 => 53,
+                6 => 55,
+                14 => 63,
+                15 => 64,
+                16 => 65,
+                _ => 28,
             },
-            31 => 1,
-            32 => 31,
             _ => 0,
         }
     }
     const __TERMINAL: &[&str] = &[
         r###""\n""###,
         r###""!""###,
-        r###""!""###,
+        r###""!=""###,
         r###""#""###,
         r###""$""###,
         r###""%""###,
-        r###""%""###,
         r###""&""###,
+        r###""&&""###,
         r###""(""###,
         r###"")""###,
         r###""*""###,
-        r###""*""###,
         r###""+""###,
         r###"",""###,
-        r###"",""###,
         r###""-""###,
         r###""->""###,
         r###"".""###,
         r###""/""###,
-        r###""/""###,
         r###"":""###,
         r###"";""###,
-        r###"";""###,
         r###""<""###,
-        r###""<""###,
-        r###""=""###,
+        r###""<=""###,
         r###""=""###,
+        r###""==""###,
         r###"">""###,
-        r###"">""###,
+        r###"">=""###,
         r###""^""###,
         r###""`""###,
         r###""any""###,
@@ -699,18 +465,26 @@ mod __parse__Schema {
         r###""condition""###,
         r###""double""###,
         r###""duration""###,
+        r###""extends""###,
+        r###""false""###,
+        r###""import""###,
         r###""int""###,
         r###""ipaddress""###,
         r###""list""###,
         r###""map""###,
+        r###""mixin""###,
         r###""permission""###,
         r###""relation""###,
         r###""string""###,
+        r###""superadmin""###,
         r###""timestamp""###,
+        r###""true""###,
         r###""type""###,
         r###""uint""###,
+        r###""with""###,
         r###""{""###,
         r###""|""###,
+        r###""||""###,
         r###""}""###,
         r###"double"###,
         r###"identifier"###,
@@ -730,34 +504,39 @@ mod __parse__Schema {
     }
     fn __expected_tokens_from_states<
         'input,
+        'err,
     >(
         __states: &[i16],
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> alloc::vec::Vec<alloc::string::String>
+    where
+        'input: 'err,
     {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&(), &())>) {
                 Some(alloc::string::ToString::to_string(terminal))
             } else {
                 None
             }
         }).collect()
     }
-    struct __StateMachine<'input>
-    where 
+    struct __StateMachine<'input, 'err>
+    where 'input: 'err
     {
         input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __phantom: core::marker::PhantomData<(&'input (), &'err ())>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    impl<'input, 'err> __state_machine::ParserDefinition for __StateMachine<'input, 'err>
+    where 'input: 'err
     {
         type Location = usize;
         type Error = LexicalError;
         type Token = Token<'input>;
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
-        type Success = Schema;
+        type Success = ConditionExpression;
         type StateIndex = i16;
         type Action = i16;
         type ReduceIndex = i16;
@@ -775,7 +554,7 @@ mod __parse__Schema {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
@@ -785,7 +564,7 @@ mod __parse__Schema {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 56 - 1)
+            __action(state, 61 - 1)
         }
 
         #[inline]
@@ -799,7 +578,7 @@ mod __parse__Schema {
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
         }
 
         fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
@@ -807,12 +586,12 @@ mod __parse__Schema {
         }
 
         fn expected_tokens_from_states(&self, states: &[i16]) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
+            __expected_tokens_from_states(states, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
         fn uses_error_recovery(&self) -> bool {
-            false
+            true
         }
 
         #[inline]
@@ -820,7 +599,7 @@ mod __parse__Schema {
             &self,
             recovery: __state_machine::ErrorRecovery<Self>,
         ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
+            __Symbol::Variant4(recovery)
         }
 
         fn reduce(
@@ -832,104 +611,112 @@ mod __parse__Schema {
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
                 self.input,
+                self.errors,
+                self.warnings,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<(&(), &())>,
             )
         }
 
         fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
-            __simulate_reduce(action, core::marker::PhantomData::<(&())>)
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
         }
     }
     fn __token_to_integer<
         'input,
+        'err,
     >(
         __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> Option<usize>
     {
         match *__token {
             Token::Newline if true => Some(0),
             Token::ExclamationMark if true => Some(1),
-            Token::ExclamationMark if true => Some(2),
+            Token::NotEq if true => Some(2),
             Token::Sharp if true => Some(3),
             Token::Dollar if true => Some(4),
             Token::Percent if true => Some(5),
-            Token::Percent if true => Some(6),
-            Token::And if true => Some(7),
+            Token::And if true => Some(6),
+            Token::AndAnd if true => Some(7),
             Token::LBracket if true => Some(8),
             Token::RBracket if true => Some(9),
             Token::Star if true => Some(10),
-            Token::Star if true => Some(11),
-            Token::Add if true => Some(12),
-            Token::Comma if true => Some(13),
-            Token::Comma if true => Some(14),
-            Token::Sub if true => Some(15),
-            Token::YulArrow if true => Some(16),
-            Token::Point if true => Some(17),
-            Token::Slash if true => Some(18),
-            Token::Slash if true => Some(19),
-            Token::Colon if true => Some(20),
-            Token::Semicolon if true => Some(21),
-            Token::Semicolon if true => Some(22),
-            Token::Lt if true => Some(23),
-            Token::Lt if true => Some(24),
-            Token::Eq if true => Some(25),
-            Token::Eq if true => Some(26),
-            Token::Gt if true => Some(27),
-            Token::Gt if true => Some(28),
-            Token::Caret if true => Some(29),
-            Token::GraveAccent if true => Some(30),
-            Token::Any if true => Some(31),
-            Token::Bool if true => Some(32),
-            Token::Bytes if true => Some(33),
-            Token::Cond if true => Some(34),
-            Token::Condition if true => Some(35),
-            Token::Double if true => Some(36),
-            Token::Duration if true => Some(37),
-            Token::Int if true => Some(38),
-            Token::IPaddress if true => Some(39),
-            Token::List if true => Some(40),
-            Token::Map if true => Some(41),
+            Token::Add if true => Some(11),
+            Token::Comma if true => Some(12),
+            Token::Sub if true => Some(13),
+            Token::YulArrow if true => Some(14),
+            Token::Point if true => Some(15),
+            Token::Slash if true => Some(16),
+            Token::Colon if true => Some(17),
+            Token::Semicolon if true => Some(18),
+            Token::Lt if true => Some(19),
+            Token::Le if true => Some(20),
+            Token::Eq if true => Some(21),
+            Token::EqEq if true => Some(22),
+            Token::Gt if true => Some(23),
+            Token::Ge if true => Some(24),
+            Token::Caret if true => Some(25),
+            Token::GraveAccent if true => Some(26),
+            Token::Any if true => Some(27),
+            Token::Bool if true => Some(28),
+            Token::Bytes if true => Some(29),
+            Token::Cond if true => Some(30),
+            Token::Condition if true => Some(31),
+            Token::Double if true => Some(32),
+            Token::Duration if true => Some(33),
+            Token::Extends if true => Some(34),
+            Token::False if true => Some(35),
+            Token::Import if true => Some(36),
+            Token::Int if true => Some(37),
+            Token::IPaddress if true => Some(38),
+            Token::List if true => Some(39),
+            Token::Map if true => Some(40),
+            Token::Mixin if true => Some(41),
             Token::Permission if true => Some(42),
             Token::Relation if true => Some(43),
             Token::String if true => Some(44),
-            Token::Timestamp if true => Some(45),
-            Token::Type if true => Some(46),
-            Token::Uint if true => Some(47),
-            Token::LBrace if true => Some(48),
-            Token::Or if true => Some(49),
-            Token::RBrace if true => Some(50),
-            Token::DoubleLiteral(_) if true => Some(51),
-            Token::Identifier(_) if true => Some(52),
-            Token::IntLiteral(_) if true => Some(53),
-            Token::StringLiteral(_) if true => Some(54),
-            Token::WhiteSpace if true => Some(55),
+            Token::Superadmin if true => Some(45),
+            Token::Timestamp if true => Some(46),
+            Token::True if true => Some(47),
+            Token::Type if true => Some(48),
+            Token::Uint if true => Some(49),
+            Token::With if true => Some(50),
+            Token::LBrace if true => Some(51),
+            Token::Or if true => Some(52),
+            Token::OrOr if true => Some(53),
+            Token::RBrace if true => Some(54),
+            Token::DoubleLiteral(_) if true => Some(55),
+            Token::Identifier(_) if true => Some(56),
+            Token::IntLiteral(_) if true => Some(57),
+            Token::StringLiteral(_) if true => Some(58),
+            Token::WhiteSpace if true => Some(59),
             _ => None,
         }
     }
     fn __token_to_symbol<
         'input,
+        'err,
     >(
         __token_index: usize,
         __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> __Symbol<'input>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 55 => __Symbol::Variant0(__token),
-            51 => match __token {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 59 => __Symbol::Variant0(__token),
+            55 => match __token {
                 Token::DoubleLiteral(__tok0) if true => __Symbol::Variant1(__tok0),
                 _ => unreachable!(),
             },
-            52 | 54 => match __token {
+            56 | 58 => match __token {
                 Token::Identifier(__tok0) | Token::StringLiteral(__tok0) if true => __Symbol::Variant2(__tok0),
                 _ => unreachable!(),
             },
-            53 => match __token {
+            57 => match __token {
                 Token::IntLiteral(__tok0) if true => __Symbol::Variant3(__tok0),
                 _ => unreachable!(),
             },
@@ -938,34 +725,37 @@ mod __parse__Schema {
     }
     fn __simulate_reduce<
         'input,
+        'err,
     >(
         __reduce_index: i16,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __state_machine::SimulatedReduce<__StateMachine<'input>>
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'err>>
+    where
+        'input: 'err,
     {
         match __reduce_index {
             0 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 0,
                 }
             }
             1 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 0,
                 }
             }
             2 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 1,
                 }
             }
             3 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 2,
+                    states_to_pop: 2,
+                    nonterminal_produced: 1,
                 }
             }
             4 => {
@@ -976,73 +766,73 @@ mod __parse__Schema {
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 3,
+                    states_to_pop: 0,
+                    nonterminal_produced: 2,
                 }
             }
             6 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 2,
                     nonterminal_produced: 3,
                 }
             }
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 0,
                     nonterminal_produced: 4,
                 }
             }
             8 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 5,
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
                 }
             }
             9 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 5,
                 }
             }
             10 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 6,
+                    states_to_pop: 3,
+                    nonterminal_produced: 5,
                 }
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 2,
                     nonterminal_produced: 6,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 0,
                     nonterminal_produced: 7,
                 }
             }
             13 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 8,
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
                 }
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 8,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 9,
+                    states_to_pop: 3,
+                    nonterminal_produced: 8,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 2,
                     nonterminal_produced: 9,
                 }
             }
@@ -1054,598 +844,832 @@ mod __parse__Schema {
             }
             18 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 11,
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
                 }
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 12,
+                    states_to_pop: 2,
+                    nonterminal_produced: 11,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
-                    nonterminal_produced: 12,
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 13,
+                    states_to_pop: 2,
+                    nonterminal_produced: 12,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 0,
+                    nonterminal_produced: 13,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 14,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 15,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 16,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 16,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 17,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 17,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 19,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 20,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 21,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 21,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 21,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 21,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 22,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 22,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 22,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 22,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 22,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 22,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 8,
+                    nonterminal_produced: 23,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 24,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 4,
+                    nonterminal_produced: 25,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 25,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 25,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 3,
+                    nonterminal_produced: 26,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 27,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 27,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 28,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 28,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 2,
+                    nonterminal_produced: 28,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 28,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 29,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 17,
+                    states_to_pop: 4,
+                    nonterminal_produced: 30,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 30,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 31,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 32,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 6,
+                    nonterminal_produced: 33,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 18,
+                    states_to_pop: 1,
+                    nonterminal_produced: 33,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 18,
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 34,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 34,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 34,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
                 }
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 20,
+                    states_to_pop: 4,
+                    nonterminal_produced: 34,
                 }
             }
             85 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 34,
                 }
             }
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             87 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 35,
                 }
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 21,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 35,
                 }
             }
             92 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 22,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             93 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 23,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             94 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 24,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             95 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 24,
+                    states_to_pop: 4,
+                    nonterminal_produced: 35,
                 }
             }
             96 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    states_to_pop: 4,
+                    nonterminal_produced: 35,
                 }
             }
             97 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 25,
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
                 }
             }
             98 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    states_to_pop: 2,
+                    nonterminal_produced: 36,
                 }
             }
             99 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    states_to_pop: 2,
+                    nonterminal_produced: 36,
                 }
             }
             100 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 26,
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
                 }
             }
             101 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    states_to_pop: 0,
+                    nonterminal_produced: 37,
                 }
             }
             102 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 37,
                 }
             }
             103 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 28,
+                    states_to_pop: 3,
+                    nonterminal_produced: 37,
                 }
             }
             104 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 38,
                 }
             }
             105 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 38,
                 }
             }
             106 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 38,
                 }
             }
             107 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 29,
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
                 }
             }
             108 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 30,
+                    states_to_pop: 4,
+                    nonterminal_produced: 39,
                 }
             }
             109 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 30,
+                    states_to_pop: 2,
+                    nonterminal_produced: 39,
                 }
             }
             110 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 31,
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
                 }
             }
             111 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 31,
+                    nonterminal_produced: 39,
                 }
             }
             112 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 32,
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
                 }
             }
             113 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 32,
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
                 }
             }
             114 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 32,
+                    nonterminal_produced: 39,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 40,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 41,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 43,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
+                }
+            }
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
+                }
+            }
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 45,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 45,
+                }
+            }
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 45,
+                }
+            }
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 46,
+                }
+            }
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
+            }
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 48,
+                }
+            }
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 49,
+                }
+            }
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 50,
+                }
+            }
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 51,
+                }
+            }
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 51,
+                }
+            }
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 52,
+                }
+            }
+            149 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            150 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 53,
+                }
+            }
+            151 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            152 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 54,
+                }
+            }
+            153 => __state_machine::SimulatedReduce::Accept,
+            154 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
                 }
             }
-            115 => __state_machine::SimulatedReduce::Accept,
             _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
-    pub struct SchemaParser {
+    pub struct ConditionBodyParser {
         _priv: (),
     }
 
-    impl Default for SchemaParser { fn default() -> Self { Self::new() } }
-    impl SchemaParser {
-        pub fn new() -> SchemaParser {
-            SchemaParser {
+    impl Default for ConditionBodyParser { fn default() -> Self { Self::new() } }
+    impl ConditionBodyParser {
+        pub fn new() -> ConditionBodyParser {
+            ConditionBodyParser {
                 _priv: (),
             }
         }
@@ -1653,20 +1677,25 @@ mod __parse__Schema {
         #[allow(dead_code)]
         pub fn parse<
             'input,
-            __TOKEN: __ToTriple<'input, >,
+            'err,
+            __TOKEN: __ToTriple<'input, 'err, >,
             __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
             input: &'input str,
+            errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+            warnings: &'err mut Vec<Diagnostic>,
             __tokens0: __TOKENS,
-        ) -> Result<Schema, __lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>
+        ) -> Result<ConditionExpression, __lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>
         {
             let __tokens = __tokens0.into_iter();
             let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
             __state_machine::Parser::drive(
                 __StateMachine {
                     input,
-                    __phantom: core::marker::PhantomData::<(&())>,
+                    errors,
+                    warnings,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
                 },
                 __tokens,
             )
@@ -1674,12 +1703,15 @@ mod __parse__Schema {
     }
     fn __accepts<
         'input,
+        'err,
     >(
         __error_state: Option<i16>,
         __states: &[i16],
         __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> bool
+    where
+        'input: 'err,
     {
         let mut __states = __states.to_vec();
         __states.extend(__error_state);
@@ -1692,7 +1724,7 @@ mod __parse__Schema {
             };
             if __action == 0 { return false; }
             if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&())>) {
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop, nonterminal_produced
                 } => (states_to_pop, nonterminal_produced),
@@ -1707,369 +1739,489 @@ mod __parse__Schema {
     }
     fn __reduce<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __action: i16,
         __lookahead_start: Option<&usize>,
         __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Schema,__lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>>
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<Result<ConditionExpression,__lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce0(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce1(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce2(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce3(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce4(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce5(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce6(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce7(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce8(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce9(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce10(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce11(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce12(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce13(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce14(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce15(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce16(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce17(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce18(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce19(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce20(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce21(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce22(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce23(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce24(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce25(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce26(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce27(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce28(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce29(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce30(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce31(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce32(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce33(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce34(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce35(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce36(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce37(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce38(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce39(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce40(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce41(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce42(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce43(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce44(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce45(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce46(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce47(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce48(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce49(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce50(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce51(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce52(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce53(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce54(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce55(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce56(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce57(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce58(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce59(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce60(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce61(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce62(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce63(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce64(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce65(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce66(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce67(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce68(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce69(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce70(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce71(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce72(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce73(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce74(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce75(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce76(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce77(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce78(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce79(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce80(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce81(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce82(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce83(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce84(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             85 => {
-                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce85(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             86 => {
-                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce86(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce87(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce88(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce89(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce90(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce91(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce92(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce93(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce94(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce95(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce96(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             97 => {
-                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce97(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             98 => {
-                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce98(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             99 => {
-                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce99(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             100 => {
-                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce100(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             101 => {
-                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce101(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             102 => {
-                __reduce102(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce102(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             103 => {
-                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce103(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             104 => {
-                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce104(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             105 => {
-                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce105(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             106 => {
-                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce106(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             107 => {
-                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce107(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             108 => {
-                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce108(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             109 => {
-                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce109(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             110 => {
-                __reduce110(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce110(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             111 => {
-                __reduce111(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce111(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             112 => {
-                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce112(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             113 => {
-                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce113(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             114 => {
-                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce114(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
             }
             115 => {
-                // __Schema = Schema => ActionFn(0);
-                let __sym0 = __pop_Variant25(__symbols);
+                __reduce115(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            116 => {
+                __reduce116(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            117 => {
+                __reduce117(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            148 => {
+                __reduce148(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            149 => {
+                __reduce149(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            150 => {
+                __reduce150(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            151 => {
+                __reduce151(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            152 => {
+                __reduce152(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            153 => {
+                // __ConditionBody = ConditionBody => ActionFn(1);
+                let __sym0 = __pop_Variant15(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action1::<>(input, errors, warnings, __sym0);
                 return Some(Ok(__nt));
             }
+            154 => {
+                __reduce154(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
             _ => panic!("invalid action code {}", __action)
         };
         let __states_len = __states.len();
@@ -2083,58 +2235,80 @@ mod __parse__Schema {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant5<
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (Token<'input>, ConditionArg), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant11<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (Token<'input>, RelationOrPermission), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (Token<'input>, RelationshipSet), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Condition, usize)
+    ) -> (usize, (Token<'input>, &'input str), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Condition, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ConditionArg, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2149,91 +2323,91 @@ mod __parse__Schema {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, ConditionType, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Permission, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant31<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Relation, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant26<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, RelationOrPermission, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant32<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Relationship, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant30<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, RelationshipSet, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant25<
+    fn __pop_Variant34<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Schema, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant26<
+    fn __pop_Variant35<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SchemaUnit, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2259,14 +2433,14 @@ mod __parse__Schema {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<ConditionArg>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2274,32 +2448,54 @@ mod __parse__Schema {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<RelationOrPermission>, usize)
+    ) -> (usize, Vec<ConditionExpression>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<RelationOrPermission>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant33<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<RelationshipSet>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant25<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize)
+    ) -> (usize, Vec<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexicalError>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2307,18 +2503,40 @@ mod __parse__Schema {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize)
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
@@ -2336,25 +2554,47 @@ mod __parse__Schema {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant36<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SchemaUnit>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Token<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<ConditionExpression>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, core::option::Option<Token<'input>>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2380,14 +2620,14 @@ mod __parse__Schema {
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant17<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, usize, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2404,3590 +2644,15323 @@ mod __parse__Schema {
     }
     fn __reduce0<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // "\n"? = "\n" => ActionFn(87);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action87::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 0)
+        // "\n"* =  => ActionFn(108);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action108::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (0, 0)
     }
     fn __reduce1<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // "\n"? =  => ActionFn(88);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action88::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 0)
+        // "\n"* = "\n"+ => ActionFn(109);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action109::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 0)
     }
     fn __reduce2<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," ConditionArg) = ",", ConditionArg => ActionFn(80);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
+        // "\n"+ = "\n" => ActionFn(121);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action80::<>(input, __sym0, __sym1);
+        let __end = __sym0.2;
+        let __nt = super::__action121::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 1)
+        (1, 1)
     }
     fn __reduce3<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," ConditionArg)* =  => ActionFn(78);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action78::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 2)
+        // "\n"+ = "\n"+, "\n" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action122::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 1)
     }
     fn __reduce4<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," ConditionArg)* = ("," ConditionArg)+ => ActionFn(79);
-        let __sym0 = __pop_Variant6(__symbols);
+        // "\n"? = "\n" => ActionFn(106);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action79::<>(input, __sym0);
+        let __nt = super::__action106::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (1, 2)
     }
     fn __reduce5<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("," ConditionArg)+ = ",", ConditionArg => ActionFn(109);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action109::<>(input, __sym0, __sym1);
+        // "\n"? =  => ActionFn(107);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action107::<>(input, errors, warnings, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 3)
+        (0, 2)
     }
     fn __reduce6<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // ("," ConditionArg)+ = ("," ConditionArg)+, ",", ConditionArg => ActionFn(110);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant13(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action110::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 3)
-    }
-    fn __reduce7<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("\n" IRelationOrPermission) = "\n", IRelationOrPermission => ActionFn(86);
+        // ("," ConditionArg) = ",", ConditionArg => ActionFn(99);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action86::<>(input, __sym0, __sym1);
+        let __nt = super::__action99::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 4)
+        (2, 3)
     }
-    fn __reduce8<
+    fn __reduce7<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("\n" IRelationOrPermission)* =  => ActionFn(84);
+        // ("," ConditionArg)* =  => ActionFn(97);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action84::<>(input, &__start, &__end);
+        let __nt = super::__action97::<>(input, errors, warnings, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 5)
+        (0, 4)
     }
-    fn __reduce9<
+    fn __reduce8<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("\n" IRelationOrPermission)* = ("\n" IRelationOrPermission)+ => ActionFn(85);
+        // ("," ConditionArg)* = ("," ConditionArg)+ => ActionFn(98);
         let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action85::<>(input, __sym0);
+        let __nt = super::__action98::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 5)
+        (1, 4)
     }
-    fn __reduce10<
+    fn __reduce9<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("\n" IRelationOrPermission)+ = "\n", IRelationOrPermission => ActionFn(113);
+        // ("," ConditionArg)+ = ",", ConditionArg => ActionFn(148);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action113::<>(input, __sym0, __sym1);
+        let __nt = super::__action148::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 6)
+        (2, 5)
     }
-    fn __reduce11<
+    fn __reduce10<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("\n" IRelationOrPermission)+ = ("\n" IRelationOrPermission)+, "\n", IRelationOrPermission => ActionFn(114);
+        // ("," ConditionArg)+ = ("," ConditionArg)+, ",", ConditionArg => ActionFn(149);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant22(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action114::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action149::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 6)
+        (3, 5)
     }
-    fn __reduce12<
+    fn __reduce11<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("|" RRelationshipSet) = "|", RRelationshipSet => ActionFn(83);
+        // ("," identifier) = ",", identifier => ActionFn(112);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action83::<>(input, __sym0, __sym1);
+        let __nt = super::__action112::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (2, 7)
+        (2, 6)
     }
-    fn __reduce13<
+    fn __reduce12<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("|" RRelationshipSet)* =  => ActionFn(81);
+        // ("," identifier)* =  => ActionFn(110);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action81::<>(input, &__start, &__end);
+        let __nt = super::__action110::<>(input, errors, warnings, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (0, 8)
+        (0, 7)
     }
-    fn __reduce14<
+    fn __reduce13<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("|" RRelationshipSet)* = ("|" RRelationshipSet)+ => ActionFn(82);
+        // ("," identifier)* = ("," identifier)+ => ActionFn(111);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action82::<>(input, __sym0);
+        let __nt = super::__action111::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 8)
+        (1, 7)
     }
-    fn __reduce15<
+    fn __reduce14<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("|" RRelationshipSet)+ = "|", RRelationshipSet => ActionFn(123);
+        // ("," identifier)+ = ",", identifier => ActionFn(152);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action123::<>(input, __sym0, __sym1);
+        let __nt = super::__action152::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 9)
+        (2, 8)
     }
-    fn __reduce16<
+    fn __reduce15<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ("|" RRelationshipSet)+ = ("|" RRelationshipSet)+, "|", RRelationshipSet => ActionFn(124);
+        // ("," identifier)+ = ("," identifier)+, ",", identifier => ActionFn(153);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant21(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action124::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action153::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (3, 9)
+        (3, 8)
     }
-    fn __reduce17<
+    fn __reduce16<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(92);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action92::<>(input, &__start, &__end);
+        // ("\n" IRelationOrPermission) = "\n", IRelationOrPermission => ActionFn(105);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action105::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 10)
+        (2, 9)
     }
-    fn __reduce18<
+    fn __reduce17<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(91);
+        // ("\n" IRelationOrPermission)* =  => ActionFn(103);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action91::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 11)
+        let __nt = super::__action103::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 10)
     }
-    fn __reduce19<
+    fn __reduce18<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Condition = "condition", identifier, "(", ConditionArgs, ")", "{", "}" => ActionFn(135);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant14(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ("\n" IRelationOrPermission)* = ("\n" IRelationOrPermission)+ => ActionFn(104);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action135::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __end = __sym0.2;
+        let __nt = super::__action104::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (7, 12)
+        (1, 10)
     }
-    fn __reduce20<
+    fn __reduce19<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Condition = "condition", identifier, "(", ConditionArgs, ")", "{", ConditionExpression+, "}" => ActionFn(136);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant0(__symbols);
-        let __sym6 = __pop_Variant16(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant14(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        // ("\n" IRelationOrPermission)+ = "\n", IRelationOrPermission => ActionFn(156);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant26(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym7.2;
-        let __nt = super::__action136::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __end = __sym1.2;
+        let __nt = super::__action156::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (8, 12)
+        (2, 11)
     }
-    fn __reduce21<
+    fn __reduce20<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionArg = identifier, ":", ConditionType => ActionFn(25);
+        // ("\n" IRelationOrPermission)+ = ("\n" IRelationOrPermission)+, "\n", IRelationOrPermission => ActionFn(157);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant26(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action25::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (3, 13)
+        let __nt = super::__action157::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 11)
     }
-    fn __reduce22<
+    fn __reduce21<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionArgs = ConditionArg => ActionFn(111);
-        let __sym0 = __pop_Variant13(__symbols);
+        // ("|" RelationSubject) = "|", RelationSubject => ActionFn(102);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant30(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action111::<>(input, __sym0);
+        let __end = __sym1.2;
+        let __nt = super::__action102::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 12)
+    }
+    fn __reduce22<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("|" RelationSubject)* =  => ActionFn(100);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action100::<>(input, errors, warnings, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 14)
+        (0, 13)
     }
     fn __reduce23<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionArgs = ConditionArg, ("," ConditionArg)+ => ActionFn(112);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
+        // ("|" RelationSubject)* = ("|" RelationSubject)+ => ActionFn(101);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action112::<>(input, __sym0, __sym1);
+        let __end = __sym0.2;
+        let __nt = super::__action101::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 14)
+        (1, 13)
     }
     fn __reduce24<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = identifier => ActionFn(38);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ("|" RelationSubject)+ = "|", RelationSubject => ActionFn(166);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant30(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2;
+        let __nt = super::__action166::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 14)
     }
     fn __reduce25<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = ":" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ("|" RelationSubject)+ = ("|" RelationSubject)+, "|", RelationSubject => ActionFn(167);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant30(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym2.2;
+        let __nt = super::__action167::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 14)
     }
     fn __reduce26<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "(" => ActionFn(40);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ConditionExpression> ",") = ConditionExpression, "," => ActionFn(133);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action40::<>(input, __sym0);
+        let __end = __sym1.2;
+        let __nt = super::__action133::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (2, 15)
     }
     fn __reduce27<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = ")" => ActionFn(41);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action41::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        // (<ConditionExpression> ",")* =  => ActionFn(131);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action131::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 16)
     }
     fn __reduce28<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "^" => ActionFn(42);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ConditionExpression> ",")* = (<ConditionExpression> ",")+ => ActionFn(132);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action132::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 16)
     }
     fn __reduce29<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "&" => ActionFn(43);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ConditionExpression> ",")+ = ConditionExpression, "," => ActionFn(170);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2;
+        let __nt = super::__action170::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (2, 17)
     }
     fn __reduce30<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "|" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ConditionExpression> ",")+ = (<ConditionExpression> ",")+, ConditionExpression, "," => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym2.2;
+        let __nt = super::__action171::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 17)
     }
     fn __reduce31<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "+" => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        // @L =  => ActionFn(114);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action114::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 18)
     }
     fn __reduce32<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "-" => ActionFn(46);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action46::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        // @R =  => ActionFn(113);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action113::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 19)
     }
     fn __reduce33<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "->" => ActionFn(47);
+        // CastType = "int" => ActionFn(88);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action47::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action88::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce34<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "#" => ActionFn(48);
+        // CastType = "uint" => ActionFn(89);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action89::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce35<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "\n" => ActionFn(49);
+        // CastType = "double" => ActionFn(90);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action49::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action90::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce36<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "*" => ActionFn(50);
+        // CastType = "bool" => ActionFn(91);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action91::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce37<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "/" => ActionFn(51);
+        // CastType = "string" => ActionFn(92);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action51::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action92::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce38<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "%" => ActionFn(52);
+        // CastType = "duration" => ActionFn(93);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action93::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce39<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "," => ActionFn(53);
+        // CastType = "timestamp" => ActionFn(94);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action53::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action94::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce40<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = ";" => ActionFn(54);
+        // CastType = "ipaddress" => ActionFn(95);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action95::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
     }
     fn __reduce41<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "=" => ActionFn(55);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<ConditionExpression> = ConditionExpression => ActionFn(248);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action55::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action248::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 21)
     }
     fn __reduce42<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "!" => ActionFn(56);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action56::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        // Comma<ConditionExpression> =  => ActionFn(249);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action249::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 21)
     }
     fn __reduce43<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = ">" => ActionFn(57);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<ConditionExpression> = (<ConditionExpression> ",")+, ConditionExpression => ActionFn(250);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2;
+        let __nt = super::__action250::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 21)
     }
     fn __reduce44<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "<" => ActionFn(58);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<ConditionExpression> = (<ConditionExpression> ",")+ => ActionFn(251);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action58::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action251::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 21)
     }
     fn __reduce45<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "int" => ActionFn(59);
+        // CompareOperator = "==" => ActionFn(62);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action59::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action62::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
     }
     fn __reduce46<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "uint" => ActionFn(60);
+        // CompareOperator = "!=" => ActionFn(63);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action60::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action63::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
     }
     fn __reduce47<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "double" => ActionFn(61);
+        // CompareOperator = "<" => ActionFn(64);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action61::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action64::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
     }
     fn __reduce48<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "bool" => ActionFn(62);
+        // CompareOperator = "<=" => ActionFn(65);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action65::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
     }
     fn __reduce49<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "bytes" => ActionFn(63);
+        // CompareOperator = ">" => ActionFn(66);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action63::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action66::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
     }
     fn __reduce50<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "string" => ActionFn(64);
+        // CompareOperator = ">=" => ActionFn(67);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action67::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
     }
     fn __reduce51<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "duration" => ActionFn(65);
+        // Condition = "condition", identifier, "(", ConditionArgs, ")", "{", ConditionBody, "}" => ActionFn(211);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant15(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action65::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym7.2;
+        let __nt = super::__action211::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (8, 23)
     }
     fn __reduce52<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "timestamp" => ActionFn(66);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionAddExpression = ConditionAddExpression, "+", ConditionMulExpression => ActionFn(212);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action66::<>(input, __sym0);
+        let __end = __sym2.2;
+        let __nt = super::__action212::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (3, 24)
     }
     fn __reduce53<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "any" => ActionFn(67);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionAddExpression = ConditionAddExpression, "-", ConditionMulExpression => ActionFn(213);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action67::<>(input, __sym0);
+        let __end = __sym2.2;
+        let __nt = super::__action213::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (3, 24)
     }
     fn __reduce54<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "list" => ActionFn(68);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionAddExpression = ConditionMulExpression => ActionFn(70);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action68::<>(input, __sym0);
+        let __nt = super::__action70::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (1, 24)
     }
     fn __reduce55<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "map" => ActionFn(69);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionAndExpression = ConditionAndExpression, "&&", "\n", ConditionCompareExpression => ActionFn(214);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action69::<>(input, __sym0);
+        let __end = __sym3.2;
+        let __nt = super::__action214::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (4, 25)
     }
     fn __reduce56<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "ipaddress" => ActionFn(70);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionAndExpression = ConditionAndExpression, "&&", ConditionCompareExpression => ActionFn(215);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action70::<>(input, __sym0);
+        let __end = __sym2.2;
+        let __nt = super::__action215::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (3, 25)
     }
     fn __reduce57<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = string => ActionFn(71);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ConditionAndExpression = ConditionCompareExpression => ActionFn(59);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action71::<>(input, __sym0);
+        let __nt = super::__action59::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (1, 25)
     }
     fn __reduce58<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = int => ActionFn(72);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ConditionArg = identifier, ":", ConditionType => ActionFn(43);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym2.2;
+        let __nt = super::__action43::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (3, 26)
     }
     fn __reduce59<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = double => ActionFn(73);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ConditionArgs = ConditionArg => ActionFn(150);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action73::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __nt = super::__action150::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 27)
     }
     fn __reduce60<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "$" => ActionFn(74);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionArgs = ConditionArg, ("," ConditionArg)+ => ActionFn(151);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        let __end = __sym1.2;
+        let __nt = super::__action151::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 27)
     }
     fn __reduce61<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "`" => ActionFn(75);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionBody = ConditionExpression => ActionFn(136);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action75::<>(input, __sym0);
+        let __nt = super::__action136::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (1, 28)
     }
     fn __reduce62<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = white_space => ActionFn(76);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionBody = ConditionExpression, "\n"+ => ActionFn(137);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action76::<>(input, __sym0);
+        let __end = __sym1.2;
+        let __nt = super::__action137::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (2, 28)
     }
     fn __reduce63<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression = "." => ActionFn(77);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionBody = "\n"+, ConditionExpression => ActionFn(138);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action77::<>(input, __sym0);
+        let __end = __sym1.2;
+        let __nt = super::__action138::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 15)
+        (2, 28)
     }
     fn __reduce64<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression* =  => ActionFn(89);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action89::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 16)
+        // ConditionBody = "\n"+, ConditionExpression, "\n"+ => ActionFn(139);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action139::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 28)
     }
     fn __reduce65<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression* = ConditionExpression+ => ActionFn(90);
-        let __sym0 = __pop_Variant16(__symbols);
+        // ConditionCompareExpression = ConditionAddExpression, CompareOperator, ConditionAddExpression => ActionFn(216);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action90::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2;
+        let __nt = super::__action216::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 29)
     }
     fn __reduce66<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression+ = ConditionExpression => ActionFn(97);
+        // ConditionCompareExpression = ConditionAddExpression => ActionFn(61);
         let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action97::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 17)
+        let __nt = super::__action61::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 29)
     }
     fn __reduce67<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionExpression+ = ConditionExpression+, ConditionExpression => ActionFn(98);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant16(__symbols);
+        // ConditionExpression = ConditionExpression, "||", "\n", ConditionAndExpression => ActionFn(217);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action98::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (2, 17)
+        let __end = __sym3.2;
+        let __nt = super::__action217::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 30)
     }
     fn __reduce68<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "int" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionExpression = ConditionExpression, "||", ConditionAndExpression => ActionFn(218);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2;
+        let __nt = super::__action218::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 30)
     }
     fn __reduce69<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "uint" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionExpression = ConditionAndExpression => ActionFn(57);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __nt = super::__action57::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 30)
     }
     fn __reduce70<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "double" => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionExpression? = ConditionExpression => ActionFn(129);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __nt = super::__action129::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 31)
     }
     fn __reduce71<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "bool" => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        // ConditionExpression? =  => ActionFn(130);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action130::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (0, 31)
     }
     fn __reduce72<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "bytes" => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionMulExpression = ConditionMulExpression, "*", ConditionUnaryExpression => ActionFn(219);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2;
+        let __nt = super::__action219::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 32)
     }
     fn __reduce73<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "string" => ActionFn(31);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionMulExpression = ConditionMulExpression, "/", ConditionUnaryExpression => ActionFn(220);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action31::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2;
+        let __nt = super::__action220::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 32)
     }
     fn __reduce74<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "duration" => ActionFn(32);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionMulExpression = ConditionMulExpression, "%", ConditionUnaryExpression => ActionFn(221);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __end = __sym2.2;
+        let __nt = super::__action221::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 32)
     }
     fn __reduce75<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "timestamp" => ActionFn(33);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionMulExpression = ConditionUnaryExpression => ActionFn(74);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __nt = super::__action74::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 32)
     }
     fn __reduce76<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "any" => ActionFn(34);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionPostfixExpression = ConditionPostfixExpression, ".", identifier, "(", Comma<ConditionExpression>, ")" => ActionFn(222);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant19(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __end = __sym5.2;
+        let __nt = super::__action222::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (6, 33)
     }
     fn __reduce77<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "list", "<", ConditionType, ">" => ActionFn(35);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant17(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionPostfixExpression = ConditionPrimaryExpression => ActionFn(79);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action35::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (4, 18)
+        let __end = __sym0.2;
+        let __nt = super::__action79::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 33)
     }
     fn __reduce78<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "map", "<", ConditionType, ">" => ActionFn(36);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant17(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionPrimaryExpression = identifier => ActionFn(223);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action36::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (4, 18)
+        let __end = __sym0.2;
+        let __nt = super::__action223::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     fn __reduce79<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // ConditionType = "ipaddress" => ActionFn(37);
+        // ConditionPrimaryExpression = "true" => ActionFn(224);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 18)
+        let __nt = super::__action224::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     fn __reduce80<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermission = Relation => ActionFn(10);
-        let __sym0 = __pop_Variant22(__symbols);
+        // ConditionPrimaryExpression = "false" => ActionFn(225);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 19)
+        let __nt = super::__action225::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     fn __reduce81<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermission = Permission => ActionFn(11);
-        let __sym0 = __pop_Variant20(__symbols);
+        // ConditionPrimaryExpression = string => ActionFn(226);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 19)
+        let __nt = super::__action226::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     fn __reduce82<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = "\n", IRelationOrPermission, "\n" => ActionFn(115);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant18(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionPrimaryExpression = int => ActionFn(227);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action227::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     fn __reduce83<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = "\n", IRelationOrPermission, ("\n" IRelationOrPermission)+, "\n" => ActionFn(116);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant8(__symbols);
-        let __sym1 = __pop_Variant18(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionPrimaryExpression = double => ActionFn(228);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (4, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action228::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
     }
     fn __reduce84<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = "\n", IRelationOrPermission => ActionFn(117);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant18(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionPrimaryExpression = CastType, "(", ConditionExpression, ")" => ActionFn(229);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action117::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 20)
+        let __end = __sym3.2;
+        let __nt = super::__action229::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 34)
     }
     fn __reduce85<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = "\n", IRelationOrPermission, ("\n" IRelationOrPermission)+ => ActionFn(118);
+        // ConditionPrimaryExpression = "(", ConditionExpression, ")" => ActionFn(87);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant8(__symbols);
-        let __sym1 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action118::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (3, 20)
+        let __nt = super::__action87::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 34)
     }
     fn __reduce86<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = IRelationOrPermission, "\n" => ActionFn(119);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant18(__symbols);
+        // ConditionType = "int" => ActionFn(44);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action119::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action44::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce87<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = IRelationOrPermission, ("\n" IRelationOrPermission)+, "\n" => ActionFn(120);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant18(__symbols);
+        // ConditionType = "uint" => ActionFn(45);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action120::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action45::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce88<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = IRelationOrPermission => ActionFn(121);
-        let __sym0 = __pop_Variant18(__symbols);
+        // ConditionType = "double" => ActionFn(46);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action121::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 20)
+        let __nt = super::__action46::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce89<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IRelationOrPermissions = IRelationOrPermission, ("\n" IRelationOrPermission)+ => ActionFn(122);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant18(__symbols);
+        // ConditionType = "bool" => ActionFn(47);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action122::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action47::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce90<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Permission = "permission", identifier, ":", RelationshipExpr => ActionFn(16);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant23(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        // ConditionType = "bytes" => ActionFn(48);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action16::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (4, 21)
+        let __end = __sym0.2;
+        let __nt = super::__action48::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce91<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RRelationshipSet = identifier => ActionFn(14);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ConditionType = "string" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 22)
+        let __nt = super::__action49::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce92<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RRelationshipSet = identifier, "#", identifier => ActionFn(15);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // ConditionType = "duration" => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action15::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (3, 22)
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce93<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Relation = "relation", identifier, ":", RelationshipSets => ActionFn(12);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant24(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
+        // ConditionType = "timestamp" => ActionFn(51);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action12::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (4, 23)
+        let __end = __sym0.2;
+        let __nt = super::__action51::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce94<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipExpr = RelationshipExpr, "+", RelationshipFactor => ActionFn(17);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant23(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant23(__symbols);
+        // ConditionType = "any" => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (3, 24)
+        let __end = __sym0.2;
+        let __nt = super::__action52::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce95<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipExpr = RelationshipExpr, "-", RelationshipFactor => ActionFn(18);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant23(__symbols);
+        // ConditionType = "list", "<", ConditionType, ">" => ActionFn(53);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action18::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (3, 24)
+        let __end = __sym3.2;
+        let __nt = super::__action53::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 35)
     }
     fn __reduce96<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipExpr = RelationshipFactor => ActionFn(19);
-        let __sym0 = __pop_Variant23(__symbols);
+        // ConditionType = "map", "<", ConditionType, ">" => ActionFn(54);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 24)
+        let __end = __sym3.2;
+        let __nt = super::__action54::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 35)
     }
     fn __reduce97<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipFactor = RelationshipFactor, "&", RelationshipSetTerm => ActionFn(20);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant23(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant23(__symbols);
+        // ConditionType = "ipaddress" => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action20::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (3, 25)
+        let __end = __sym0.2;
+        let __nt = super::__action55::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
     }
     fn __reduce98<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipFactor = RelationshipSetTerm => ActionFn(21);
-        let __sym0 = __pop_Variant23(__symbols);
+        // ConditionUnaryExpression = "!", ConditionUnaryExpression => ActionFn(230);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 25)
+        let __end = __sym1.2;
+        let __nt = super::__action230::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 36)
     }
     fn __reduce99<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipSetTerm = RRelationshipSet => ActionFn(22);
-        let __sym0 = __pop_Variant21(__symbols);
+        // ConditionUnaryExpression = "-", ConditionUnaryExpression => ActionFn(231);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 26)
+        let __end = __sym1.2;
+        let __nt = super::__action231::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 36)
     }
     fn __reduce100<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipSetTerm = "(", RelationshipExpr, ")" => ActionFn(23);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant23(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ConditionUnaryExpression = ConditionPostfixExpression => ActionFn(77);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action23::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (3, 26)
+        let __end = __sym0.2;
+        let __nt = super::__action77::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 36)
     }
     fn __reduce101<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipSets = RRelationshipSet => ActionFn(125);
-        let __sym0 = __pop_Variant21(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action125::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 27)
-    }
-    fn __reduce102<
+        // Extends =  => ActionFn(13);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action13::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 37)
+    }
+    fn __reduce102<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RelationshipSets = RRelationshipSet, ("|" RRelationshipSet)+ => ActionFn(126);
+        // Extends = "extends", identifier => ActionFn(154);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant21(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action126::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (2, 27)
+        let __nt = super::__action154::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (2, 37)
     }
     fn __reduce103<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Schema =  => ActionFn(137);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action137::<>(input, &__start, &__end);
+        // Extends = "extends", identifier, ("," identifier)+ => ActionFn(155);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action155::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (0, 28)
+        (3, 37)
     }
     fn __reduce104<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Schema = SchemaUnit+ => ActionFn(138);
-        let __sym0 = __pop_Variant27(__symbols);
+        // IRelationOrPermission = Relation => ActionFn(20);
+        let __sym0 = __pop_Variant31(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action138::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 28)
+        let __nt = super::__action20::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 38)
     }
     fn __reduce105<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit = Type => ActionFn(2);
-        let __sym0 = __pop_Variant28(__symbols);
+        // IRelationOrPermission = Permission => ActionFn(21);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action2::<>(input, __sym0);
+        let __nt = super::__action21::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 29)
+        (1, 38)
     }
     fn __reduce106<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit = Condition => ActionFn(3);
-        let __sym0 = __pop_Variant12(__symbols);
+        // IRelationOrPermission = error => ActionFn(22);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action3::<>(input, __sym0);
+        let __nt = super::__action22::<>(input, errors, warnings, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 29)
+        (1, 38)
     }
     fn __reduce107<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit = SchemaUnit, "\n" => ActionFn(4);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant26(__symbols);
+        // IRelationOrPermissions = "\n", IRelationOrPermission, "\n" => ActionFn(158);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action4::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (2, 29)
+        let __end = __sym2.2;
+        let __nt = super::__action158::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 39)
     }
     fn __reduce108<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit* =  => ActionFn(93);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action93::<>(input, &__start, &__end);
+        // IRelationOrPermissions = "\n", IRelationOrPermission, ("\n" IRelationOrPermission)+, "\n" => ActionFn(159);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action159::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (0, 30)
+        (4, 39)
     }
     fn __reduce109<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit* = SchemaUnit+ => ActionFn(94);
-        let __sym0 = __pop_Variant27(__symbols);
+        // IRelationOrPermissions = "\n", IRelationOrPermission => ActionFn(160);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action94::<>(input, __sym0);
+        let __end = __sym1.2;
+        let __nt = super::__action160::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 30)
+        (2, 39)
     }
     fn __reduce110<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit+ = SchemaUnit => ActionFn(95);
-        let __sym0 = __pop_Variant26(__symbols);
+        // IRelationOrPermissions = "\n", IRelationOrPermission, ("\n" IRelationOrPermission)+ => ActionFn(161);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action95::<>(input, __sym0);
+        let __end = __sym2.2;
+        let __nt = super::__action161::<>(input, errors, warnings, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 31)
+        (3, 39)
     }
     fn __reduce111<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // SchemaUnit+ = SchemaUnit+, SchemaUnit => ActionFn(96);
+        // IRelationOrPermissions = IRelationOrPermission, "\n" => ActionFn(162);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant26(__symbols);
-        let __sym0 = __pop_Variant27(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action96::<>(input, __sym0, __sym1);
+        let __nt = super::__action162::<>(input, errors, warnings, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (2, 31)
+        (2, 39)
     }
     fn __reduce112<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Type = "type", identifier, "{", IRelationOrPermissions, "}" => ActionFn(132);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant19(__symbols);
+        // IRelationOrPermissions = IRelationOrPermission, ("\n" IRelationOrPermission)+, "\n" => ActionFn(163);
+        assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant2(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action132::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (5, 32)
+        let __end = __sym2.2;
+        let __nt = super::__action163::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 39)
     }
     fn __reduce113<
         'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = IRelationOrPermission => ActionFn(164);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action164::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 39)
+    }
+    fn __reduce114<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = IRelationOrPermission, ("\n" IRelationOrPermission)+ => ActionFn(165);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action165::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 39)
+    }
+    fn __reduce115<
+        'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Type = "type", identifier, "{", "}" => ActionFn(133);
+        // Mixin = "mixin", identifier, Extends, TypeBody => ActionFn(232);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant25(__symbols);
         let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2, __sym3);
+        let __nt = super::__action232::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (4, 32)
+        (4, 40)
     }
-    fn __reduce114<
+    fn __reduce116<
         'input,
+        'err,
     >(
         input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Type = "type", identifier => ActionFn(134);
-        assert!(__symbols.len() >= 2);
+        // Permission = "permission", identifier, ":", RelationshipExpr => ActionFn(233);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant32(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action134::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 32)
+        let __end = __sym3.2;
+        let __nt = super::__action233::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (4, 41)
     }
-}
-#[allow(unused_imports)]
-pub use self::__parse__Schema::SchemaParser;
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action0<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Schema, usize),
-) -> Schema
-{
-    __0
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action1<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, alloc::vec::Vec<SchemaUnit>, usize),
-) -> Schema
-{
-    Schema::new(__0)
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action2<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Type, usize),
-) -> SchemaUnit
-{
-    SchemaUnit::Type(__0)
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action3<
-    'input,
->(
-    input: &'input str,
-    (_, __0, _): (usize, Condition, usize),
-) -> SchemaUnit
-{
-    SchemaUnit::Condition(__0)
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action4<
-    'input,
->(
-    input: &'input str,
-    (_, su, _): (usize, SchemaUnit, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-) -> SchemaUnit
-{
-    su
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action5<
-    'input,
->(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, n, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, rops, _): (usize, Vec<RelationOrPermission>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Type
-{
-    Type::new(n.to_string(), rops)
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action6<
-    'input,
->(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, n, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Type
-{
-    Type::new(n.to_string(), vec![])
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action7<
-    'input,
->(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, n, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Type
-{
-    Type::new(n.to_string(), vec![])
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action8<
-    'input,
->(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, name, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, args, _): (usize, Vec<ConditionArg>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, body, _): (usize, alloc::vec::Vec<ConditionExpression>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Condition
-{
+    fn __reduce117<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
     {
-    Condition {
-      name: name.to_string(),
-      args,
-      body,
+        // RRelationshipSet = identifier => ActionFn(234);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action234::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 42)
     }
-  }
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action9<
-    'input,
->(
-    input: &'input str,
-    (_, _, _): (usize, core::option::Option<Token<'input>>, usize),
-    (_, s1, _): (usize, RelationOrPermission, usize),
+    fn __reduce118<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RRelationshipSet = identifier, "#", identifier => ActionFn(235);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action235::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 42)
+    }
+    fn __reduce119<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RRelationshipSet = identifier, "#", "*" => ActionFn(236);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action236::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 42)
+    }
+    fn __reduce120<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Relation = "relation", identifier, ":", RelationshipSets => ActionFn(237);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant33(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action237::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (4, 43)
+    }
+    fn __reduce121<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationSubject = RRelationshipSet => ActionFn(28);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action28::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 44)
+    }
+    fn __reduce122<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationSubject = RRelationshipSet, "with", identifier => ActionFn(238);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action238::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 44)
+    }
+    fn __reduce123<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationSubject = RRelationshipSet, "^", identifier => ActionFn(239);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action239::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 44)
+    }
+    fn __reduce124<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipExpr = RelationshipExpr, "+", RelationshipFactor => ActionFn(32);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant32(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action32::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 45)
+    }
+    fn __reduce125<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipExpr = RelationshipExpr, "-", RelationshipFactor => ActionFn(33);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant32(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action33::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 45)
+    }
+    fn __reduce126<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipExpr = RelationshipFactor => ActionFn(34);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action34::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 45)
+    }
+    fn __reduce127<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipFactor = RelationshipFactor, "&", RelationshipSetTerm => ActionFn(35);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant32(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action35::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 46)
+    }
+    fn __reduce128<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipFactor = RelationshipSetTerm => ActionFn(36);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action36::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 46)
+    }
+    fn __reduce129<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier => ActionFn(240);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action240::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 47)
+    }
+    fn __reduce130<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier, "->", identifier => ActionFn(241);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action241::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce131<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier, "#", identifier => ActionFn(242);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action242::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce132<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier, "#", "*" => ActionFn(243);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action243::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce133<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = "(", RelationshipExpr, ")" => ActionFn(41);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant32(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action41::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce134<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSets = RelationSubject => ActionFn(168);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action168::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 48)
+    }
+    fn __reduce135<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSets = RelationSubject, ("|" RelationSubject)+ => ActionFn(169);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action169::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (2, 48)
+    }
+    fn __reduce136<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Schema =  => ActionFn(252);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action252::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (0, 49)
+    }
+    fn __reduce137<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Schema = SchemaUnit+ => ActionFn(253);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action253::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 49)
+    }
+    fn __reduce138<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = Type => ActionFn(3);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action3::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce139<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = Mixin => ActionFn(4);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action4::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce140<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = Condition => ActionFn(5);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action5::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce141<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = "import", string => ActionFn(244);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action244::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (2, 50)
+    }
+    fn __reduce142<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = "superadmin", identifier, "#", identifier => ActionFn(245);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action245::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (4, 50)
+    }
+    fn __reduce143<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = SchemaUnit, "\n" => ActionFn(8);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action8::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (2, 50)
+    }
+    fn __reduce144<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = error => ActionFn(9);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action9::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce145<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit* =  => ActionFn(115);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action115::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 51)
+    }
+    fn __reduce146<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit* = SchemaUnit+ => ActionFn(116);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action116::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 51)
+    }
+    fn __reduce147<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit+ = SchemaUnit => ActionFn(117);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action117::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 52)
+    }
+    fn __reduce148<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit+ = SchemaUnit+, SchemaUnit => ActionFn(118);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant35(__symbols);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action118::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (2, 52)
+    }
+    fn __reduce149<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "type", identifier, Extends, TypeBody => ActionFn(246);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action246::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (4, 53)
+    }
+    fn __reduce150<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "type", identifier => ActionFn(247);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action247::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 53)
+    }
+    fn __reduce151<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // TypeBody = "{", IRelationOrPermissions, "}" => ActionFn(15);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action15::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 54)
+    }
+    fn __reduce152<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // TypeBody = "{", "}" => ActionFn(16);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action16::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 54)
+    }
+    fn __reduce154<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // __Schema = Schema => ActionFn(0);
+        let __sym0 = __pop_Variant34(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action0::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 56)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__ConditionBody::ConditionBodyParser;
+
+#[rustfmt::skip]
+#[allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::never_loop, clippy::match_single_binding, clippy::needless_raw_string_hashes)]
+mod __parse__Schema {
+
+    use crate::{ token::Token, lexer::{LexicalError, unescape}, ast::*, Diagnostic };
+    use lalrpop_util::ErrorRecovery;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<'input>
+     {
+        Variant0(Token<'input>),
+        Variant1(f64),
+        Variant2(&'input str),
+        Variant3(i64),
+        Variant4(__lalrpop_util::ErrorRecovery<usize, Token<'input>, LexicalError>),
+        Variant5(alloc::vec::Vec<Token<'input>>),
+        Variant6(core::option::Option<Token<'input>>),
+        Variant7((Token<'input>, ConditionArg)),
+        Variant8(alloc::vec::Vec<(Token<'input>, ConditionArg)>),
+        Variant9((Token<'input>, &'input str)),
+        Variant10(alloc::vec::Vec<(Token<'input>, &'input str)>),
+        Variant11((Token<'input>, RelationOrPermission)),
+        Variant12(alloc::vec::Vec<(Token<'input>, RelationOrPermission)>),
+        Variant13((Token<'input>, RelationshipSet)),
+        Variant14(alloc::vec::Vec<(Token<'input>, RelationshipSet)>),
+        Variant15(ConditionExpression),
+        Variant16(alloc::vec::Vec<ConditionExpression>),
+        Variant17(usize),
+        Variant18(ConditionType),
+        Variant19(Vec<ConditionExpression>),
+        Variant20(BinaryOperator),
+        Variant21(Condition),
+        Variant22(ConditionArg),
+        Variant23(Vec<ConditionArg>),
+        Variant24(core::option::Option<ConditionExpression>),
+        Variant25(Vec<String>),
+        Variant26(RelationOrPermission),
+        Variant27(Vec<RelationOrPermission>),
+        Variant28(Type),
+        Variant29(Permission),
+        Variant30(RelationshipSet),
+        Variant31(Relation),
+        Variant32(Relationship),
+        Variant33(Vec<RelationshipSet>),
+        Variant34(Schema),
+        Variant35(SchemaUnit),
+        Variant36(alloc::vec::Vec<SchemaUnit>),
+    }
+    const __ACTION: &[i16] = &[
+        // State 0
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 59, 0, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 59, 0, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -151, 0, 0, 69, 0, -151, 0, 0, 0, 0, -151, 0, 0, 0, -151, 0, 0, -151, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, -151,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 84,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 90, 91, 0, 0, 92, 93, 0, 0, 0, 94, 95, 96, 97, 0, 0, 0, 98, 0, 99, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0,
+        // State 15
+        116, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 84,
+        // State 17
+        28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0,
+        // State 18
+        134, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 19
+        -67, 0, 135, 0, 0, 0, 0, -67, 0, -67, 0, 34, -67, 35, 0, 0, 0, 0, 0, 136, 137, 0, 138, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, -67, 0, 0, 0, 0, 0, 0,
+        // State 20
+        116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, -62, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 22
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 23
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 90, 91, 0, 0, 92, 93, 0, 0, 0, 94, 95, 96, 97, 0, 0, 0, 98, 0, 99, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 90, 91, 0, 0, 92, 93, 0, 0, 0, 94, 95, 96, 97, 0, 0, 0, 98, 0, 99, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 84,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 84,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0,
+        // State 30
+        116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, -64, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 32
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 33
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 34
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 35
+        44, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 36
+        45, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 37
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 38
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 39
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 84,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0,
+        // State 42
+        -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, -135, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 44
+        0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0,
+        // State 49
+        0, 22, 0, 0, 0, 0, 0, 0, 23, -43, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0,
+        // State 51
+        0, 22, 0, 0, 0, 0, 0, 0, 23, -45, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 118, 119, 0, 120, 0, 121, 122, 0, 0, 0, 0, 0, 123, 0, 124, 125, 0, 126, 0, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0,
+        // State 52
+        -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, -141, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141,
+        // State 53
+        -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, -148, 0, 0, 0, -148, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148,
+        // State 56
+        -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, -139, 0, 0, 0, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+        // State 62
+        -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, -145, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145,
+        // State 63
+        -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, -144, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144,
+        // State 64
+        64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, -149, 0, 0, 0, 0, -149, 0, 0, 0, -149, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, -142, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142,
+        // State 67
+        0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, -116, 0, 0, 0, -116, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116,
+        // State 73
+        -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, -143, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143,
+        // State 74
+        -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, 0, -150, 0, 0, 0, 0, -150, 0, 0, 0, -150, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0,
+        // State 78
+        -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0,
+        // State 79
+        -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0,
+        // State 82
+        -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, 0, 0, 0, -153, 0, 0, 0, 0, -153, 0, 0, 0, -153, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153,
+        // State 83
+        -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0,
+        // State 101
+        -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, -152, 0, 0, 0, 0, -152, 0, 0, 0, -152, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        -70, 0, 0, 0, 0, 0, 0, 36, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0,
+        // State 110
+        -58, 0, 0, 0, 0, 0, 0, -58, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, -58, 0, 0, 0, 0, 0, 0,
+        // State 111
+        -55, 0, -55, 0, 0, 38, 0, -55, 0, -55, 39, -55, -55, -55, 0, 0, 40, 0, 0, -55, -55, 0, -55, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0, 0,
+        // State 112
+        -101, 0, -101, 0, 0, -101, 0, -101, 0, -101, -101, -101, -101, -101, 0, 143, -101, 0, 0, -101, -101, 0, -101, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, 0, 0, 0, 0, 0, 0,
+        // State 113
+        -78, 0, -78, 0, 0, -78, 0, -78, 0, -78, -78, -78, -78, -78, 0, -78, -78, 0, 0, -78, -78, 0, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, 0, 0, 0, 0,
+        // State 114
+        -76, 0, -76, 0, 0, -76, 0, -76, 0, -76, -76, -76, -76, -76, 0, 0, -76, 0, 0, -76, -76, 0, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0, 0,
+        // State 115
+        -3, -3, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, -3, -3, 0, -3, 0, -3, -3, 0, 0, 0, 0, 0, -3, 0, -3, -3, 0, -3, 0, 0, 0, 0, -3, -3, -3, -3, -3, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        -81, 0, -81, 0, 0, -81, 0, -81, 0, -81, -81, -81, -81, -81, 0, -81, -81, 0, 0, -81, -81, 0, -81, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        -80, 0, -80, 0, 0, -80, 0, -80, 0, -80, -80, -80, -80, -80, 0, -80, -80, 0, 0, -80, -80, 0, -80, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80, -80, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        -84, 0, -84, 0, 0, -84, 0, -84, 0, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, -84, -84, 0, -84, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, 0, 0, 0,
+        // State 127
+        -79, 0, -79, 0, 0, -79, 0, -79, 0, -79, -79, -79, -79, -79, 0, -79, -79, 0, 0, -79, -79, 0, -79, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, 0, 0, 0, 0,
+        // State 128
+        -83, 0, -83, 0, 0, -83, 0, -83, 0, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, -83, -83, 0, -83, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, 0, 0, 0,
+        // State 129
+        -82, 0, -82, 0, 0, -82, 0, -82, 0, -82, -82, -82, -82, -82, 0, -82, -82, 0, 0, -82, -82, 0, -82, -82, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, 0, 0, 0, 0, 0, 0,
+        // State 130
+        -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0,
+        // State 131
+        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        -4, -4, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, -4, -4, 0, -4, 0, -4, -4, 0, 0, 0, 0, 0, -4, 0, -4, -4, 0, -4, 0, 0, 0, 0, -4, -4, -4, -4, -4, 0, 0,
+        // State 134
+        0, -47, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, -47, -47, 0, -47, 0, -47, -47, 0, 0, 0, 0, 0, -47, 0, -47, -47, 0, -47, 0, 0, 0, 0, 0, -47, -47, -47, -47, 0, 0,
+        // State 135
+        0, -48, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, -48, -48, 0, -48, 0, -48, -48, 0, 0, 0, 0, 0, -48, 0, -48, -48, 0, -48, 0, 0, 0, 0, 0, -48, -48, -48, -48, 0, 0,
+        // State 136
+        0, -49, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, -49, -49, 0, -49, 0, -49, -49, 0, 0, 0, 0, 0, -49, 0, -49, -49, 0, -49, 0, 0, 0, 0, 0, -49, -49, -49, -49, 0, 0,
+        // State 137
+        0, -46, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, -46, -46, 0, -46, 0, -46, -46, 0, 0, 0, 0, 0, -46, 0, -46, -46, 0, -46, 0, 0, 0, 0, 0, -46, -46, -46, -46, 0, 0,
+        // State 138
+        0, -50, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, -50, 0, -50, 0, -50, -50, 0, 0, 0, 0, 0, -50, 0, -50, -50, 0, -50, 0, 0, 0, 0, 0, -50, -50, -50, -50, 0, 0,
+        // State 139
+        0, -51, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, -51, 0, -51, 0, -51, -51, 0, 0, 0, 0, 0, -51, 0, -51, -51, 0, -51, 0, 0, 0, 0, 0, -51, -51, -51, -51, 0, 0,
+        // State 140
+        -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52,
+        // State 141
+        134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0,
+        // State 143
+        -99, 0, -99, 0, 0, -99, 0, -99, 0, -99, -99, -99, -99, -99, 0, 0, -99, 0, 0, -99, -99, 0, -99, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        -100, 0, -100, 0, 0, -100, 0, -100, 0, -100, -100, -100, -100, -100, 0, 0, -100, 0, 0, -100, -100, 0, -100, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0,
+        // State 149
+        -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0,
+        // State 150
+        -127, 0, 0, 0, 0, 0, 48, 0, 0, -127, 0, -127, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0,
+        // State 151
+        -129, 0, 0, 0, 0, 0, -129, 0, 0, -129, 0, -129, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0,
+        // State 152
+        -130, 0, 0, 172, 0, 0, -130, 0, 0, -130, 0, -130, 0, -130, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0,
+        // State 153
+        -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 175, 0, -122, 0, -122, 0, 0, 0, 0, 0, 0,
+        // State 154
+        -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0,
+        // State 155
+        -118, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, -118, 0, -118, 0, 0, 0, 0, 0, 0,
+        // State 156
+        134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        -66, 0, 0, 0, 0, 0, 0, -66, 0, -66, 0, 34, -66, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, -66, 0, 0, 0, 0, 0, 0,
+        // State 159
+        -53, 0, -53, 0, 0, 38, 0, -53, 0, -53, 39, -53, -53, -53, 0, 0, 40, 0, 0, -53, -53, 0, -53, -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, 0, 0, 0, 0, 0, 0,
+        // State 160
+        -54, 0, -54, 0, 0, 38, 0, -54, 0, -54, 39, -54, -54, -54, 0, 0, 40, 0, 0, -54, -54, 0, -54, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0, 0,
+        // State 161
+        -57, 0, 0, 0, 0, 0, 0, -57, 0, -57, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, 0, 0, 0, 0, 0, 0,
+        // State 162
+        -69, 0, 0, 0, 0, 0, 0, 36, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, -69, 0, 0, 0, 0, 0, 0,
+        // State 163
+        -75, 0, -75, 0, 0, -75, 0, -75, 0, -75, -75, -75, -75, -75, 0, 0, -75, 0, 0, -75, -75, 0, -75, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0, 0,
+        // State 164
+        -73, 0, -73, 0, 0, -73, 0, -73, 0, -73, -73, -73, -73, -73, 0, 0, -73, 0, 0, -73, -73, 0, -73, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, -73, 0, 0, 0, 0, 0, 0,
+        // State 165
+        -74, 0, -74, 0, 0, -74, 0, -74, 0, -74, -74, -74, -74, -74, 0, 0, -74, 0, 0, -74, -74, 0, -74, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        -86, 0, -86, 0, 0, -86, 0, -86, 0, -86, -86, -86, -86, -86, 0, -86, -86, 0, 0, -86, -86, 0, -86, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 0, 46, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 188, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0,
+        // State 175
+        -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, -136, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0,
+        // State 177
+        -85, 0, -85, 0, 0, -85, 0, -85, 0, -85, -85, -85, -85, -85, 0, -85, -85, 0, 0, -85, -85, 0, -85, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, 0, 0, 0,
+        // State 178
+        -56, 0, 0, 0, 0, 0, 0, -56, 0, -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, -56, 0, 0, 0, 0, 0, 0,
+        // State 179
+        -68, 0, 0, 0, 0, 0, 0, 36, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, -68, 0, 0, 0, 0, 0, 0,
+        // State 180
+        -125, 0, 0, 0, 0, 0, 48, 0, 0, -125, 0, -125, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0,
+        // State 181
+        -126, 0, 0, 0, 0, 0, 48, 0, 0, -126, 0, -126, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0,
+        // State 182
+        -128, 0, 0, 0, 0, 0, -128, 0, 0, -128, 0, -128, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0,
+        // State 183
+        -134, 0, 0, 0, 0, 0, -134, 0, 0, -134, 0, -134, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0,
+        // State 184
+        -133, 0, 0, 0, 0, 0, -133, 0, 0, -133, 0, -133, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0,
+        // State 185
+        -132, 0, 0, 0, 0, 0, -132, 0, 0, -132, 0, -132, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0,
+        // State 186
+        -131, 0, 0, 0, 0, 0, -131, 0, 0, -131, 0, -131, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0,
+        // State 187
+        -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, -124, 0, 0, 0, 0, 0, 0,
+        // State 188
+        -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, -123, 0, 0, 0, 0, 0, 0,
+        // State 189
+        -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, -25, 0, 0, 0, 0, 0, 0,
+        // State 190
+        -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, -120, 0, -120, 0, 0, 0, 0, 0, 0,
+        // State 191
+        -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, 0, -119, 0, -119, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+        // State 194
+        -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, -26, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        -77, 0, -77, 0, 0, -77, 0, -77, 0, -77, -77, -77, -77, -77, 0, -77, -77, 0, 0, -77, -77, 0, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, -30, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, -30, 0, -30, 0, -30, -30, 0, 0, 0, 0, 0, -30, 0, -30, -30, 0, -30, 0, 0, 0, 0, 0, -30, -30, -30, -30, 0, 0,
+        // State 198
+        0, -31, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, -31, 0, -31, 0, -31, -31, 0, 0, 0, 0, 0, -31, 0, -31, -31, 0, -31, 0, 0, 0, 0, 0, -31, -31, -31, -31, 0, 0,
+    ];
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 61 + integer]
+    }
+    const __EOF_ACTION: &[i16] = &[
+        // State 0
+        -137,
+        // State 1
+        -138,
+        // State 2
+        0,
+        // State 3
+        -151,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        0,
+        // State 23
+        0,
+        // State 24
+        0,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        0,
+        // State 50
+        0,
+        // State 51
+        0,
+        // State 52
+        -141,
+        // State 53
+        -140,
+        // State 54
+        -155,
+        // State 55
+        -148,
+        // State 56
+        -139,
+        // State 57
+        0,
+        // State 58
+        0,
+        // State 59
+        0,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        -145,
+        // State 63
+        -144,
+        // State 64
+        -149,
+        // State 65
+        0,
+        // State 66
+        -142,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        -116,
+        // State 73
+        -143,
+        // State 74
+        -150,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        0,
+        // State 82
+        -153,
+        // State 83
+        0,
+        // State 84
+        0,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        0,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        0,
+        // State 92
+        0,
+        // State 93
+        0,
+        // State 94
+        0,
+        // State 95
+        0,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        0,
+        // State 101
+        -152,
+        // State 102
+        0,
+        // State 103
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        0,
+        // State 118
+        0,
+        // State 119
+        0,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        -52,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        0,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        0,
+        // State 177
+        0,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+    ];
+    fn __goto(state: i16, nt: usize) -> i16 {
+        match nt {
+            1 => match state {
+                20 => 141,
+                30 => 156,
+                _ => 18,
+            },
+            5 => 75,
+            8 => 84,
+            11 => match state {
+                17 => 131,
+                _ => 100,
+            },
+            14 => 175,
+            17 => 51,
+            20 => 107,
+            21 => 192,
+            22 => 32,
+            23 => 52,
+            24 => match state {
+                32 => 158,
+                _ => 19,
+            },
+            25 => match state {
+                36 => 162,
+                44 => 179,
+                _ => 108,
+            },
+            26 => match state {
+                10 => 86,
+                14 => 106,
+                _ => 7,
+            },
+            27 => 70,
+            28 => 109,
+            29 => match state {
+                35 => 161,
+                43 => 178,
+                _ => 110,
+            },
+            30 => match state {
+                18 => 30,
+                22 => 144,
+                31 => 157,
+                49 => 193,
+                51 => 195,
+                _ => 20,
+            },
+            32 => match state {
+                33 => 159,
+                34 => 160,
+                _ => 111,
+            },
+            33 => 112,
+            34 => 113,
+            35 => match state {
+                24 => 146,
+                25 => 147,
+                _ => 87,
+            },
+            36 => match state {
+                21 => 143,
+                23 => 145,
+                37 => 163,
+                38 => 164,
+                39 => 165,
+                _ => 114,
+            },
+            37 => match state {
+                3 => 6,
+                _ => 5,
+            },
+            38 => match state {
+                8 => 12,
+                13 => 17,
+                26 | 40 => 148,
+                _ => 130,
+            },
+            39 => 77,
+            40 => 53,
+            41 => 78,
+            42 => 153,
+            43 => 79,
+            44 => match state {
+                48 => 189,
+                50 => 194,
+                _ => 42,
+            },
+            45 => match state {
+                41 => 170,
+                _ => 149,
+            },
+            46 => match state {
+                45 => 180,
+                46 => 181,
+                _ => 150,
+            },
+            47 => match state {
+                47 => 182,
+                _ => 151,
+            },
+            48 => 154,
+            49 => 54,
+            50 => match state {
+                1 => 64,
+                _ => 55,
+            },
+            52 => 1,
+            53 => 56,
+            54 => match state {
+                6 => 74,
+                _ => 72,
+            },
+            _ => 0,
+        }
+    }
+    const __TERMINAL: &[&str] = &[
+        r###""\n""###,
+        r###""!""###,
+        r###""!=""###,
+        r###""#""###,
+        r###""$""###,
+        r###""%""###,
+        r###""&""###,
+        r###""&&""###,
+        r###""(""###,
+        r###"")""###,
+        r###""*""###,
+        r###""+""###,
+        r###"",""###,
+        r###""-""###,
+        r###""->""###,
+        r###"".""###,
+        r###""/""###,
+        r###"":""###,
+        r###"";""###,
+        r###""<""###,
+        r###""<=""###,
+        r###""=""###,
+        r###""==""###,
+        r###"">""###,
+        r###"">=""###,
+        r###""^""###,
+        r###""`""###,
+        r###""any""###,
+        r###""bool""###,
+        r###""bytes""###,
+        r###""cond""###,
+        r###""condition""###,
+        r###""double""###,
+        r###""duration""###,
+        r###""extends""###,
+        r###""false""###,
+        r###""import""###,
+        r###""int""###,
+        r###""ipaddress""###,
+        r###""list""###,
+        r###""map""###,
+        r###""mixin""###,
+        r###""permission""###,
+        r###""relation""###,
+        r###""string""###,
+        r###""superadmin""###,
+        r###""timestamp""###,
+        r###""true""###,
+        r###""type""###,
+        r###""uint""###,
+        r###""with""###,
+        r###""{""###,
+        r###""|""###,
+        r###""||""###,
+        r###""}""###,
+        r###"double"###,
+        r###"identifier"###,
+        r###"int"###,
+        r###"string"###,
+        r###"white_space"###,
+    ];
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    fn __expected_tokens_from_states<
+        'input,
+        'err,
+    >(
+        __states: &[i16],
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> alloc::vec::Vec<alloc::string::String>
+    where
+        'input: 'err,
+    {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&(), &())>) {
+                Some(alloc::string::ToString::to_string(terminal))
+            } else {
+                None
+            }
+        }).collect()
+    }
+    struct __StateMachine<'input, 'err>
+    where 'input: 'err
+    {
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __phantom: core::marker::PhantomData<(&'input (), &'err ())>,
+    }
+    impl<'input, 'err> __state_machine::ParserDefinition for __StateMachine<'input, 'err>
+    where 'input: 'err
+    {
+        type Location = usize;
+        type Error = LexicalError;
+        type Token = Token<'input>;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<'input>;
+        type Success = Schema;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
+        }
+
+        #[inline]
+        fn action(&self, state: i16, integer: usize) -> i16 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 61 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i16) -> i16 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i16, nt: usize) -> i16 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
+        }
+
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        fn expected_tokens_from_states(&self, states: &[i16]) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens_from_states(states, core::marker::PhantomData::<(&(), &())>)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            true
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            __Symbol::Variant4(recovery)
+        }
+
+        fn reduce(
+            &mut self,
+            action: i16,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i16>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                self.input,
+                self.errors,
+                self.warnings,
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<(&(), &())>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
+        }
+    }
+    fn __token_to_integer<
+        'input,
+        'err,
+    >(
+        __token: &Token<'input>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Token::Newline if true => Some(0),
+            Token::ExclamationMark if true => Some(1),
+            Token::NotEq if true => Some(2),
+            Token::Sharp if true => Some(3),
+            Token::Dollar if true => Some(4),
+            Token::Percent if true => Some(5),
+            Token::And if true => Some(6),
+            Token::AndAnd if true => Some(7),
+            Token::LBracket if true => Some(8),
+            Token::RBracket if true => Some(9),
+            Token::Star if true => Some(10),
+            Token::Add if true => Some(11),
+            Token::Comma if true => Some(12),
+            Token::Sub if true => Some(13),
+            Token::YulArrow if true => Some(14),
+            Token::Point if true => Some(15),
+            Token::Slash if true => Some(16),
+            Token::Colon if true => Some(17),
+            Token::Semicolon if true => Some(18),
+            Token::Lt if true => Some(19),
+            Token::Le if true => Some(20),
+            Token::Eq if true => Some(21),
+            Token::EqEq if true => Some(22),
+            Token::Gt if true => Some(23),
+            Token::Ge if true => Some(24),
+            Token::Caret if true => Some(25),
+            Token::GraveAccent if true => Some(26),
+            Token::Any if true => Some(27),
+            Token::Bool if true => Some(28),
+            Token::Bytes if true => Some(29),
+            Token::Cond if true => Some(30),
+            Token::Condition if true => Some(31),
+            Token::Double if true => Some(32),
+            Token::Duration if true => Some(33),
+            Token::Extends if true => Some(34),
+            Token::False if true => Some(35),
+            Token::Import if true => Some(36),
+            Token::Int if true => Some(37),
+            Token::IPaddress if true => Some(38),
+            Token::List if true => Some(39),
+            Token::Map if true => Some(40),
+            Token::Mixin if true => Some(41),
+            Token::Permission if true => Some(42),
+            Token::Relation if true => Some(43),
+            Token::String if true => Some(44),
+            Token::Superadmin if true => Some(45),
+            Token::Timestamp if true => Some(46),
+            Token::True if true => Some(47),
+            Token::Type if true => Some(48),
+            Token::Uint if true => Some(49),
+            Token::With if true => Some(50),
+            Token::LBrace if true => Some(51),
+            Token::Or if true => Some(52),
+            Token::OrOr if true => Some(53),
+            Token::RBrace if true => Some(54),
+            Token::DoubleLiteral(_) if true => Some(55),
+            Token::Identifier(_) if true => Some(56),
+            Token::IntLiteral(_) if true => Some(57),
+            Token::StringLiteral(_) if true => Some(58),
+            Token::WhiteSpace if true => Some(59),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+        'input,
+        'err,
+    >(
+        __token_index: usize,
+        __token: Token<'input>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __Symbol<'input>
+    {
+        #[allow(clippy::manual_range_patterns)]match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 59 => __Symbol::Variant0(__token),
+            55 => match __token {
+                Token::DoubleLiteral(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            56 | 58 => match __token {
+                Token::Identifier(__tok0) | Token::StringLiteral(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            57 => match __token {
+                Token::IntLiteral(__tok0) if true => __Symbol::Variant3(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    fn __simulate_reduce<
+        'input,
+        'err,
+    >(
+        __reduce_index: i16,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'err>>
+    where
+        'input: 'err,
+    {
+        match __reduce_index {
+            0 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 0,
+                }
+            }
+            1 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 0,
+                }
+            }
+            2 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 1,
+                }
+            }
+            3 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 1,
+                }
+            }
+            4 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 2,
+                }
+            }
+            5 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 2,
+                }
+            }
+            6 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 3,
+                }
+            }
+            7 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 4,
+                }
+            }
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 5,
+                }
+            }
+            11 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 6,
+                }
+            }
+            12 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 7,
+                }
+            }
+            13 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            14 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
+            }
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 8,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 9,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 10,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
+                }
+            }
+            19 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 11,
+                }
+            }
+            20 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
+                }
+            }
+            21 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 12,
+                }
+            }
+            22 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 13,
+                }
+            }
+            23 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
+                }
+            }
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 14,
+                }
+            }
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 15,
+                }
+            }
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 16,
+                }
+            }
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 17,
+                }
+            }
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 17,
+                }
+            }
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
+                }
+            }
+            32 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 19,
+                }
+            }
+            33 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            34 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            35 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            36 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            37 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 21,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 21,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 23,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
+                }
+            }
+            55 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 25,
+                }
+            }
+            56 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 25,
+                }
+            }
+            57 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            58 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 26,
+                }
+            }
+            59 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            60 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 27,
+                }
+            }
+            61 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            62 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 28,
+                }
+            }
+            63 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 28,
+                }
+            }
+            64 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 28,
+                }
+            }
+            65 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
+                }
+            }
+            66 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            67 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 30,
+                }
+            }
+            68 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
+                }
+            }
+            69 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
+            }
+            70 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
+            }
+            71 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
+                }
+            }
+            72 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
+                }
+            }
+            73 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
+                }
+            }
+            74 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
+                }
+            }
+            75 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
+            }
+            76 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 33,
+                }
+            }
+            77 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 33,
+                }
+            }
+            78 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            79 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            80 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            81 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            82 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            83 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            84 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 34,
+                }
+            }
+            85 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 34,
+                }
+            }
+            86 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            87 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            88 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            89 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            90 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            91 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            92 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 35,
+                }
+            }
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 35,
+                }
+            }
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 36,
+                }
+            }
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 36,
+                }
+            }
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
+                }
+            }
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 37,
+                }
+            }
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 37,
+                }
+            }
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 37,
+                }
+            }
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 38,
+                }
+            }
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 38,
+                }
+            }
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 38,
+                }
+            }
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
+            }
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 39,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 39,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 39,
+                }
+            }
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
+            }
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
+            }
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 39,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 40,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 41,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 43,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
+                }
+            }
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
+                }
+            }
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 45,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 45,
+                }
+            }
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 45,
+                }
+            }
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 46,
+                }
+            }
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
+            }
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 47,
+                }
+            }
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 48,
+                }
+            }
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 49,
+                }
+            }
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 50,
+                }
+            }
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 51,
+                }
+            }
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 51,
+                }
+            }
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 52,
+                }
+            }
+            149 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            150 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 53,
+                }
+            }
+            151 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            152 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 54,
+                }
+            }
+            153 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            154 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct SchemaParser {
+        _priv: (),
+    }
+
+    impl Default for SchemaParser { fn default() -> Self { Self::new() } }
+    impl SchemaParser {
+        pub fn new() -> SchemaParser {
+            SchemaParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+            'err,
+            __TOKEN: __ToTriple<'input, 'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            input: &'input str,
+            errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+            warnings: &'err mut Vec<Diagnostic>,
+            __tokens0: __TOKENS,
+        ) -> Result<Schema, __lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    input,
+                    errors,
+                    warnings,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'input,
+        'err,
+    >(
+        __error_state: Option<i16>,
+        __states: &[i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> bool
+    where
+        'input: 'err,
+    {
+        let mut __states = __states.to_vec();
+        __states.extend(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    fn __reduce<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<Result<Schema,__lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            1 => {
+                __reduce1(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            2 => {
+                __reduce2(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            3 => {
+                __reduce3(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            4 => {
+                __reduce4(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            5 => {
+                __reduce5(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            6 => {
+                __reduce6(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            7 => {
+                __reduce7(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            8 => {
+                __reduce8(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            9 => {
+                __reduce9(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            10 => {
+                __reduce10(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            11 => {
+                __reduce11(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            12 => {
+                __reduce12(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            13 => {
+                __reduce13(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            14 => {
+                __reduce14(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            15 => {
+                __reduce15(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            16 => {
+                __reduce16(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            17 => {
+                __reduce17(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            18 => {
+                __reduce18(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            19 => {
+                __reduce19(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            20 => {
+                __reduce20(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            21 => {
+                __reduce21(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            22 => {
+                __reduce22(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            23 => {
+                __reduce23(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            24 => {
+                __reduce24(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
+                __reduce30(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            31 => {
+                __reduce31(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            32 => {
+                __reduce32(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            33 => {
+                __reduce33(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            34 => {
+                __reduce34(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            35 => {
+                __reduce35(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            36 => {
+                __reduce36(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            37 => {
+                __reduce37(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            38 => {
+                __reduce38(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            39 => {
+                __reduce39(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            40 => {
+                __reduce40(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            41 => {
+                __reduce41(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            42 => {
+                __reduce42(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            43 => {
+                __reduce43(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            44 => {
+                __reduce44(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            45 => {
+                __reduce45(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            46 => {
+                __reduce46(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            47 => {
+                __reduce47(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            48 => {
+                __reduce48(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            49 => {
+                __reduce49(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            50 => {
+                __reduce50(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            51 => {
+                __reduce51(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            52 => {
+                __reduce52(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            53 => {
+                __reduce53(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            54 => {
+                __reduce54(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            55 => {
+                __reduce55(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            56 => {
+                __reduce56(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            57 => {
+                __reduce57(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            58 => {
+                __reduce58(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            59 => {
+                __reduce59(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            60 => {
+                __reduce60(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            61 => {
+                __reduce61(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            62 => {
+                __reduce62(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            63 => {
+                __reduce63(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            64 => {
+                __reduce64(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            65 => {
+                __reduce65(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            66 => {
+                __reduce66(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            67 => {
+                __reduce67(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            68 => {
+                __reduce68(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            69 => {
+                __reduce69(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            70 => {
+                __reduce70(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            71 => {
+                __reduce71(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            72 => {
+                __reduce72(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            73 => {
+                __reduce73(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            74 => {
+                __reduce74(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            75 => {
+                __reduce75(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            76 => {
+                __reduce76(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            77 => {
+                __reduce77(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            78 => {
+                __reduce78(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            79 => {
+                __reduce79(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            80 => {
+                __reduce80(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            81 => {
+                __reduce81(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            82 => {
+                __reduce82(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            83 => {
+                __reduce83(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            84 => {
+                __reduce84(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            85 => {
+                __reduce85(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            86 => {
+                __reduce86(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            87 => {
+                __reduce87(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            88 => {
+                __reduce88(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            89 => {
+                __reduce89(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            90 => {
+                __reduce90(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            91 => {
+                __reduce91(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            92 => {
+                __reduce92(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            93 => {
+                __reduce93(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            94 => {
+                __reduce94(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            95 => {
+                __reduce95(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            96 => {
+                __reduce96(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            97 => {
+                __reduce97(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            98 => {
+                __reduce98(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            99 => {
+                __reduce99(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            100 => {
+                __reduce100(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            101 => {
+                __reduce101(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            102 => {
+                __reduce102(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            103 => {
+                __reduce103(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            104 => {
+                __reduce104(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            105 => {
+                __reduce105(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            106 => {
+                __reduce106(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            107 => {
+                __reduce107(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            108 => {
+                __reduce108(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            109 => {
+                __reduce109(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            110 => {
+                __reduce110(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            111 => {
+                __reduce111(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            112 => {
+                __reduce112(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            113 => {
+                __reduce113(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            114 => {
+                __reduce114(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            115 => {
+                __reduce115(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            116 => {
+                __reduce116(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            117 => {
+                __reduce117(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            148 => {
+                __reduce148(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            149 => {
+                __reduce149(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            150 => {
+                __reduce150(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            151 => {
+                __reduce151(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            152 => {
+                __reduce152(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            153 => {
+                __reduce153(input, errors, warnings, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            154 => {
+                // __Schema = Schema => ActionFn(0);
+                let __sym0 = __pop_Variant34(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = super::__action0::<>(input, errors, warnings, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Token<'input>, ConditionArg), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Token<'input>, RelationOrPermission), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Token<'input>, RelationshipSet), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Token<'input>, &'input str), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, BinaryOperator, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Condition, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ConditionArg, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ConditionExpression, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ConditionType, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant29<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Permission, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant31<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Relation, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, RelationOrPermission, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant32<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Relationship, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant30<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, RelationshipSet, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant34<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Schema, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant35<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SchemaUnit, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Token<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Type, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<ConditionArg>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<ConditionExpression>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant27<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<RelationOrPermission>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant33<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<RelationshipSet>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexicalError>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<ConditionExpression>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant36<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SchemaUnit>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Token<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<ConditionExpression>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Token<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, f64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __reduce0<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "\n"* =  => ActionFn(108);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action108::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (0, 0)
+    }
+    fn __reduce1<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "\n"* = "\n"+ => ActionFn(109);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action109::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 0)
+    }
+    fn __reduce2<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "\n"+ = "\n" => ActionFn(121);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action121::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 1)
+    }
+    fn __reduce3<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "\n"+ = "\n"+, "\n" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action122::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 1)
+    }
+    fn __reduce4<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "\n"? = "\n" => ActionFn(106);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action106::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 2)
+    }
+    fn __reduce5<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "\n"? =  => ActionFn(107);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action107::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 2)
+    }
+    fn __reduce6<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," ConditionArg) = ",", ConditionArg => ActionFn(99);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action99::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 3)
+    }
+    fn __reduce7<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," ConditionArg)* =  => ActionFn(97);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action97::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 4)
+    }
+    fn __reduce8<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," ConditionArg)* = ("," ConditionArg)+ => ActionFn(98);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action98::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 4)
+    }
+    fn __reduce9<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," ConditionArg)+ = ",", ConditionArg => ActionFn(148);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action148::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 5)
+    }
+    fn __reduce10<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," ConditionArg)+ = ("," ConditionArg)+, ",", ConditionArg => ActionFn(149);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant22(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action149::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 5)
+    }
+    fn __reduce11<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," identifier) = ",", identifier => ActionFn(112);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action112::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 6)
+    }
+    fn __reduce12<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," identifier)* =  => ActionFn(110);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action110::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 7)
+    }
+    fn __reduce13<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," identifier)* = ("," identifier)+ => ActionFn(111);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action111::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 7)
+    }
+    fn __reduce14<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," identifier)+ = ",", identifier => ActionFn(152);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action152::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 8)
+    }
+    fn __reduce15<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("," identifier)+ = ("," identifier)+, ",", identifier => ActionFn(153);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action153::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 8)
+    }
+    fn __reduce16<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("\n" IRelationOrPermission) = "\n", IRelationOrPermission => ActionFn(105);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action105::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 9)
+    }
+    fn __reduce17<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("\n" IRelationOrPermission)* =  => ActionFn(103);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action103::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 10)
+    }
+    fn __reduce18<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("\n" IRelationOrPermission)* = ("\n" IRelationOrPermission)+ => ActionFn(104);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action104::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 10)
+    }
+    fn __reduce19<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("\n" IRelationOrPermission)+ = "\n", IRelationOrPermission => ActionFn(156);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action156::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 11)
+    }
+    fn __reduce20<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("\n" IRelationOrPermission)+ = ("\n" IRelationOrPermission)+, "\n", IRelationOrPermission => ActionFn(157);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant26(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action157::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 11)
+    }
+    fn __reduce21<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("|" RelationSubject) = "|", RelationSubject => ActionFn(102);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant30(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action102::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 12)
+    }
+    fn __reduce22<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("|" RelationSubject)* =  => ActionFn(100);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action100::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 13)
+    }
+    fn __reduce23<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("|" RelationSubject)* = ("|" RelationSubject)+ => ActionFn(101);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action101::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 13)
+    }
+    fn __reduce24<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("|" RelationSubject)+ = "|", RelationSubject => ActionFn(166);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant30(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action166::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (2, 14)
+    }
+    fn __reduce25<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("|" RelationSubject)+ = ("|" RelationSubject)+, "|", RelationSubject => ActionFn(167);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant30(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action167::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 14)
+    }
+    fn __reduce26<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ConditionExpression> ",") = ConditionExpression, "," => ActionFn(133);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action133::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 15)
+    }
+    fn __reduce27<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ConditionExpression> ",")* =  => ActionFn(131);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action131::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 16)
+    }
+    fn __reduce28<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ConditionExpression> ",")* = (<ConditionExpression> ",")+ => ActionFn(132);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action132::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 16)
+    }
+    fn __reduce29<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ConditionExpression> ",")+ = ConditionExpression, "," => ActionFn(170);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action170::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (2, 17)
+    }
+    fn __reduce30<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ConditionExpression> ",")+ = (<ConditionExpression> ",")+, ConditionExpression, "," => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action171::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (3, 17)
+    }
+    fn __reduce31<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(114);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action114::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 18)
+    }
+    fn __reduce32<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(113);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action113::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 19)
+    }
+    fn __reduce33<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "int" => ActionFn(88);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action88::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce34<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "uint" => ActionFn(89);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action89::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce35<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "double" => ActionFn(90);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action90::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce36<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "bool" => ActionFn(91);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action91::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce37<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "string" => ActionFn(92);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action92::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce38<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "duration" => ActionFn(93);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action93::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce39<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "timestamp" => ActionFn(94);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action94::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce40<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CastType = "ipaddress" => ActionFn(95);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action95::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce41<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ConditionExpression> = ConditionExpression => ActionFn(248);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action248::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 21)
+    }
+    fn __reduce42<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ConditionExpression> =  => ActionFn(249);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action249::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 21)
+    }
+    fn __reduce43<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ConditionExpression> = (<ConditionExpression> ",")+, ConditionExpression => ActionFn(250);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action250::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 21)
+    }
+    fn __reduce44<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ConditionExpression> = (<ConditionExpression> ",")+ => ActionFn(251);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action251::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 21)
+    }
+    fn __reduce45<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CompareOperator = "==" => ActionFn(62);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action62::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce46<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CompareOperator = "!=" => ActionFn(63);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action63::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce47<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CompareOperator = "<" => ActionFn(64);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action64::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce48<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CompareOperator = "<=" => ActionFn(65);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action65::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce49<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CompareOperator = ">" => ActionFn(66);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action66::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce50<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CompareOperator = ">=" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action67::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce51<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Condition = "condition", identifier, "(", ConditionArgs, ")", "{", ConditionBody, "}" => ActionFn(211);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant15(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant23(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym7.2;
+        let __nt = super::__action211::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (8, 23)
+    }
+    fn __reduce52<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionAddExpression = ConditionAddExpression, "+", ConditionMulExpression => ActionFn(212);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action212::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 24)
+    }
+    fn __reduce53<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionAddExpression = ConditionAddExpression, "-", ConditionMulExpression => ActionFn(213);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action213::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 24)
+    }
+    fn __reduce54<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionAddExpression = ConditionMulExpression => ActionFn(70);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action70::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 24)
+    }
+    fn __reduce55<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionAndExpression = ConditionAndExpression, "&&", "\n", ConditionCompareExpression => ActionFn(214);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action214::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 25)
+    }
+    fn __reduce56<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionAndExpression = ConditionAndExpression, "&&", ConditionCompareExpression => ActionFn(215);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action215::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 25)
+    }
+    fn __reduce57<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionAndExpression = ConditionCompareExpression => ActionFn(59);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action59::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 25)
+    }
+    fn __reduce58<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionArg = identifier, ":", ConditionType => ActionFn(43);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action43::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (3, 26)
+    }
+    fn __reduce59<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionArgs = ConditionArg => ActionFn(150);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action150::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 27)
+    }
+    fn __reduce60<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionArgs = ConditionArg, ("," ConditionArg)+ => ActionFn(151);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action151::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 27)
+    }
+    fn __reduce61<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionBody = ConditionExpression => ActionFn(136);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action136::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 28)
+    }
+    fn __reduce62<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionBody = ConditionExpression, "\n"+ => ActionFn(137);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action137::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 28)
+    }
+    fn __reduce63<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionBody = "\n"+, ConditionExpression => ActionFn(138);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action138::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 28)
+    }
+    fn __reduce64<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionBody = "\n"+, ConditionExpression, "\n"+ => ActionFn(139);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action139::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 28)
+    }
+    fn __reduce65<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionCompareExpression = ConditionAddExpression, CompareOperator, ConditionAddExpression => ActionFn(216);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action216::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 29)
+    }
+    fn __reduce66<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionCompareExpression = ConditionAddExpression => ActionFn(61);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action61::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 29)
+    }
+    fn __reduce67<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionExpression = ConditionExpression, "||", "\n", ConditionAndExpression => ActionFn(217);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action217::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 30)
+    }
+    fn __reduce68<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionExpression = ConditionExpression, "||", ConditionAndExpression => ActionFn(218);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action218::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 30)
+    }
+    fn __reduce69<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionExpression = ConditionAndExpression => ActionFn(57);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action57::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 30)
+    }
+    fn __reduce70<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionExpression? = ConditionExpression => ActionFn(129);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action129::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 31)
+    }
+    fn __reduce71<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionExpression? =  => ActionFn(130);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action130::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (0, 31)
+    }
+    fn __reduce72<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionMulExpression = ConditionMulExpression, "*", ConditionUnaryExpression => ActionFn(219);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action219::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 32)
+    }
+    fn __reduce73<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionMulExpression = ConditionMulExpression, "/", ConditionUnaryExpression => ActionFn(220);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action220::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 32)
+    }
+    fn __reduce74<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionMulExpression = ConditionMulExpression, "%", ConditionUnaryExpression => ActionFn(221);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action221::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 32)
+    }
+    fn __reduce75<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionMulExpression = ConditionUnaryExpression => ActionFn(74);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action74::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 32)
+    }
+    fn __reduce76<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPostfixExpression = ConditionPostfixExpression, ".", identifier, "(", Comma<ConditionExpression>, ")" => ActionFn(222);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant19(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action222::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (6, 33)
+    }
+    fn __reduce77<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPostfixExpression = ConditionPrimaryExpression => ActionFn(79);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action79::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 33)
+    }
+    fn __reduce78<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = identifier => ActionFn(223);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action223::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce79<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = "true" => ActionFn(224);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action224::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce80<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = "false" => ActionFn(225);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action225::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce81<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = string => ActionFn(226);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action226::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce82<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = int => ActionFn(227);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action227::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce83<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = double => ActionFn(228);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action228::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 34)
+    }
+    fn __reduce84<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = CastType, "(", ConditionExpression, ")" => ActionFn(229);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action229::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (4, 34)
+    }
+    fn __reduce85<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionPrimaryExpression = "(", ConditionExpression, ")" => ActionFn(87);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action87::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 34)
+    }
+    fn __reduce86<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "int" => ActionFn(44);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action44::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce87<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "uint" => ActionFn(45);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action45::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce88<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "double" => ActionFn(46);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action46::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce89<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "bool" => ActionFn(47);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action47::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce90<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "bytes" => ActionFn(48);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action48::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce91<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "string" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action49::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce92<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "duration" => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce93<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "timestamp" => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action51::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce94<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "any" => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action52::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce95<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "list", "<", ConditionType, ">" => ActionFn(53);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action53::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 35)
+    }
+    fn __reduce96<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "map", "<", ConditionType, ">" => ActionFn(54);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action54::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (4, 35)
+    }
+    fn __reduce97<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionType = "ipaddress" => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action55::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 35)
+    }
+    fn __reduce98<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionUnaryExpression = "!", ConditionUnaryExpression => ActionFn(230);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action230::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 36)
+    }
+    fn __reduce99<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionUnaryExpression = "-", ConditionUnaryExpression => ActionFn(231);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action231::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 36)
+    }
+    fn __reduce100<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ConditionUnaryExpression = ConditionPostfixExpression => ActionFn(77);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action77::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 36)
+    }
+    fn __reduce101<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Extends =  => ActionFn(13);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action13::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (0, 37)
+    }
+    fn __reduce102<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Extends = "extends", identifier => ActionFn(154);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action154::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (2, 37)
+    }
+    fn __reduce103<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Extends = "extends", identifier, ("," identifier)+ => ActionFn(155);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action155::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (3, 37)
+    }
+    fn __reduce104<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermission = Relation => ActionFn(20);
+        let __sym0 = __pop_Variant31(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action20::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 38)
+    }
+    fn __reduce105<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermission = Permission => ActionFn(21);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action21::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 38)
+    }
+    fn __reduce106<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermission = error => ActionFn(22);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action22::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 38)
+    }
+    fn __reduce107<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = "\n", IRelationOrPermission, "\n" => ActionFn(158);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action158::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 39)
+    }
+    fn __reduce108<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = "\n", IRelationOrPermission, ("\n" IRelationOrPermission)+, "\n" => ActionFn(159);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action159::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (4, 39)
+    }
+    fn __reduce109<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = "\n", IRelationOrPermission => ActionFn(160);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action160::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 39)
+    }
+    fn __reduce110<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = "\n", IRelationOrPermission, ("\n" IRelationOrPermission)+ => ActionFn(161);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant26(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action161::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 39)
+    }
+    fn __reduce111<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = IRelationOrPermission, "\n" => ActionFn(162);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action162::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 39)
+    }
+    fn __reduce112<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = IRelationOrPermission, ("\n" IRelationOrPermission)+, "\n" => ActionFn(163);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action163::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 39)
+    }
+    fn __reduce113<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = IRelationOrPermission => ActionFn(164);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action164::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 39)
+    }
+    fn __reduce114<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IRelationOrPermissions = IRelationOrPermission, ("\n" IRelationOrPermission)+ => ActionFn(165);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action165::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 39)
+    }
+    fn __reduce115<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Mixin = "mixin", identifier, Extends, TypeBody => ActionFn(232);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action232::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (4, 40)
+    }
+    fn __reduce116<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Permission = "permission", identifier, ":", RelationshipExpr => ActionFn(233);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant32(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action233::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (4, 41)
+    }
+    fn __reduce117<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RRelationshipSet = identifier => ActionFn(234);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action234::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 42)
+    }
+    fn __reduce118<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RRelationshipSet = identifier, "#", identifier => ActionFn(235);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action235::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 42)
+    }
+    fn __reduce119<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RRelationshipSet = identifier, "#", "*" => ActionFn(236);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action236::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 42)
+    }
+    fn __reduce120<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Relation = "relation", identifier, ":", RelationshipSets => ActionFn(237);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant33(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action237::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (4, 43)
+    }
+    fn __reduce121<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationSubject = RRelationshipSet => ActionFn(28);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action28::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 44)
+    }
+    fn __reduce122<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationSubject = RRelationshipSet, "with", identifier => ActionFn(238);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action238::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 44)
+    }
+    fn __reduce123<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationSubject = RRelationshipSet, "^", identifier => ActionFn(239);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action239::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (3, 44)
+    }
+    fn __reduce124<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipExpr = RelationshipExpr, "+", RelationshipFactor => ActionFn(32);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant32(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action32::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 45)
+    }
+    fn __reduce125<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipExpr = RelationshipExpr, "-", RelationshipFactor => ActionFn(33);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant32(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action33::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 45)
+    }
+    fn __reduce126<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipExpr = RelationshipFactor => ActionFn(34);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action34::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 45)
+    }
+    fn __reduce127<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipFactor = RelationshipFactor, "&", RelationshipSetTerm => ActionFn(35);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant32(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action35::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 46)
+    }
+    fn __reduce128<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipFactor = RelationshipSetTerm => ActionFn(36);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action36::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 46)
+    }
+    fn __reduce129<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier => ActionFn(240);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action240::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 47)
+    }
+    fn __reduce130<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier, "->", identifier => ActionFn(241);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action241::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce131<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier, "#", identifier => ActionFn(242);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action242::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce132<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = identifier, "#", "*" => ActionFn(243);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action243::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce133<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSetTerm = "(", RelationshipExpr, ")" => ActionFn(41);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant32(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action41::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (3, 47)
+    }
+    fn __reduce134<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSets = RelationSubject => ActionFn(168);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action168::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 48)
+    }
+    fn __reduce135<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelationshipSets = RelationSubject, ("|" RelationSubject)+ => ActionFn(169);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action169::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (2, 48)
+    }
+    fn __reduce136<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Schema =  => ActionFn(252);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action252::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (0, 49)
+    }
+    fn __reduce137<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Schema = SchemaUnit+ => ActionFn(253);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action253::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (1, 49)
+    }
+    fn __reduce138<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = Type => ActionFn(3);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action3::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce139<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = Mixin => ActionFn(4);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action4::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce140<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = Condition => ActionFn(5);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action5::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce141<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = "import", string => ActionFn(244);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action244::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (2, 50)
+    }
+    fn __reduce142<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = "superadmin", identifier, "#", identifier => ActionFn(245);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action245::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (4, 50)
+    }
+    fn __reduce143<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = SchemaUnit, "\n" => ActionFn(8);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action8::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (2, 50)
+    }
+    fn __reduce144<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit = error => ActionFn(9);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action9::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce145<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit* =  => ActionFn(115);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action115::<>(input, errors, warnings, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 51)
+    }
+    fn __reduce146<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit* = SchemaUnit+ => ActionFn(116);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action116::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 51)
+    }
+    fn __reduce147<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit+ = SchemaUnit => ActionFn(117);
+        let __sym0 = __pop_Variant35(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action117::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 52)
+    }
+    fn __reduce148<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SchemaUnit+ = SchemaUnit+, SchemaUnit => ActionFn(118);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant35(__symbols);
+        let __sym0 = __pop_Variant36(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action118::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (2, 52)
+    }
+    fn __reduce149<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "type", identifier, Extends, TypeBody => ActionFn(246);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant25(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action246::<>(input, errors, warnings, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (4, 53)
+    }
+    fn __reduce150<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "type", identifier => ActionFn(247);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action247::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 53)
+    }
+    fn __reduce151<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // TypeBody = "{", IRelationOrPermissions, "}" => ActionFn(15);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action15::<>(input, errors, warnings, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 54)
+    }
+    fn __reduce152<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // TypeBody = "{", "}" => ActionFn(16);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action16::<>(input, errors, warnings, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (2, 54)
+    }
+    fn __reduce153<
+        'input,
+        'err,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+        warnings: &'err mut Vec<Diagnostic>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // __ConditionBody = ConditionBody => ActionFn(1);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action1::<>(input, errors, warnings, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 55)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__Schema::SchemaParser;
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action0<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Schema, usize),
+) -> Schema
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action1<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action2<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, alloc::vec::Vec<SchemaUnit>, usize),
+) -> Schema
+{
+    Schema::new(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action3<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Type, usize),
+) -> SchemaUnit
+{
+    SchemaUnit::Type(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action4<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Type, usize),
+) -> SchemaUnit
+{
+    SchemaUnit::Mixin(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action5<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Condition, usize),
+) -> SchemaUnit
+{
+    SchemaUnit::Condition(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action6<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, path, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SchemaUnit
+{
+    SchemaUnit::Import(Import {
+    path: unescape(path),
+    loc: (l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action7<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, t, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, rel, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SchemaUnit
+{
+    SchemaUnit::Superadmin(Superadmin {
+    r#type: t.to_string(),
+    relation: rel.to_string(),
+    loc: (l, r),
+  })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action8<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, su, _): (usize, SchemaUnit, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> SchemaUnit
+{
+    su
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action9<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, e, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexicalError>, usize),
+) -> SchemaUnit
+{
+    {
+    errors.push(e);
+    SchemaUnit::Error
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action10<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Vec<String>, usize),
+    (_, rops, _): (usize, Vec<RelationOrPermission>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Type
+{
+    Type::new(n.to_string(), rops, (l, r)).with_extends(e)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action11<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Type
+{
+    Type::new(n.to_string(), vec![], (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action12<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Vec<String>, usize),
+    (_, rops, _): (usize, Vec<RelationOrPermission>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Type
+{
+    Type::new(n.to_string(), rops, (l, r)).with_extends(e)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action13<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action14<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, s1, _): (usize, &'input str, usize),
+    (_, s2, _): (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize),
+) -> Vec<String>
+{
+    {
+    let mut names = vec![s1.to_string()];
+    names.extend(s2.into_iter().map(|e| e.1.to_string()));
+    names
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action15<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Vec<RelationOrPermission>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action16<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+    (_, __1, _): (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action17<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, name, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, args, _): (usize, Vec<ConditionArg>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, body, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Condition
+{
+    {
+    Condition {
+      name: name.to_string(),
+      args,
+      body,
+      loc: (l, r),
+    }
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action18<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, alloc::vec::Vec<Token<'input>>, usize),
+    (_, __0, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, alloc::vec::Vec<Token<'input>>, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action19<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, s1, _): (usize, RelationOrPermission, usize),
     (_, s2, _): (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
     (_, _, _): (usize, core::option::Option<Token<'input>>, usize),
-) -> Vec<RelationOrPermission>
+) -> Vec<RelationOrPermission>
+{
+    {
+    let mut rsss = vec![s1];
+    rsss.extend(s2.into_iter().map(|e| e.1));
+    rsss
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action20<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Relation, usize),
+) -> RelationOrPermission
+{
+    RelationOrPermission::Relation(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action21<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Permission, usize),
+) -> RelationOrPermission
+{
+    RelationOrPermission::Permission(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action22<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, e, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token<'input>, LexicalError>, usize),
+) -> RelationOrPermission
+{
+    {
+    errors.push(e);
+    RelationOrPermission::Error
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action23<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, subjects, _): (usize, Vec<RelationshipSet>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Relation
+{
+    Relation {
+    name: n.to_string(),
+    subjects,
+    description: None,
+    loc: (l, r),
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action24<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, s1, _): (usize, RelationshipSet, usize),
+    (_, s2, _): (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
+) -> Vec<RelationshipSet>
+{
+    {
+    let mut rsss = vec![s1];
+    rsss.extend(s2.into_iter().map(|e| e.1));
+    rsss
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action25<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> RelationshipSet
+{
+    RelationshipSet::new(SetKind::Single(n.to_string()), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action26<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, c, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> RelationshipSet
+{
+    RelationshipSet::new(SetKind::Set(n.to_string(), c.to_string()), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action27<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> RelationshipSet
+{
+    RelationshipSet::new(SetKind::Set(n.to_string(), "*".to_string()), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action28<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, RelationshipSet, usize),
+) -> RelationshipSet
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action29<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, RelationshipSet, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, c, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> RelationshipSet
+{
+    RelationshipSet::new(SetKind::Conditional(Box::new(s), c.to_string()), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action30<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, RelationshipSet, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, c, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> RelationshipSet
+{
+    RelationshipSet::new(SetKind::Conditional(Box::new(s), c.to_string()), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action31<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, expr, _): (usize, Relationship, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Permission
+{
+    Permission {
+    name: n.to_string(),
+    permission: expr.compute(),
+    description: None,
+    loc: (l, r),
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action32<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, Relationship, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, Relationship, usize),
+) -> Relationship
+{
+    Relationship::Union {
+    children: vec![Box::new(l), Box::new(r)]
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action33<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, Relationship, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, Relationship, usize),
+) -> Relationship
+{
+    Relationship::Difference {
+    base: Box::new(l),
+    subtract: Box::new(r)
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action34<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Relationship, usize),
+) -> Relationship
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action35<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, Relationship, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, Relationship, usize),
+) -> Relationship
+{
+    Relationship::Intersection {
+    children: vec![Box::new(l), Box::new(r)]
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action36<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Relationship, usize),
+) -> Relationship
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action37<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Relationship
+{
+    Relationship::Set(RelationshipSet::new(SetKind::Single(n.to_string()), (l, r)))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action38<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, c, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Relationship
+{
+    Relationship::Set(RelationshipSet::new(SetKind::Set(n.to_string(), c.to_string()), (l, r)))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action39<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, c, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Relationship
+{
+    {
+    warnings.push(Diagnostic::warning(
+      Some((l, r)),
+      format!("`{}#{}` in a permission is deprecated, use `{}->{}` for a tuple to userset", n, c, n, c),
+    ));
+    Relationship::Set(RelationshipSet::new(SetKind::Set(n.to_string(), c.to_string()), (l, r)))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action40<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Relationship
+{
+    Relationship::Set(RelationshipSet::new(SetKind::Set(n.to_string(), "*".to_string()), (l, r)))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action41<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, Relationship, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> Relationship
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action42<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, s1, _): (usize, ConditionArg, usize),
+    (_, s2, _): (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
+) -> Vec<ConditionArg>
+{
+    {
+    let mut rsss = vec![s1];
+    rsss.extend(s2.into_iter().map(|e| e.1));
+    rsss
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action43<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, name, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, t, _): (usize, ConditionType, usize),
+) -> ConditionArg
+{
+    ConditionArg {
+    name: name.to_string(),
+    r#type: t,
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action44<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action45<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Uint
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action46<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Double
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action47<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Bool
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action48<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Bytes
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action49<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::String
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action50<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Duration
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action51<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Timestamp
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action52<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Any
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action53<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, t, _): (usize, ConditionType, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::List(Box::new(t))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action54<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, t, _): (usize, ConditionType, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Map(Box::new(t))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action55<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::IPaddress
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action56<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::Or, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action57<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action58<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, _, _): (usize, core::option::Option<Token<'input>>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::And, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action59<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action60<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, op, _): (usize, BinaryOperator, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(op, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action61<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action62<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Eq
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action63<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Ne
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action64<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Lt
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action65<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Le
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action66<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Gt
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action67<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> BinaryOperator
+{
+    BinaryOperator::Ge
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action68<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::Add, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action69<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::Sub, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action70<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action71<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::Mul, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action72<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::Div, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action73<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, left, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, right, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::binary(BinaryOperator::Rem, left, right, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action74<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action75<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, operand, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::unary(UnaryOperator::Not, operand, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action76<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, operand, _): (usize, ConditionExpression, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::unary(UnaryOperator::Neg, operand, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action77<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action78<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, receiver, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, method, _): (usize, &'input str, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, args, _): (usize, Vec<ConditionExpression>, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::new(ExpressionKind::MethodCall {
+      receiver: Box::new(receiver),
+      method: method.to_string(),
+      args,
+    }, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action79<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action80<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    ConditionExpression::new(ExpressionKind::Identifier(n.to_string()), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action81<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    ConditionExpression::new(ExpressionKind::Literal(Literal::Bool(true)), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action82<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    ConditionExpression::new(ExpressionKind::Literal(Literal::Bool(false)), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action83<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    ConditionExpression::new(ExpressionKind::Literal(Literal::String(unescape(s))), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action84<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, i, _): (usize, i64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    ConditionExpression::new(ExpressionKind::Literal(Literal::Int(i)), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action85<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, d, _): (usize, f64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    ConditionExpression::new(ExpressionKind::Literal(Literal::Double(d)), (l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action86<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, l, _): (usize, usize, usize),
+    (_, t, _): (usize, ConditionType, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, expr, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> ConditionExpression
+{
+    {
+    ConditionExpression::new(ExpressionKind::Cast { r#type: t, expr: Box::new(expr) }, (l, r))
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action87<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, _, _): (usize, Token<'input>, usize),
+    (_, __0, _): (usize, ConditionExpression, usize),
+    (_, _, _): (usize, Token<'input>, usize),
+) -> ConditionExpression
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action88<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action89<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Uint
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action90<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Double
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action91<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Bool
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action92<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::String
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action93<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Duration
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action94<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::Timestamp
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action95<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> ConditionType
+{
+    ConditionType::IPaddress
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action96<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, mut v, _): (usize, alloc::vec::Vec<ConditionExpression>, usize),
+    (_, e, _): (usize, core::option::Option<ConditionExpression>, usize),
+) -> Vec<ConditionExpression>
+{
+    match e {
+    None => v,
+    Some(e) => {
+      v.push(e);
+      v
+    }
+  }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action97<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action98<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
+) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action99<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+    (_, __1, _): (usize, ConditionArg, usize),
+) -> (Token<'input>, ConditionArg)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action100<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action101<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action102<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+    (_, __1, _): (usize, RelationshipSet, usize),
+) -> (Token<'input>, RelationshipSet)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action103<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action104<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action105<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+    (_, __1, _): (usize, RelationOrPermission, usize),
+) -> (Token<'input>, RelationOrPermission)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action106<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> core::option::Option<Token<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action107<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Token<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action108<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Token<'input>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action109<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<Token<'input>>, usize),
+) -> alloc::vec::Vec<Token<'input>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action110<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(Token<'input>, &'input str)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action111<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize),
+) -> alloc::vec::Vec<(Token<'input>, &'input str)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action112<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+    (_, __1, _): (usize, &'input str, usize),
+) -> (Token<'input>, &'input str)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    *__lookbehind
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    *__lookahead
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action115<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<SchemaUnit>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action116<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<SchemaUnit>, usize),
+) -> alloc::vec::Vec<SchemaUnit>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action117<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, SchemaUnit, usize),
+) -> alloc::vec::Vec<SchemaUnit>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action118<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<SchemaUnit>, usize),
+    (_, e, _): (usize, SchemaUnit, usize),
+) -> alloc::vec::Vec<SchemaUnit>
 {
-    {
-    let mut rsss = vec![s1];
-    rsss.extend(s2.into_iter().map(|e| e.1));
-    rsss
-  }
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action10<
+fn __action119<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Relation, usize),
-) -> RelationOrPermission
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, (Token<'input>, &'input str), usize),
+) -> alloc::vec::Vec<(Token<'input>, &'input str)>
 {
-    RelationOrPermission::Relation(__0)
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action11<
+fn __action120<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Permission, usize),
-) -> RelationOrPermission
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize),
+    (_, e, _): (usize, (Token<'input>, &'input str), usize),
+) -> alloc::vec::Vec<(Token<'input>, &'input str)>
 {
-    RelationOrPermission::Permission(__0)
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action12<
+fn __action121<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, n, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, rsss, _): (usize, Vec<RelationshipSet>, usize),
-) -> Relation
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Token<'input>>
 {
-    Relation {
-    name: n.to_string(),
-    subjects: rsss,
-  }
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action13<
+fn __action122<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, s1, _): (usize, RelationshipSet, usize),
-    (_, s2, _): (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
-) -> Vec<RelationshipSet>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<Token<'input>>, usize),
+    (_, e, _): (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<Token<'input>>
 {
-    {
-    let mut rsss = vec![s1];
-    rsss.extend(s2.into_iter().map(|e| e.1));
-    rsss
-  }
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action14<
+fn __action123<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, n, _): (usize, &'input str, usize),
-) -> RelationshipSet
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, (Token<'input>, RelationOrPermission), usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
 {
-    RelationshipSet::Single(n.to_string())
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action15<
+fn __action124<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, n, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, &'input str, usize),
-) -> RelationshipSet
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+    (_, e, _): (usize, (Token<'input>, RelationOrPermission), usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
 {
-    RelationshipSet::Set(n.to_string(), r.to_string())
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action16<
+fn __action125<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, n, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, expr, _): (usize, Relationship, usize),
-) -> Permission
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, (Token<'input>, RelationshipSet), usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
 {
-    Permission {
-    name: n.to_string(),
-    permission: expr.compute(),
-  }
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action17<
+fn __action126<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, l, _): (usize, Relationship, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, Relationship, usize),
-) -> Relationship
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
+    (_, e, _): (usize, (Token<'input>, RelationshipSet), usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
 {
-    Relationship::Union {
-    children: vec![Box::new(l), Box::new(r)]
-  }
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action18<
+fn __action127<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, l, _): (usize, Relationship, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, Relationship, usize),
-) -> Relationship
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, (Token<'input>, ConditionArg), usize),
+) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
 {
-    Relationship::Difference {
-    base: Box::new(l),
-    subtract: Box::new(r)
-  }
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action19<
+fn __action128<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Relationship, usize),
-) -> Relationship
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
+    (_, e, _): (usize, (Token<'input>, ConditionArg), usize),
+) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
 {
-    __0
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action20<
+fn __action129<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, l, _): (usize, Relationship, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, r, _): (usize, Relationship, usize),
-) -> Relationship
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> core::option::Option<ConditionExpression>
 {
-    Relationship::Intersection {
-    children: vec![Box::new(l), Box::new(r)]
-  }
+    Some(__0)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action21<
+fn __action130<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Relationship, usize),
-) -> Relationship
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<ConditionExpression>
 {
-    __0
+    None
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action22<
+fn __action131<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, RelationshipSet, usize),
-) -> Relationship
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<ConditionExpression>
 {
-    Relationship::Set(__0)
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action23<
+fn __action132<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<ConditionExpression>, usize),
+) -> alloc::vec::Vec<ConditionExpression>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action133<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
     (_, _, _): (usize, Token<'input>, usize),
-    (_, __0, _): (usize, Relationship, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-) -> Relationship
+) -> ConditionExpression
 {
     __0
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action24<
+fn __action134<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, s1, _): (usize, ConditionArg, usize),
-    (_, s2, _): (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
-) -> Vec<ConditionArg>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, __0, _): (usize, ConditionExpression, usize),
+) -> alloc::vec::Vec<ConditionExpression>
 {
-    {
-    let mut rsss = vec![s1];
-    rsss.extend(s2.into_iter().map(|e| e.1));
-    rsss
-  }
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action135<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    (_, v, _): (usize, alloc::vec::Vec<ConditionExpression>, usize),
+    (_, e, _): (usize, ConditionExpression, usize),
+) -> alloc::vec::Vec<ConditionExpression>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action136<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __start1 = __0.2;
+    let __end1 = __0.2;
+    let __temp0 = __action108(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action108(
+        input,
+        errors,
+        warnings,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action18(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action137<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, alloc::vec::Vec<Token<'input>>, usize),
+) -> ConditionExpression
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __start1 = __1.0;
+    let __end1 = __1.2;
+    let __temp0 = __action108(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action109(
+        input,
+        errors,
+        warnings,
+        __1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action18(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action138<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<Token<'input>>, usize),
+    __1: (usize, ConditionExpression, usize),
+) -> ConditionExpression
+{
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __start1 = __1.2;
+    let __end1 = __1.2;
+    let __temp0 = __action109(
+        input,
+        errors,
+        warnings,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action108(
+        input,
+        errors,
+        warnings,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action18(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __1,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action25<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action139<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, name, _): (usize, &'input str, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, t, _): (usize, ConditionType, usize),
-) -> ConditionArg
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<Token<'input>>, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, alloc::vec::Vec<Token<'input>>, usize),
+) -> ConditionExpression
 {
-    ConditionArg {
-    name: name.to_string(),
-    r#type: t,
-  }
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __start1 = __2.0;
+    let __end1 = __2.2;
+    let __temp0 = __action109(
+        input,
+        errors,
+        warnings,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action109(
+        input,
+        errors,
+        warnings,
+        __2,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action18(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __1,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action26<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action140<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, usize, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, ConditionExpression, usize),
+    __5: (usize, usize, usize),
+) -> ConditionExpression
 {
-    ConditionType::Int
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action106(
+        input,
+        errors,
+        warnings,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action27<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action141<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, usize, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, ConditionExpression, usize),
+    __4: (usize, usize, usize),
+) -> ConditionExpression
 {
-    ConditionType::Uint
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action107(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action28<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action142<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, usize, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, ConditionExpression, usize),
+    __5: (usize, usize, usize),
+) -> ConditionExpression
 {
-    ConditionType::Double
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action106(
+        input,
+        errors,
+        warnings,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action29<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action143<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, usize, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, ConditionExpression, usize),
+    __4: (usize, usize, usize),
+) -> ConditionExpression
 {
-    ConditionType::Bool
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action107(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action30<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action144<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionType::Bytes
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __start1 = __3.0;
+    let __end1 = __3.2;
+    let __temp0 = __action106(
+        input,
+        errors,
+        warnings,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action106(
+        input,
+        errors,
+        warnings,
+        __3,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __1,
+        __2,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action31<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action145<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionType::String
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __start1 = __2.2;
+    let __end1 = __2.2;
+    let __temp0 = __action106(
+        input,
+        errors,
+        warnings,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action107(
+        input,
+        errors,
+        warnings,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __1,
+        __2,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action32<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action146<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationOrPermission, usize),
+    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionType::Duration
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __start1 = __2.0;
+    let __end1 = __2.2;
+    let __temp0 = __action107(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action106(
+        input,
+        errors,
+        warnings,
+        __2,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action33<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action147<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationOrPermission, usize),
+    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionType::Timestamp
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __start1 = __1.2;
+    let __end1 = __1.2;
+    let __temp0 = __action107(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action107(
+        input,
+        errors,
+        warnings,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action19(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __temp1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action34<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action148<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, ConditionArg, usize),
+) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
 {
-    ConditionType::Any
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action99(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action127(
+        input,
+        errors,
+        warnings,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action35<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action149<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, t, _): (usize, ConditionType, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionArg, usize),
+) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
 {
-    ConditionType::List(Box::new(t))
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action99(
+        input,
+        errors,
+        warnings,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action128(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action36<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action150<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-    (_, t, _): (usize, ConditionType, usize),
-    (_, _, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionArg, usize),
+) -> Vec<ConditionArg>
 {
-    ConditionType::Map(Box::new(t))
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action97(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action42(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action37<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action151<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionType
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionArg, usize),
+    __1: (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
+) -> Vec<ConditionArg>
 {
-    ConditionType::IPaddress
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action98(
+        input,
+        errors,
+        warnings,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action42(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action38<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action152<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<(Token<'input>, &'input str)>
 {
-    ConditionExpression::Identifier(__0.to_string())
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action112(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action119(
+        input,
+        errors,
+        warnings,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action39<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action153<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<(Token<'input>, &'input str)>
 {
-    ConditionExpression::Colon
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action112(
+        input,
+        errors,
+        warnings,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action120(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action40<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action154<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+) -> Vec<String>
 {
-    ConditionExpression::LBracket
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action110(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action41<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action155<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, alloc::vec::Vec<(Token<'input>, &'input str)>, usize),
+) -> Vec<String>
 {
-    ConditionExpression::RBracket
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action111(
+        input,
+        errors,
+        warnings,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action42<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action156<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
 {
-    ConditionExpression::Caret
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action105(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action123(
+        input,
+        errors,
+        warnings,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action43<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action157<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, RelationOrPermission, usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
 {
-    ConditionExpression::And
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action105(
+        input,
+        errors,
+        warnings,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action124(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action44<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action158<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+    __2: (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Or
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action103(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action144(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action45<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action159<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+    __3: (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Add
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action104(
+        input,
+        errors,
+        warnings,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action144(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action46<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action160<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Sub
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action103(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action145(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action47<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action161<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationOrPermission, usize),
+    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::YulArrow
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action104(
+        input,
+        errors,
+        warnings,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action145(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action48<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action162<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationOrPermission, usize),
+    __1: (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Sharp
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action103(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action146(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action49<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action163<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationOrPermission, usize),
+    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Newline
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action104(
+        input,
+        errors,
+        warnings,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action146(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action50<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action164<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationOrPermission, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Star
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action103(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action147(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action51<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action165<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationOrPermission, usize),
+    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
+) -> Vec<RelationOrPermission>
 {
-    ConditionExpression::Slash
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action104(
+        input,
+        errors,
+        warnings,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action147(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action52<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action166<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, RelationshipSet, usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
 {
-    ConditionExpression::Percent
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action102(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action125(
+        input,
+        errors,
+        warnings,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action53<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action167<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, RelationshipSet, usize),
+) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
 {
-    ConditionExpression::Comma
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action102(
+        input,
+        errors,
+        warnings,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action54<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action168<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationshipSet, usize),
+) -> Vec<RelationshipSet>
 {
-    ConditionExpression::Semicolon
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action100(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action55<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action169<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationshipSet, usize),
+    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
+) -> Vec<RelationshipSet>
 {
-    ConditionExpression::Eq
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action101(
+        input,
+        errors,
+        warnings,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action56<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action170<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<ConditionExpression>
 {
-    ConditionExpression::ExclamationMark
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action133(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        input,
+        errors,
+        warnings,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action57<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action171<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<ConditionExpression>, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, Token<'input>, usize),
+) -> alloc::vec::Vec<ConditionExpression>
 {
-    ConditionExpression::Gt
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action133(
+        input,
+        errors,
+        warnings,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action135(
+        input,
+        errors,
+        warnings,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action58<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action172<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, core::option::Option<ConditionExpression>, usize),
+) -> Vec<ConditionExpression>
 {
-    ConditionExpression::Lt
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action131(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action96(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action59<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action173<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<ConditionExpression>, usize),
+    __1: (usize, core::option::Option<ConditionExpression>, usize),
+) -> Vec<ConditionExpression>
 {
-    ConditionExpression::Int
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action132(
+        input,
+        errors,
+        warnings,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action96(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action60<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action174<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> ConditionExpression
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<ConditionArg>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, ConditionExpression, usize),
+    __7: (usize, Token<'input>, usize),
+    __8: (usize, usize, usize),
+) -> Condition
 {
-    ConditionExpression::Uint
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action61<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action175<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Double
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action62<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action176<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Bool
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action63<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action177<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, ConditionExpression, usize),
+    __4: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Bytes
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action64<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action178<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::String
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action65<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action179<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, BinaryOperator, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Duration
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action66<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action180<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, ConditionExpression, usize),
+    __4: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Timestamp
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action142(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action67<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action181<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Any
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action143(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action68<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action182<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::List
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action71(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action69<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action183<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Map
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action70<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action184<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::IPaddress
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action73(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action71<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action185<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Vec<ConditionExpression>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::StringLiteral(__0.to_string())
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action78(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action72<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action186<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, i64, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::IntLiteral(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action80(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action73<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action187<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, f64, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::DoubleLiteral(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action81(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action74<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action188<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Dollar
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action82(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action75<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action189<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::GraveAccent
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action83(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action76<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action190<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::WhiteSpace
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action84(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action77<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action191<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, f64, usize),
+    __1: (usize, usize, usize),
 ) -> ConditionExpression
 {
-    ConditionExpression::Point
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action85(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action78<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action192<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionType, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, usize, usize),
+) -> ConditionExpression
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action86(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action79<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action193<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
-) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, usize, usize),
+) -> ConditionExpression
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action75(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action80<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action194<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-    (_, __1, _): (usize, ConditionArg, usize),
-) -> (Token<'input>, ConditionArg)
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, ConditionExpression, usize),
+    __2: (usize, usize, usize),
+) -> ConditionExpression
 {
-    (__0, __1)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action81<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action195<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Vec<String>, usize),
+    __3: (usize, Vec<RelationOrPermission>, usize),
+    __4: (usize, usize, usize),
+) -> Type
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action82<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action196<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Relationship, usize),
+    __4: (usize, usize, usize),
+) -> Permission
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action31(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action83<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action197<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-    (_, __1, _): (usize, RelationshipSet, usize),
-) -> (Token<'input>, RelationshipSet)
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> RelationshipSet
 {
-    (__0, __1)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action84<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action198<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> RelationshipSet
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action26(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action85<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action199<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, usize, usize),
+) -> RelationshipSet
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action86<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action200<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-    (_, __1, _): (usize, RelationOrPermission, usize),
-) -> (Token<'input>, RelationOrPermission)
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<RelationshipSet>, usize),
+    __4: (usize, usize, usize),
+) -> Relation
 {
-    (__0, __1)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action87<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action201<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Token<'input>, usize),
-) -> core::option::Option<Token<'input>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationshipSet, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> RelationshipSet
 {
-    Some(__0)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action29(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action88<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action202<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Token<'input>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationshipSet, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> RelationshipSet
 {
-    None
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action30(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action89<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action203<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<ConditionExpression>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Relationship
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action37(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action90<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action204<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<ConditionExpression>, usize),
-) -> alloc::vec::Vec<ConditionExpression>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Relationship
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action38(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action91<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action205<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Relationship
 {
-    *__lookbehind
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action92<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action206<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, usize, usize),
+) -> Relationship
 {
-    *__lookahead
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action40(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action93<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action207<
     'input,
+    'err,
 >(
     input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<SchemaUnit>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> SchemaUnit
 {
-    alloc::vec![]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action94<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action208<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<SchemaUnit>, usize),
-) -> alloc::vec::Vec<SchemaUnit>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> SchemaUnit
 {
-    v
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action95<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action209<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, SchemaUnit, usize),
-) -> alloc::vec::Vec<SchemaUnit>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Vec<String>, usize),
+    __3: (usize, Vec<RelationOrPermission>, usize),
+    __4: (usize, usize, usize),
+) -> Type
 {
-    alloc::vec![__0]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action96<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action210<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<SchemaUnit>, usize),
-    (_, e, _): (usize, SchemaUnit, usize),
-) -> alloc::vec::Vec<SchemaUnit>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Type
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action114(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        errors,
+        warnings,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action97<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action211<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, ConditionExpression, usize),
-) -> alloc::vec::Vec<ConditionExpression>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<ConditionArg>, usize),
+    __4: (usize, Token<'input>, usize),
+    __5: (usize, Token<'input>, usize),
+    __6: (usize, ConditionExpression, usize),
+    __7: (usize, Token<'input>, usize),
+) -> Condition
 {
-    alloc::vec![__0]
+    let __start0 = __7.2;
+    let __end0 = __7.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action98<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action212<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<ConditionExpression>, usize),
-    (_, e, _): (usize, ConditionExpression, usize),
-) -> alloc::vec::Vec<ConditionExpression>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action175(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action99<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action213<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, (Token<'input>, RelationOrPermission), usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    alloc::vec![__0]
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action176(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action100<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action214<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-    (_, e, _): (usize, (Token<'input>, RelationOrPermission), usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action101<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action215<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, (Token<'input>, RelationshipSet), usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    alloc::vec![__0]
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action178(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action102<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action216<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
-    (_, e, _): (usize, (Token<'input>, RelationshipSet), usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, BinaryOperator, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action179(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action103<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action217<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, __0, _): (usize, (Token<'input>, ConditionArg), usize),
-) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    alloc::vec![__0]
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action104<
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action218<
     'input,
+    'err,
 >(
     input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
-    (_, e, _): (usize, (Token<'input>, ConditionArg), usize),
-) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
+        input,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action181(
+        input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action105<
+fn __action219<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-    __3: (usize, Token<'input>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    let __start0 = __0.0;
-    let __end0 = __0.2;
-    let __start1 = __3.0;
-    let __end1 = __3.2;
-    let __temp0 = __action87(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
-        __0,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action87(
-        input,
-        __3,
-    );
-    let __temp1 = (__start1, __temp1, __end1);
-    __action9(
+    __action182(
         input,
-        __temp0,
+        errors,
+        warnings,
+        __0,
         __1,
         __2,
-        __temp1,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action106<
+fn __action220<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    let __start0 = __0.0;
-    let __end0 = __0.2;
-    let __start1 = __2.2;
-    let __end1 = __2.2;
-    let __temp0 = __action87(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
-        __0,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action88(
-        input,
-        &__start1,
-        &__end1,
-    );
-    let __temp1 = (__start1, __temp1, __end1);
-    __action9(
+    __action183(
         input,
-        __temp0,
+        errors,
+        warnings,
+        __0,
         __1,
         __2,
-        __temp1,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action107<
+fn __action221<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationOrPermission, usize),
-    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-    __2: (usize, Token<'input>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    let __start0 = __0.0;
-    let __end0 = __0.0;
-    let __start1 = __2.0;
-    let __end1 = __2.2;
-    let __temp0 = __action88(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action87(
-        input,
-        __2,
-    );
-    let __temp1 = (__start1, __temp1, __end1);
-    __action9(
+    __action184(
         input,
-        __temp0,
+        errors,
+        warnings,
         __0,
         __1,
-        __temp1,
+        __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action108<
+fn __action222<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationOrPermission, usize),
-    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Token<'input>, usize),
+    __4: (usize, Vec<ConditionExpression>, usize),
+    __5: (usize, Token<'input>, usize),
+) -> ConditionExpression
 {
-    let __start0 = __0.0;
-    let __end0 = __0.0;
-    let __start1 = __1.2;
-    let __end1 = __1.2;
-    let __temp0 = __action88(
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    let __temp1 = __action88(
-        input,
-        &__start1,
-        &__end1,
-    );
-    let __temp1 = (__start1, __temp1, __end1);
-    __action9(
+    __action185(
         input,
-        __temp0,
+        errors,
+        warnings,
         __0,
         __1,
-        __temp1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action109<
+fn __action223<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, ConditionArg, usize),
-) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+) -> ConditionExpression
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action80(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __0,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(
+    __action186(
         input,
+        errors,
+        warnings,
+        __0,
         __temp0,
     )
 }
@@ -5995,25 +17968,30 @@ fn __action109<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action110<
+fn __action224<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
-    __1: (usize, Token<'input>, usize),
-    __2: (usize, ConditionArg, usize),
-) -> alloc::vec::Vec<(Token<'input>, ConditionArg)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+) -> ConditionExpression
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action80(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __1,
-        __2,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action104(
+    __action187(
         input,
+        errors,
+        warnings,
         __0,
         __temp0,
     )
@@ -6022,23 +18000,30 @@ fn __action110<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action111<
+fn __action225<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, ConditionArg, usize),
-) -> Vec<ConditionArg>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+) -> ConditionExpression
 {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action78(
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action24(
+    __action188(
         input,
+        errors,
+        warnings,
         __0,
         __temp0,
     )
@@ -6047,23 +18032,30 @@ fn __action111<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action112<
+fn __action226<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, ConditionArg, usize),
-    __1: (usize, alloc::vec::Vec<(Token<'input>, ConditionArg)>, usize),
-) -> Vec<ConditionArg>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+) -> ConditionExpression
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action79(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action24(
+    __action189(
         input,
+        errors,
+        warnings,
         __0,
         __temp0,
     )
@@ -6072,24 +18064,31 @@ fn __action112<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action113<
+fn __action227<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, i64, usize),
+) -> ConditionExpression
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action86(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __0,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action99(
+    __action190(
         input,
+        errors,
+        warnings,
+        __0,
         __temp0,
     )
 }
@@ -6097,25 +18096,30 @@ fn __action113<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action114<
+fn __action228<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-    __1: (usize, Token<'input>, usize),
-    __2: (usize, RelationOrPermission, usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationOrPermission)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, f64, usize),
+) -> ConditionExpression
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action86(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __1,
-        __2,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action100(
+    __action191(
         input,
+        errors,
+        warnings,
         __0,
         __temp0,
     )
@@ -6124,82 +18128,103 @@ fn __action114<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action115<
+fn __action229<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-    __2: (usize, Token<'input>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionType, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, ConditionExpression, usize),
+    __3: (usize, Token<'input>, usize),
+) -> ConditionExpression
 {
-    let __start0 = __1.2;
-    let __end0 = __2.0;
-    let __temp0 = __action84(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action192(
         input,
+        errors,
+        warnings,
         __0,
         __1,
-        __temp0,
         __2,
+        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action116<
+fn __action230<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-    __3: (usize, Token<'input>, usize),
-) -> Vec<RelationOrPermission>
+    __1: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
-    let __start0 = __2.0;
-    let __end0 = __2.2;
-    let __temp0 = __action85(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action113(
         input,
-        __2,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(
+    __action193(
         input,
+        errors,
+        warnings,
         __0,
         __1,
         __temp0,
-        __3,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action117<
+fn __action231<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-) -> Vec<RelationOrPermission>
+    __1: (usize, ConditionExpression, usize),
+) -> ConditionExpression
 {
     let __start0 = __1.2;
     let __end0 = __1.2;
-    let __temp0 = __action84(
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action106(
+    __action194(
         input,
+        errors,
+        warnings,
         __0,
         __1,
         __temp0,
@@ -6209,26 +18234,37 @@ fn __action117<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action118<
+fn __action232<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationOrPermission, usize),
-    __2: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-) -> Vec<RelationOrPermission>
+    __1: (usize, &'input str, usize),
+    __2: (usize, Vec<String>, usize),
+    __3: (usize, Vec<RelationOrPermission>, usize),
+) -> Type
 {
-    let __start0 = __2.0;
-    let __end0 = __2.2;
-    let __temp0 = __action85(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
         input,
-        __2,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action106(
+    __action195(
         input,
+        errors,
+        warnings,
         __0,
         __1,
+        __2,
+        __3,
         __temp0,
     )
 }
@@ -6236,78 +18272,105 @@ fn __action118<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action119<
+fn __action233<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationOrPermission, usize),
-    __1: (usize, Token<'input>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, Token<'input>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Relationship, usize),
+) -> Permission
 {
-    let __start0 = __0.2;
-    let __end0 = __1.0;
-    let __temp0 = __action84(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action107(
+    __action196(
         input,
+        errors,
+        warnings,
         __0,
-        __temp0,
         __1,
+        __2,
+        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action120<
+fn __action234<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationOrPermission, usize),
-    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-    __2: (usize, Token<'input>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+) -> RelationshipSet
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action85(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action107(
+    __action197(
         input,
+        errors,
+        warnings,
         __0,
         __temp0,
-        __2,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action121<
+fn __action235<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationOrPermission, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> RelationshipSet
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action84(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action108(
+    __action198(
         input,
+        errors,
+        warnings,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -6315,24 +18378,35 @@ fn __action121<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action122<
+fn __action236<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationOrPermission, usize),
-    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationOrPermission)>, usize),
-) -> Vec<RelationOrPermission>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, Token<'input>, usize),
+) -> RelationshipSet
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action85(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action108(
+    __action199(
         input,
+        errors,
+        warnings,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -6340,24 +18414,37 @@ fn __action122<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action123<
+fn __action237<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
-    __1: (usize, RelationshipSet, usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+    __1: (usize, &'input str, usize),
+    __2: (usize, Token<'input>, usize),
+    __3: (usize, Vec<RelationshipSet>, usize),
+) -> Relation
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action83(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
         input,
-        __0,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action101(
+    __action200(
         input,
+        errors,
+        warnings,
+        __0,
+        __1,
+        __2,
+        __3,
         __temp0,
     )
 }
@@ -6365,26 +18452,35 @@ fn __action123<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action124<
+fn __action238<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, RelationshipSet, usize),
     __1: (usize, Token<'input>, usize),
-    __2: (usize, RelationshipSet, usize),
-) -> alloc::vec::Vec<(Token<'input>, RelationshipSet)>
+    __2: (usize, &'input str, usize),
+) -> RelationshipSet
 {
-    let __start0 = __1.0;
+    let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action83(
+    let __temp0 = __action113(
         input,
-        __1,
-        __2,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
+    __action201(
         input,
+        errors,
+        warnings,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -6392,24 +18488,35 @@ fn __action124<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action125<
+fn __action239<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, RelationshipSet, usize),
-) -> Vec<RelationshipSet>
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> RelationshipSet
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action81(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
+    __action202(
         input,
+        errors,
+        warnings,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
@@ -6417,23 +18524,30 @@ fn __action125<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action126<
+fn __action240<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, RelationshipSet, usize),
-    __1: (usize, alloc::vec::Vec<(Token<'input>, RelationshipSet)>, usize),
-) -> Vec<RelationshipSet>
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+) -> Relationship
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action82(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action113(
         input,
-        __1,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
+    __action203(
         input,
+        errors,
+        warnings,
         __0,
         __temp0,
     )
@@ -6442,176 +18556,179 @@ fn __action126<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action127<
+fn __action241<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Token<'input>, usize),
-    __3: (usize, Vec<ConditionArg>, usize),
-    __4: (usize, Token<'input>, usize),
-    __5: (usize, Token<'input>, usize),
-    __6: (usize, alloc::vec::Vec<ConditionExpression>, usize),
-    __7: (usize, Token<'input>, usize),
-    __8: (usize, usize, usize),
-) -> Condition
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> Relationship
 {
-    let __start0 = __0.0;
-    let __end0 = __0.0;
-    let __temp0 = __action92(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action8(
+    __action204(
         input,
-        __temp0,
+        errors,
+        warnings,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
-        __8,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action128<
+fn __action242<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Token<'input>, usize),
-    __3: (usize, Vec<RelationOrPermission>, usize),
-    __4: (usize, Token<'input>, usize),
-    __5: (usize, usize, usize),
-) -> Type
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
+    __2: (usize, &'input str, usize),
+) -> Relationship
 {
-    let __start0 = __0.0;
-    let __end0 = __0.0;
-    let __temp0 = __action92(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action5(
+    __action205(
         input,
-        __temp0,
+        errors,
+        warnings,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action129<
+fn __action243<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Token<'input>, usize),
     __2: (usize, Token<'input>, usize),
-    __3: (usize, Token<'input>, usize),
-    __4: (usize, usize, usize),
-) -> Type
+) -> Relationship
 {
-    let __start0 = __0.0;
-    let __end0 = __0.0;
-    let __temp0 = __action92(
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action206(
         input,
-        __temp0,
+        errors,
+        warnings,
         __0,
         __1,
         __2,
-        __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action130<
+fn __action244<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, usize, usize),
-) -> Type
+) -> SchemaUnit
 {
-    let __start0 = __0.0;
-    let __end0 = __0.0;
-    let __temp0 = __action92(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action7(
+    __action207(
         input,
-        __temp0,
+        errors,
+        warnings,
         __0,
         __1,
-        __2,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action131<
+fn __action245<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
     __2: (usize, Token<'input>, usize),
-    __3: (usize, Vec<ConditionArg>, usize),
-    __4: (usize, Token<'input>, usize),
-    __5: (usize, Token<'input>, usize),
-    __6: (usize, alloc::vec::Vec<ConditionExpression>, usize),
-    __7: (usize, Token<'input>, usize),
-) -> Condition
+    __3: (usize, &'input str, usize),
+) -> SchemaUnit
 {
-    let __start0 = __7.2;
-    let __end0 = __7.2;
-    let __temp0 = __action91(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action127(
+    __action208(
         input,
+        errors,
+        warnings,
         __0,
         __1,
         __2,
         __3,
-        __4,
-        __5,
-        __6,
-        __7,
         __temp0,
     )
 }
@@ -6619,32 +18736,37 @@ fn __action131<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action132<
+fn __action246<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, Token<'input>, usize),
+    __2: (usize, Vec<String>, usize),
     __3: (usize, Vec<RelationOrPermission>, usize),
-    __4: (usize, Token<'input>, usize),
 ) -> Type
 {
-    let __start0 = __4.2;
-    let __end0 = __4.2;
-    let __temp0 = __action91(
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action128(
+    __action209(
         input,
+        errors,
+        warnings,
         __0,
         __1,
         __2,
         __3,
-        __4,
         __temp0,
     )
 }
@@ -6652,30 +18774,33 @@ fn __action132<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action133<
+fn __action247<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, Token<'input>, usize),
     __1: (usize, &'input str, usize),
-    __2: (usize, Token<'input>, usize),
-    __3: (usize, Token<'input>, usize),
 ) -> Type
 {
-    let __start0 = __3.2;
-    let __end0 = __3.2;
-    let __temp0 = __action91(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action113(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action129(
+    __action210(
         input,
+        errors,
+        warnings,
         __0,
         __1,
-        __2,
-        __3,
         __temp0,
     )
 }
@@ -6683,26 +18808,29 @@ fn __action133<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action134<
+fn __action248<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
-) -> Type
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, ConditionExpression, usize),
+) -> Vec<ConditionExpression>
 {
-    let __start0 = __1.2;
-    let __end0 = __1.2;
-    let __temp0 = __action91(
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action129(
         input,
-        &__start0,
-        &__end0,
+        errors,
+        warnings,
+        __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(
+    __action172(
         input,
-        __0,
-        __1,
+        errors,
+        warnings,
         __temp0,
     )
 }
@@ -6710,98 +18838,127 @@ fn __action134<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action135<
+fn __action249<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Token<'input>, usize),
-    __3: (usize, Vec<ConditionArg>, usize),
-    __4: (usize, Token<'input>, usize),
-    __5: (usize, Token<'input>, usize),
-    __6: (usize, Token<'input>, usize),
-) -> Condition
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<ConditionExpression>
 {
-    let __start0 = __5.2;
-    let __end0 = __6.0;
-    let __temp0 = __action89(
+    let __start0 = *__lookbehind;
+    let __end0 = *__lookahead;
+    let __temp0 = __action130(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
+    __action172(
         input,
-        __0,
+        errors,
+        warnings,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action250<
+    'input,
+    'err,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<ConditionExpression>, usize),
+    __1: (usize, ConditionExpression, usize),
+) -> Vec<ConditionExpression>
+{
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action129(
+        input,
+        errors,
+        warnings,
         __1,
-        __2,
-        __3,
-        __4,
-        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action173(
+        input,
+        errors,
+        warnings,
+        __0,
         __temp0,
-        __6,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action136<
+fn __action251<
     'input,
+    'err,
 >(
     input: &'input str,
-    __0: (usize, Token<'input>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Token<'input>, usize),
-    __3: (usize, Vec<ConditionArg>, usize),
-    __4: (usize, Token<'input>, usize),
-    __5: (usize, Token<'input>, usize),
-    __6: (usize, alloc::vec::Vec<ConditionExpression>, usize),
-    __7: (usize, Token<'input>, usize),
-) -> Condition
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
+    __0: (usize, alloc::vec::Vec<ConditionExpression>, usize),
+) -> Vec<ConditionExpression>
 {
-    let __start0 = __6.0;
-    let __end0 = __6.2;
-    let __temp0 = __action90(
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action130(
         input,
-        __6,
+        errors,
+        warnings,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
+    __action173(
         input,
+        errors,
+        warnings,
         __0,
-        __1,
-        __2,
-        __3,
-        __4,
-        __5,
         __temp0,
-        __7,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action137<
+fn __action252<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __lookbehind: &usize,
     __lookahead: &usize,
 ) -> Schema
 {
     let __start0 = *__lookbehind;
     let __end0 = *__lookahead;
-    let __temp0 = __action93(
+    let __temp0 = __action115(
         input,
+        errors,
+        warnings,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    __action2(
         input,
+        errors,
+        warnings,
         __temp0,
     )
 }
@@ -6809,39 +18966,46 @@ fn __action137<
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action138<
+fn __action253<
     'input,
+    'err,
 >(
     input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Token<'input>, LexicalError>>,
+    warnings: &'err mut Vec<Diagnostic>,
     __0: (usize, alloc::vec::Vec<SchemaUnit>, usize),
 ) -> Schema
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action94(
+    let __temp0 = __action116(
         input,
+        errors,
+        warnings,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    __action2(
         input,
+        errors,
+        warnings,
         __temp0,
     )
 }
 #[allow(clippy::type_complexity, dead_code)]
 
-pub  trait __ToTriple<'input, >
+pub  trait __ToTriple<'input, 'err, >
 {
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, LexicalError>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<'input, 'err, > __ToTriple<'input, 'err, > for (usize, Token<'input>, usize)
 {
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, LexicalError>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), LexicalError>
+impl<'input, 'err, > __ToTriple<'input, 'err, > for Result<(usize, Token<'input>, usize), LexicalError>
 {
     fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, LexicalError>> {
         match value {
@@ -0,0 +1,202 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    parse_file, ConditionExpression, Diagnostic, ExpressionKind, Loc, Relationship, RelationshipSet, Schema, SetKind,
+};
+
+/// The files a model is composed of. Locations of the composed `Schema` are offsets into the files laid end to end,
+/// so that they stay unique, `locate` maps them back to the file they come from.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+#[derive(Debug, Clone)]
+struct SourceFile {
+    name: String,
+    source: String,
+    offset: usize,
+}
+
+impl SourceMap {
+    pub fn files(&self) -> impl Iterator<Item = &str> {
+        self.files.iter().map(|f| f.name.as_str())
+    }
+
+    /// Rebases the location of a diagnostic reported on the composed model onto its file.
+    pub fn locate(&self, diagnostic: Diagnostic) -> Diagnostic {
+        let Some((start, end)) = diagnostic.loc else {
+            return diagnostic;
+        };
+        let Some(file) = self.files.iter().rev().find(|f| f.offset <= start) else {
+            return diagnostic;
+        };
+        let loc = (start - file.offset, end - file.offset);
+        Diagnostic {
+            loc: Some(loc),
+            ..diagnostic
+        }
+        .with_source(&file.source)
+        .in_file(&file.name)
+    }
+
+    fn push(&mut self, name: String, source: String) -> usize {
+        let offset = self.files.last().map(|f| f.offset + f.source.len() + 1).unwrap_or(0);
+        self.files.push(SourceFile { name, source, offset });
+        offset
+    }
+}
+
+/// Parses `source` and every file it imports, transitively, into one `Schema`. `load` returns the source of an
+/// imported path or why it could not be read; each file is loaded once, whatever the number of imports of it.
+/// Types, mixins and conditions defined by several files are reported, errors name the file they were found in.
+pub fn parse_with_imports(
+    name: &str,
+    source: &str,
+    mut load: impl FnMut(&str) -> Result<String, String>,
+) -> Result<(Schema, SourceMap), Vec<Diagnostic>> {
+    let mut composed = Schema::default();
+    let mut map = SourceMap::default();
+    let mut errors = vec![];
+    let mut types: HashMap<String, String> = HashMap::new();
+    let mut conditions: HashMap<String, String> = HashMap::new();
+    let mut seen = HashSet::from([normalize(name).to_string()]);
+    let mut queue = VecDeque::from([(name.to_string(), source.to_string())]);
+
+    while let Some((name, source)) = queue.pop_front() {
+        let mut schema = match parse_file(&source) {
+            Ok((schema, _)) => schema,
+            Err(es) => {
+                errors.extend(es.into_iter().map(|e| e.in_file(&name)));
+                continue;
+            }
+        };
+        let error = |loc: Loc, message: String| Diagnostic::new(Some(loc), message).with_source(&source).in_file(&name);
+
+        for import in schema.imports.drain(..) {
+            let path = normalize(&import.path);
+            if !seen.insert(path.to_string()) {
+                continue;
+            }
+            match load(path) {
+                Ok(imported) => queue.push_back((path.to_string(), imported)),
+                Err(reason) => errors.push(error(
+                    import.loc,
+                    format!("cannot import `{}`: {}", import.path, reason),
+                )),
+            }
+        }
+        for typ in &schema.types {
+            match types.get(&typ.name) {
+                Some(file) if file.ne(&name) => errors.push(error(
+                    typ.loc,
+                    format!("duplicate type `{}`, already defined in `{}`", typ.name, file),
+                )),
+                _ => {
+                    types.insert(typ.name.clone(), name.clone());
+                }
+            }
+        }
+        for mixin in &schema.mixins {
+            match types.get(&mixin.name) {
+                Some(file) if file.ne(&name) => errors.push(error(
+                    mixin.loc,
+                    format!("duplicate mixin `{}`, already defined in `{}`", mixin.name, file),
+                )),
+                _ => {
+                    types.insert(mixin.name.clone(), name.clone());
+                }
+            }
+        }
+        for cond in &schema.conditions {
+            match conditions.get(&cond.name) {
+                Some(file) if file.ne(&name) => errors.push(error(
+                    cond.loc,
+                    format!("duplicate condition `{}`, already defined in `{}`", cond.name, file),
+                )),
+                _ => {
+                    conditions.insert(cond.name.clone(), name.clone());
+                }
+            }
+        }
+
+        composed
+            .warnings
+            .extend(schema.warnings.drain(..).map(|w| w.in_file(&name)));
+        let offset = map.push(name, source);
+        shift(&mut schema, offset);
+        composed.types.extend(schema.types);
+        composed.mixins.extend(schema.mixins);
+        composed.conditions.extend(schema.conditions);
+        composed.superadmins.extend(schema.superadmins);
+    }
+
+    if errors.is_empty() {
+        Ok((composed, map))
+    } else {
+        Err(errors)
+    }
+}
+
+fn normalize(path: &str) -> &str {
+    path.trim_start_matches("./")
+}
+
+fn shift(schema: &mut Schema, offset: usize) {
+    let shift_loc = |loc: &mut Loc| *loc = (loc.0 + offset, loc.1 + offset);
+    for typ in schema.types.iter_mut().chain(schema.mixins.iter_mut()) {
+        shift_loc(&mut typ.loc);
+        for rel in &mut typ.relations {
+            shift_loc(&mut rel.loc);
+            rel.subjects.iter_mut().for_each(|set| shift_set(set, offset));
+        }
+        for perm in &mut typ.permissions {
+            shift_loc(&mut perm.loc);
+            shift_relationship(&mut perm.permission, offset);
+        }
+    }
+    for superadmin in &mut schema.superadmins {
+        shift_loc(&mut superadmin.loc);
+    }
+    for cond in &mut schema.conditions {
+        shift_loc(&mut cond.loc);
+        shift_expression(&mut cond.body, offset);
+    }
+}
+
+fn shift_relationship(relationship: &mut Relationship, offset: usize) {
+    match relationship {
+        Relationship::Set(set) => shift_set(set, offset),
+        Relationship::Union { children } | Relationship::Intersection { children } => {
+            children.iter_mut().for_each(|child| shift_relationship(child, offset));
+        }
+        Relationship::Difference { base, subtract } => {
+            shift_relationship(base, offset);
+            shift_relationship(subtract, offset);
+        }
+    }
+}
+
+fn shift_set(set: &mut RelationshipSet, offset: usize) {
+    set.loc = (set.loc.0 + offset, set.loc.1 + offset);
+    if let SetKind::Conditional(set, _) = &mut set.kind {
+        shift_set(set, offset);
+    }
+}
+
+fn shift_expression(expr: &mut ConditionExpression, offset: usize) {
+    expr.loc = (expr.loc.0 + offset, expr.loc.1 + offset);
+    match &mut expr.kind {
+        ExpressionKind::Literal(_) | ExpressionKind::Identifier(_) => {}
+        ExpressionKind::Cast { expr, .. } => shift_expression(expr, offset),
+        ExpressionKind::MethodCall { receiver, args, .. } => {
+            shift_expression(receiver, offset);
+            args.iter_mut().for_each(|arg| shift_expression(arg, offset));
+        }
+        ExpressionKind::Unary { operand, .. } => shift_expression(operand, offset),
+        ExpressionKind::Binary { left, right, .. } => {
+            shift_expression(left, offset);
+            shift_expression(right, offset);
+        }
+    }
+}
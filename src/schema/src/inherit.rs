@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use crate::{Diagnostic, Loc, Permission, Relation, Schema, Type};
+
+/// Copies the relations and permissions of the types and mixins each type `extends` into it, transitively. A member
+/// declared by the type overrides the inherited one of the same name. The result has no mixins and no `extends`, it
+/// is the model the checker sees; errors are ignored, `validate` reports them.
+pub fn flatten(schema: &Schema) -> Schema {
+    flatten_with_errors(schema).0
+}
+
+/// Like `flatten`, along with undefined parents, cycles, duplicate mixins and members inherited from two parents
+/// with different definitions.
+pub(crate) fn flatten_with_errors(schema: &Schema) -> (Schema, Vec<Diagnostic>) {
+    let mut flattener = Flattener {
+        defs: HashMap::new(),
+        resolved: HashMap::new(),
+        visiting: vec![],
+        errors: vec![],
+    };
+    for typ in &schema.types {
+        flattener.defs.entry(&typ.name).or_insert(typ);
+    }
+    for mixin in &schema.mixins {
+        if flattener.defs.contains_key(mixin.name.as_str()) {
+            flattener.error(mixin.loc, format!("duplicate mixin `{}`", mixin.name));
+        } else {
+            flattener.defs.insert(&mixin.name, mixin);
+        }
+    }
+    // mixins that no type extends are still checked
+    for mixin in &schema.mixins {
+        flattener.resolve(&mixin.name);
+    }
+    let types = schema
+        .types
+        .iter()
+        .map(|typ| Type {
+            extends: vec![],
+            ..flattener.resolve(&typ.name).unwrap_or_else(|| typ.clone())
+        })
+        .collect();
+    let flat = Schema {
+        types,
+        mixins: vec![],
+        conditions: schema.conditions.clone(),
+        superadmins: schema.superadmins.clone(),
+        imports: schema.imports.clone(),
+        warnings: schema.warnings.clone(),
+    };
+    (flat, flattener.errors)
+}
+
+struct Flattener<'a> {
+    defs: HashMap<&'a str, &'a Type>,
+    resolved: HashMap<&'a str, Type>,
+    visiting: Vec<&'a str>,
+    errors: Vec<Diagnostic>,
+}
+
+enum Member {
+    Relation(Relation),
+    Permission(Permission),
+}
+
+impl Member {
+    fn name(&self) -> &str {
+        match self {
+            Member::Relation(rel) => &rel.name,
+            Member::Permission(perm) => &perm.name,
+        }
+    }
+
+    fn same(&self, other: &Member) -> bool {
+        match (self, other) {
+            (Member::Relation(a), Member::Relation(b)) => a.eq(b),
+            (Member::Permission(a), Member::Permission(b)) => a.eq(b),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Flattener<'a> {
+    fn error(&mut self, loc: Loc, message: String) {
+        // nodes deserialized from json carry no span
+        self.errors
+            .push(Diagnostic::new((loc.0 < loc.1).then_some(loc), message));
+    }
+
+    /// The type or mixin with its inherited members, none when it is undefined or part of a cycle.
+    fn resolve(&mut self, name: &str) -> Option<Type> {
+        if let Some(typ) = self.resolved.get(name) {
+            return Some(typ.clone());
+        }
+        let def = *self.defs.get(name)?;
+        if let Some(i) = self.visiting.iter().position(|v| v.eq(&name)) {
+            let mut path = self.visiting[i..].to_vec();
+            path.push(&def.name);
+            self.error(def.loc, format!("cyclic extends `{}`", path.join(" -> ")));
+            return None;
+        }
+        self.visiting.push(&def.name);
+
+        let declared = |member: &str| {
+            def.relations.iter().any(|r| r.name.eq(member)) || def.permissions.iter().any(|p| p.name.eq(member))
+        };
+        let mut inherited: Vec<(Member, &str)> = vec![];
+        for parent in &def.extends {
+            if !self.defs.contains_key(parent.as_str()) {
+                self.error(
+                    def.loc,
+                    format!("undefined type or mixin `{}` extended by `{}`", parent, def.name),
+                );
+                continue;
+            }
+            let Some(resolved) = self.resolve(parent) else {
+                continue;
+            };
+            let members = resolved
+                .relations
+                .into_iter()
+                .map(Member::Relation)
+                .chain(resolved.permissions.into_iter().map(Member::Permission));
+            for member in members {
+                if declared(member.name()) {
+                    continue;
+                }
+                match inherited.iter().find(|(m, _)| m.name().eq(member.name())) {
+                    Some((m, _)) if m.same(&member) => {}
+                    Some((_, from)) => {
+                        let message = format!(
+                            "`{}` is inherited from both `{}` and `{}` by `{}`, declare it to override them",
+                            member.name(),
+                            from,
+                            parent,
+                            def.name
+                        );
+                        self.error(def.loc, message);
+                    }
+                    None => inherited.push((member, parent)),
+                }
+            }
+        }
+
+        let mut typ = Type {
+            relations: vec![],
+            permissions: vec![],
+            ..def.clone()
+        };
+        for (member, _) in inherited {
+            match member {
+                Member::Relation(rel) => typ.relations.push(rel),
+                Member::Permission(perm) => typ.permissions.push(perm),
+            }
+        }
+        typ.relations.extend(def.relations.iter().cloned());
+        typ.permissions.extend(def.permissions.iter().cloned());
+
+        self.visiting.pop();
+        self.resolved.insert(&def.name, typ.clone());
+        Some(typ)
+    }
+}
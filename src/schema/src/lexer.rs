@@ -49,7 +49,7 @@ impl<'input> Lexer<'input> {
         loop {
             self.at_begin_of_line = false;
             match self.next_char() {
-                Some((i, ch)) if UnicodeXID::is_xid_start(ch) || ch == '*' => {
+                Some((i, ch)) if UnicodeXID::is_xid_start(ch) => {
                     let (id, end) = self.match_identifier(i);
                     return if let Some(w) = KEYWORDS.get(id) {
                         Some(Ok((i, *w, end)))
@@ -57,16 +57,16 @@ impl<'input> Lexer<'input> {
                         Some(Ok((i, Token::Identifier(id), end)))
                     };
                 }
-                Some((start, ch)) if ch >= '0' && ch <= '9' => {
+                Some((start, ch)) if ch.is_ascii_digit() => {
                     if ch == '0' && matches!(self.chars.peek(), Some((_, 'x')) | Some((_, 'X'))) {
-                        let mut end = start;
+                        let mut end = start + 2;
                         _ = self.next_char();
                         loop {
                             let peek = self.chars.peek();
                             if let Some((i, ch)) = peek {
-                                let ch = ch.clone();
-                                if (ch >= '0' && ch <= '9') || ((ch >= 'a' && ch <= 'e') || (ch >= 'A' && ch <= 'E')) {
-                                    end = i.clone();
+                                let ch = *ch;
+                                if ch.is_ascii_hexdigit() {
+                                    end = *i + 1;
                                     _ = self.next_char();
                                 } else {
                                     break;
@@ -83,16 +83,16 @@ impl<'input> Lexer<'input> {
                         )));
                     } else {
                         let mut exist_decimal_part = false;
-                        let mut end = start;
+                        let mut end = start + 1;
                         loop {
                             let peek = self.chars.peek();
                             if let Some((i, ch)) = peek {
-                                let ch = ch.clone();
-                                if ch >= '0' && ch <= '9' {
-                                    end = i.clone();
+                                let ch = *ch;
+                                if ch.is_ascii_digit() {
+                                    end = *i + 1;
                                     _ = self.next_char();
                                 } else if ch == '.' {
-                                    end = i.clone();
+                                    end = *i + 1;
                                     if !exist_decimal_part {
                                         _ = self.next_char();
                                         exist_decimal_part = true;
@@ -130,8 +130,11 @@ impl<'input> Lexer<'input> {
                     loop {
                         if let Some((i, ch)) = self.next_char() {
                             end = i;
-                            if ch == '"' {
-                                return Some(Ok((start, Token::StringLiteral(&self.input[start..end - 1]), end)));
+                            if ch == '\\' {
+                                // the escaped char can't end the literal
+                                _ = self.next_char();
+                            } else if ch == '"' {
+                                return Some(Ok((start, Token::StringLiteral(&self.input[start + 1..end]), end + 1)));
                             }
                         } else {
                             end = self.input.len();
@@ -153,24 +156,28 @@ impl<'input> Lexer<'input> {
                 Some((i, '(')) => return Some(Ok((i, Token::LBracket, i + 1))),
                 Some((i, ')')) => return Some(Ok((i, Token::RBracket, i + 1))),
                 Some((i, '^')) => return Some(Ok((i, Token::Caret, i + 1))),
-                Some((i, '&')) => return Some(Ok((i, Token::And, i + 1))),
-                Some((i, '|')) => return Some(Ok((i, Token::Or, i + 1))),
+                Some((i, '&')) => return Some(Ok(self.match_double(i, '&', Token::AndAnd, Token::And))),
+                Some((i, '|')) => return Some(Ok(self.match_double(i, '|', Token::OrOr, Token::Or))),
                 Some((i, '+')) => return Some(Ok((i, Token::Add, i + 1))),
-                Some((i, '-')) => {
-                    let peek = self.chars.peek();
-                    if matches!(peek, Some((_, '>'))) {
-                        return Some(Ok((i, Token::YulArrow, i + 2)));
-                    } else {
-                        return Some(Ok((i, Token::Sub, i + 1)));
-                    }
-                }
+                Some((i, '-')) => return Some(Ok(self.match_double(i, '>', Token::YulArrow, Token::Sub))),
                 Some((i, '#')) => return Some(Ok((i, Token::Sharp, i + 1))),
+                // the wildcard of `user#*` and the product of conditions, never part of an identifier
+                Some((i, '*')) => return Some(Ok((i, Token::Star, i + 1))),
+                Some((i, '%')) => return Some(Ok((i, Token::Percent, i + 1))),
+                Some((i, ';')) => return Some(Ok((i, Token::Semicolon, i + 1))),
+                Some((i, '=')) => return Some(Ok(self.match_double(i, '=', Token::EqEq, Token::Eq))),
+                Some((i, '!')) => return Some(Ok(self.match_double(i, '=', Token::NotEq, Token::ExclamationMark))),
+                Some((i, '>')) => return Some(Ok(self.match_double(i, '=', Token::Ge, Token::Gt))),
+                Some((i, '<')) => return Some(Ok(self.match_double(i, '=', Token::Le, Token::Lt))),
                 Some((_, ' ')) | Some((_, '\t')) | Some((_, '\x0C')) => (),
                 Some((i, '\n')) | Some((i, '\r')) => {
                     self.at_begin_of_line = true;
                     return Some(Ok((i, Token::Newline, i + 1)));
                 }
-                Some((_, '/')) => {
+                Some((i, '/')) => {
+                    if !matches!(self.chars.peek(), Some((_, '/'))) {
+                        return Some(Ok((i, Token::Slash, i + 1)));
+                    }
                     if let Err(err) = self.eat_comment() {
                         return Some(Err(err));
                     }
@@ -198,11 +205,27 @@ impl<'input> Lexer<'input> {
         }
     }
 
+    /// Matches a two characters operator such as `->` or `==`, falls back to the single character token.
+    fn match_double(
+        &mut self,
+        start: usize,
+        second: char,
+        double: Token<'input>,
+        single: Token<'input>,
+    ) -> (usize, Token<'input>, usize) {
+        if matches!(self.chars.peek(), Some((_, ch)) if *ch == second) {
+            self.next_char();
+            (start, double, start + 2)
+        } else {
+            (start, single, start + 1)
+        }
+    }
+
     fn match_identifier(&mut self, start: usize) -> (&'input str, usize) {
         let end;
         loop {
             if let Some((i, ch)) = self.chars.peek() {
-                if !UnicodeXID::is_xid_continue(*ch) && *ch != '*' {
+                if !UnicodeXID::is_xid_continue(*ch) {
                     end = *i;
                     break;
                 }
@@ -230,7 +253,7 @@ impl<'input> Lexer<'input> {
                         break;
                     }
                     Some((offset, '\n' | '\r')) => {
-                        end = offset.clone();
+                        end = *offset;
                         break;
                     }
                     Some(_) => {
@@ -271,6 +294,23 @@ impl<'input> Lexer<'input> {
     }
 }
 
+/// The value of a string literal, `\"` and `\\` stand for a quote and a backslash, any other backslash is kept as
+/// written.
+pub fn unescape(literal: &str) -> String {
+    let mut value = String::with_capacity(literal.len());
+    let mut chars = literal.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (ch, chars.peek()) {
+            ('\\', Some(&escaped @ ('"' | '\\'))) => {
+                value.push(escaped);
+                chars.next();
+            }
+            _ => value.push(ch),
+        }
+    }
+    value
+}
+
 impl<'input> Iterator for Lexer<'input> {
     type Item = Spanned<Token<'input>, usize, LexicalError>;
 
@@ -288,11 +328,21 @@ impl<'input> Iterator for Lexer<'input> {
     }
 }
 
+/// Whether the token is one of the words the lexer reserves, such a word cannot be used as a name.
+pub fn is_keyword(token: &Token) -> bool {
+    !matches!(token, Token::Identifier(_)) && KEYWORDS.contains_key(token.to_string().as_str())
+}
+
 static KEYWORDS: phf::Map<&'static str, Token> = phf_map! {
     "type" => Token::Type,
     "relation" => Token::Relation,
     "permission" => Token::Permission,
     "cond" => Token::Cond,
+    "with" => Token::With,
+    "import" => Token::Import,
+    "superadmin" => Token::Superadmin,
+    "extends" => Token::Extends,
+    "mixin" => Token::Mixin,
     "condition" => Token::Condition,
     "int" => Token::Int,
     "uint" => Token::Uint,
@@ -306,4 +356,6 @@ static KEYWORDS: phf::Map<&'static str, Token> = phf_map! {
     "list" => Token::List,
     "map" => Token::Map,
     "ipaddress" => Token::IPaddress,
+    "true" => Token::True,
+    "false" => Token::False,
 };
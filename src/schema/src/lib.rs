@@ -1,54 +1,212 @@
 pub mod ast;
+mod diagnostic;
+mod diff;
+mod eval;
+mod format;
+// generated from grammar.lalrpop, whose parameters are `&mut Vec`s
+#[allow(clippy::ptr_arg, clippy::empty_line_after_outer_attr)]
 mod grammar;
+mod import;
+mod inherit;
 pub mod lexer;
+pub mod openfga;
 mod pos;
+pub mod spicedb;
 pub mod token;
+mod validate;
 
 #[cfg(test)]
 mod tests;
 
 pub use ast::*;
+pub use diagnostic::*;
+pub use diff::*;
+pub use eval::*;
+pub use format::*;
 pub use grammar::SchemaParser as Parser;
+pub use import::*;
+pub use inherit::flatten;
 use lalrpop_util::ParseError;
 pub use lexer::Lexer;
 use lexer::LexicalError;
-pub use pos::Loc;
+pub use pos::{CodeLocation, Loc};
 pub use token::*;
+pub use validate::*;
 
-pub fn parse<'a>(input: &'a str) -> Result<(Schema, Vec<(Loc, String)>), Vec<(Loc, String)>> {
+/// Parses a model, on failure every syntax error found is returned, or the type errors of the conditions when the
+/// syntax is valid. Diagnostics are already resolved against `input`, warnings of a valid model are kept in
+/// `Schema::warnings`. Imports are left unresolved and warned about, `parse_with_imports` composes them.
+#[allow(clippy::type_complexity)]
+pub fn parse(input: &str) -> Result<(Schema, Vec<(Loc, String)>), Vec<Diagnostic>> {
+    let (mut schema, comments) = parse_file(input)?;
+    for import in &schema.imports {
+        schema.warnings.push(
+            Diagnostic::warning(
+                Some(import.loc),
+                format!(
+                    "`{}` is not imported, only `parse_with_imports` composes the imported files",
+                    import.path
+                ),
+            )
+            .with_source(input),
+        );
+    }
+    Ok((schema, comments))
+}
+
+/// `parse` without the warnings about imports, for `parse_with_imports` which resolves them.
+#[allow(clippy::type_complexity)]
+pub(crate) fn parse_file(input: &str) -> Result<(Schema, Vec<(Loc, String)>), Vec<Diagnostic>> {
     let mut errors = vec![];
+    let mut recovered = vec![];
+    let mut warnings = vec![];
     let mut comments = vec![];
     let lexer = lexer::Lexer::new(input, &mut comments);
-    let res = Parser::new().parse(input, lexer);
+    let res = Parser::new().parse(input, &mut recovered, &mut warnings, lexer);
+    for recovery in recovered {
+        errors.push(parse_error(recovery.error));
+    }
     match res {
-        Ok(s) => Ok((s, comments)),
-        Err(e) => {
-            match e {
-                ParseError::InvalidToken { location } => {
-                    errors.push(((location, location), format!("parser-invalid-token")));
-                }
-                ParseError::UnrecognizedEof { location, expected } => {
-                    errors.push((
-                        (location, location),
-                        format!("parser-unrecognized-eof: excepted: {:?}", expected),
+        Ok(mut s) if errors.is_empty() => {
+            for cond in &s.conditions {
+                for (loc, err) in cond.type_check() {
+                    errors.push(Diagnostic::new(
+                        Some(loc),
+                        format!("condition `{}`: {}", cond.name, err),
                     ));
                 }
-                ParseError::UnrecognizedToken { token, expected } => {
-                    errors.push((
-                        (token.0, token.2),
-                        format!("parser-unrecognized-token: excepted: {:?}", expected),
-                    ));
-                }
-                ParseError::ExtraToken { token } => {
-                    errors.push(((token.0, token.2), format!("parser-extra-token")));
-                }
-                ParseError::User { error } => match error {
-                    LexicalError::UnrecognisedToken(loc, token) => {
-                        errors.push((loc, format!("lex-unrecognized-token: {:?}", token)));
-                    }
-                },
             }
-            Err(errors)
+            if errors.is_empty() {
+                s.warnings = warnings.into_iter().map(|w| w.with_source(input)).collect();
+                attach_descriptions(&mut s, &mut comments, input);
+                return Ok((s, comments));
+            }
         }
+        Ok(_) => {}
+        Err(e) => errors.push(parse_error(e)),
+    }
+    Err(errors.into_iter().map(|e| e.with_source(input)).collect())
+}
+
+/// Moves the `///` comments written on the lines right before a type, a relation or a permission to its
+/// `description`, the other comments are returned by `parse` as they are.
+fn attach_descriptions(schema: &mut Schema, comments: &mut Vec<(Loc, String)>, input: &str) {
+    let mut attached = vec![false; comments.len()];
+    let mut describe = |start: usize| description(input, comments, &mut attached, start);
+    for typ in schema.types.iter_mut().chain(schema.mixins.iter_mut()) {
+        typ.description = describe(typ.loc.0);
+        for rel in &mut typ.relations {
+            rel.description = describe(rel.loc.0);
+        }
+        for perm in &mut typ.permissions {
+            perm.description = describe(perm.loc.0);
+        }
+    }
+    let mut attached = attached.into_iter();
+    comments.retain(|_| !attached.next().unwrap_or_default());
+}
+
+fn description(input: &str, comments: &[(Loc, String)], attached: &mut [bool], mut start: usize) -> Option<String> {
+    let mut lines = vec![];
+    // comments are in source order, walk back from the node while only blank space separates them
+    while let Some(i) = comments.iter().rposition(|(loc, _)| loc.1 <= start) {
+        let ((l, r), text) = &comments[i];
+        let line_start = input[..*l].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let Some(doc) = text.strip_prefix("///") else {
+            break;
+        };
+        if !input[*r..start].trim().is_empty() || !input[line_start..*l].trim().is_empty() {
+            break;
+        }
+        lines.push(doc.strip_prefix(' ').unwrap_or(doc).trim_end());
+        attached[i] = true;
+        start = *l;
+    }
+    lines.reverse();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Reads a condition defined apart from a model, such as the ones of OpenFGA or SpiceDB. Only the expression is
+/// parsed, the condition is built around it. The error is the first one found, without location.
+pub(crate) fn parse_condition(name: &str, args: Vec<ConditionArg>, expression: &str) -> Result<Condition, String> {
+    let mut recovered = vec![];
+    let mut warnings = vec![];
+    let mut comments = vec![];
+    let lexer = lexer::Lexer::new(expression, &mut comments);
+    let body = grammar::ConditionBodyParser::new()
+        .parse(expression, &mut recovered, &mut warnings, lexer)
+        .map_err(|e| parse_error(e).message)?;
+    if let Some(recovery) = recovered.into_iter().next() {
+        return Err(parse_error(recovery.error).message);
+    }
+    let cond = Condition {
+        name: name.to_string(),
+        args,
+        body,
+        loc: (0, 0),
+    };
+    match cond.type_check().into_iter().next() {
+        Some((_, err)) => Err(err.to_string()),
+        None => Ok(cond),
+    }
+}
+
+fn parse_error(e: ParseError<usize, Token, LexicalError>) -> Diagnostic {
+    match e {
+        ParseError::InvalidToken { location } => Diagnostic::new(Some((location, location)), "invalid token"),
+        ParseError::UnrecognizedEof { location, expected } => Diagnostic::new(
+            Some((location, location)),
+            format!("unexpected end of file, {}", expected_tokens(&expected)),
+        ),
+        // some keywords, such as `mixin` or `import`, were valid names in the models written before they were added
+        ParseError::UnrecognizedToken { token, expected }
+            if lexer::is_keyword(&token.1) && expected.iter().any(|e| e == "identifier") =>
+        {
+            Diagnostic::new(
+                Some((token.0, token.2)),
+                format!("`{}` is a reserved keyword, it cannot be used as a name", token.1),
+            )
+        }
+        ParseError::UnrecognizedToken { token, expected } => Diagnostic::new(
+            Some((token.0, token.2)),
+            format!(
+                "unexpected {}, {}",
+                describe_token(&token.1),
+                expected_tokens(&expected)
+            ),
+        ),
+        ParseError::ExtraToken { token } => Diagnostic::new(
+            Some((token.0, token.2)),
+            format!("unexpected {}", describe_token(&token.1)),
+        ),
+        ParseError::User { error } => match error {
+            LexicalError::UnrecognisedToken(loc, _) => Diagnostic::new(Some(loc), error.to_string()),
+        },
+    }
+}
+
+fn describe_token(token: &Token) -> String {
+    match token {
+        Token::Newline => "end of line".to_string(),
+        Token::Identifier(id) => format!("identifier `{}`", id),
+        token => format!("`{}`", token),
+    }
+}
+
+/// lalrpop names terminals as they are written in the grammar, `"type"`, `"\n"` or `identifier`
+fn expected_tokens(expected: &[String]) -> String {
+    let mut names: Vec<String> = expected
+        .iter()
+        .map(|e| match e.as_str() {
+            r#""\n""# => "end of line".to_string(),
+            e if e.starts_with('"') => format!("`{}`", e.trim_matches('"')),
+            e => e.replace('_', " "),
+        })
+        .collect();
+    names.dedup();
+    match names.len() {
+        0 => "expected nothing".to_string(),
+        1 => format!("expected {}", names[0]),
+        n => format!("expected one of {} or {}", names[..n - 1].join(", "), names[n - 1]),
     }
 }
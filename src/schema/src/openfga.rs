@@ -0,0 +1,484 @@
+//! Conversion from and to the authorization model json of OpenFGA (schema version 1.1), of a `Schema` or of the
+//! `protocol::Typesystem` it is checked with.
+//!
+//! A relation of OpenFGA is either directly assigned (`this`) or rewritten, the ones mixing both, such as
+//! `define viewer: [user] or editor`, have no counterpart here: relations only hold direct types and permissions only
+//! rewrites.
+
+use std::collections::BTreeMap;
+
+use protocol::{Typesystem, WILDCARD};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    flatten, parse_condition, Condition, ConditionArg, ConditionType, Diagnostic, Permission, Relation, Relationship,
+    RelationshipSet, Schema, SetKind, Type,
+};
+
+pub const SCHEMA_VERSION: &str = "1.1";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AuthorizationModel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default = "schema_version")]
+    pub schema_version: String,
+    pub type_definitions: Vec<TypeDefinition>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub conditions: BTreeMap<String, ConditionDefinition>,
+}
+
+fn schema_version() -> String {
+    SCHEMA_VERSION.to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TypeDefinition {
+    pub r#type: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub relations: BTreeMap<String, Userset>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum Userset {
+    This(Empty),
+    ComputedUserset(ObjectRelation),
+    TupleToUserset(TupleToUserset),
+    Union(Usersets),
+    Intersection(Usersets),
+    Difference(Difference),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Empty {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ObjectRelation {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub object: String,
+    pub relation: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TupleToUserset {
+    pub tupleset: ObjectRelation,
+    pub computed_userset: ObjectRelation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Usersets {
+    pub child: Vec<Userset>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Difference {
+    pub base: Box<Userset>,
+    pub subtract: Box<Userset>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Metadata {
+    #[serde(default)]
+    pub relations: BTreeMap<String, RelationMetadata>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RelationMetadata {
+    #[serde(default)]
+    pub directly_related_user_types: Vec<RelationReference>,
+}
+
+/// `user`, `user:*` (`wildcard`), `group#member` (`relation`), each one optionally `with` a condition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RelationReference {
+    pub r#type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wildcard: Option<Empty>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub condition: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ConditionDefinition {
+    pub name: String,
+    pub expression: String,
+    #[serde(default)]
+    pub parameters: BTreeMap<String, ParameterType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ParameterType {
+    /// `TYPE_NAME_INT`, `TYPE_NAME_LIST`...
+    pub type_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generic_types: Vec<ParameterType>,
+}
+
+/// Exports a model, relations and permissions of a type are keyed by name. `bytes` has no OpenFGA counterpart, it is
+/// exported as `TYPE_NAME_BYTES` which only `from_openfga` reads back. Superadmins have no counterpart and are left
+/// out, types are exported flattened.
+pub fn to_openfga(schema: &Schema) -> AuthorizationModel {
+    let type_definitions = flatten(schema)
+        .types
+        .iter()
+        .map(|typ| {
+            let mut relations = BTreeMap::new();
+            let mut metadata = BTreeMap::new();
+            for rel in &typ.relations {
+                relations.insert(rel.name.clone(), Userset::This(Empty {}));
+                metadata.insert(
+                    rel.name.clone(),
+                    RelationMetadata {
+                        directly_related_user_types: rel.subjects.iter().map(relation_reference).collect(),
+                    },
+                );
+            }
+            for perm in &typ.permissions {
+                relations.insert(perm.name.clone(), rewrite(&perm.permission));
+            }
+            TypeDefinition {
+                r#type: typ.name.clone(),
+                relations,
+                metadata: (!metadata.is_empty()).then_some(Metadata { relations: metadata }),
+            }
+        })
+        .collect();
+    let conditions = schema
+        .conditions
+        .iter()
+        .map(|cond| {
+            let definition = ConditionDefinition {
+                name: cond.name.clone(),
+                expression: cond.body.to_string(),
+                parameters: cond
+                    .args
+                    .iter()
+                    .map(|arg| (arg.name.clone(), parameter_type(&arg.r#type)))
+                    .collect(),
+            };
+            (cond.name.clone(), definition)
+        })
+        .collect();
+    AuthorizationModel {
+        id: None,
+        schema_version: schema_version(),
+        type_definitions,
+        conditions,
+    }
+}
+
+/// Exports the typesystem a model is checked with, types are sorted by name. The typesystem keeps no condition
+/// definitions, conditional subjects only name theirs, export the `Schema` to define them as well.
+pub fn typesystem_to_openfga(typesystem: &Typesystem) -> AuthorizationModel {
+    let mut types = typesystem.types.values().collect::<Vec<_>>();
+    types.sort_by(|l, r| l.name.cmp(&r.name));
+    let type_definitions = types
+        .into_iter()
+        .map(|typ| {
+            let relations = typ
+                .relations
+                .iter()
+                .map(|(name, userset)| (name.clone(), typesystem_rewrite(userset)))
+                .collect();
+            let metadata = typ
+                .metadata
+                .iter()
+                .map(|(name, metadata)| {
+                    let directly_related_user_types = metadata
+                        .directly_related_user_types
+                        .iter()
+                        .map(typesystem_reference)
+                        .collect();
+                    (
+                        name.clone(),
+                        RelationMetadata {
+                            directly_related_user_types,
+                        },
+                    )
+                })
+                .collect::<BTreeMap<_, _>>();
+            TypeDefinition {
+                r#type: typ.name.clone(),
+                relations,
+                metadata: (!metadata.is_empty()).then_some(Metadata { relations: metadata }),
+            }
+        })
+        .collect();
+    AuthorizationModel {
+        id: None,
+        schema_version: schema_version(),
+        type_definitions,
+        conditions: BTreeMap::new(),
+    }
+}
+
+/// Imports a model as the typesystem it is checked with, see `from_openfga`.
+pub fn typesystem_from_openfga(model: &AuthorizationModel) -> Result<Typesystem, Vec<Diagnostic>> {
+    from_openfga(model).map(Schema::to_typesystem)
+}
+
+fn typesystem_reference(reference: &protocol::RelationReference) -> RelationReference {
+    let direct = |typ: &str| RelationReference {
+        r#type: typ.to_string(),
+        relation: None,
+        wildcard: None,
+        condition: String::new(),
+    };
+    match reference {
+        protocol::RelationReference::Direct(typ) => direct(typ),
+        protocol::RelationReference::Wildcard(typ) => RelationReference {
+            wildcard: Some(Empty {}),
+            ..direct(typ)
+        },
+        protocol::RelationReference::Relation { r#type, relation } => RelationReference {
+            relation: Some(relation.clone()),
+            ..direct(r#type)
+        },
+        protocol::RelationReference::Conditional { reference, condition } => RelationReference {
+            condition: condition.clone(),
+            ..typesystem_reference(reference)
+        },
+    }
+}
+
+fn typesystem_rewrite(userset: &protocol::Userset) -> Userset {
+    let usersets = |children: &[Box<protocol::Userset>]| Usersets {
+        child: children.iter().map(|c| typesystem_rewrite(c)).collect(),
+    };
+    match userset {
+        protocol::Userset::This => Userset::This(Empty {}),
+        protocol::Userset::Computed(computed) => Userset::ComputedUserset(object_relation(&computed.relation)),
+        protocol::Userset::TupleTo(ttu) => Userset::TupleToUserset(TupleToUserset {
+            tupleset: object_relation(&ttu.tupleset.relation),
+            computed_userset: object_relation(&ttu.computed_userset.relation),
+        }),
+        protocol::Userset::Union { children } => Userset::Union(usersets(children)),
+        protocol::Userset::Intersection { children } => Userset::Intersection(usersets(children)),
+        protocol::Userset::Difference { base, subtract } => Userset::Difference(Difference {
+            base: Box::new(typesystem_rewrite(base)),
+            subtract: Box::new(typesystem_rewrite(subtract)),
+        }),
+    }
+}
+
+fn relation_reference(sub: &RelationshipSet) -> RelationReference {
+    match &sub.kind {
+        SetKind::Single(typ) => RelationReference {
+            r#type: typ.clone(),
+            relation: None,
+            wildcard: None,
+            condition: String::new(),
+        },
+        SetKind::Set(typ, relation) if relation.eq(WILDCARD) => RelationReference {
+            wildcard: Some(Empty {}),
+            ..relation_reference(&RelationshipSet::single(typ))
+        },
+        SetKind::Set(typ, relation) => RelationReference {
+            relation: Some(relation.clone()),
+            ..relation_reference(&RelationshipSet::single(typ))
+        },
+        SetKind::Conditional(set, condition) => RelationReference {
+            condition: condition.clone(),
+            ..relation_reference(set)
+        },
+    }
+}
+
+fn rewrite(relationship: &Relationship) -> Userset {
+    match relationship {
+        Relationship::Set(set) => match &set.kind {
+            SetKind::Single(relation) => Userset::ComputedUserset(object_relation(relation)),
+            SetKind::Set(tupleset, computed) => Userset::TupleToUserset(TupleToUserset {
+                tupleset: object_relation(tupleset),
+                computed_userset: object_relation(computed),
+            }),
+            SetKind::Conditional(set, _) => rewrite(&Relationship::Set(*set.clone())),
+        },
+        Relationship::Union { children } => Userset::Union(Usersets {
+            child: children.iter().map(|c| rewrite(c)).collect(),
+        }),
+        Relationship::Intersection { children } => Userset::Intersection(Usersets {
+            child: children.iter().map(|c| rewrite(c)).collect(),
+        }),
+        Relationship::Difference { base, subtract } => Userset::Difference(Difference {
+            base: Box::new(rewrite(base)),
+            subtract: Box::new(rewrite(subtract)),
+        }),
+    }
+}
+
+fn object_relation(relation: &str) -> ObjectRelation {
+    ObjectRelation {
+        object: String::new(),
+        relation: relation.to_string(),
+    }
+}
+
+fn parameter_type(typ: &ConditionType) -> ParameterType {
+    let (type_name, generic_types) = match typ {
+        ConditionType::Int => ("TYPE_NAME_INT", vec![]),
+        ConditionType::Uint => ("TYPE_NAME_UINT", vec![]),
+        ConditionType::Double => ("TYPE_NAME_DOUBLE", vec![]),
+        ConditionType::Bool => ("TYPE_NAME_BOOL", vec![]),
+        ConditionType::Bytes => ("TYPE_NAME_BYTES", vec![]),
+        ConditionType::String => ("TYPE_NAME_STRING", vec![]),
+        ConditionType::Duration => ("TYPE_NAME_DURATION", vec![]),
+        ConditionType::Timestamp => ("TYPE_NAME_TIMESTAMP", vec![]),
+        ConditionType::Any => ("TYPE_NAME_ANY", vec![]),
+        ConditionType::IPaddress => ("TYPE_NAME_IPADDRESS", vec![]),
+        ConditionType::List(t) => ("TYPE_NAME_LIST", vec![parameter_type(t)]),
+        ConditionType::Map(t) => ("TYPE_NAME_MAP", vec![parameter_type(t)]),
+    };
+    ParameterType {
+        type_name: type_name.to_string(),
+        generic_types,
+    }
+}
+
+/// Imports a model, every relation or condition that cannot be converted is reported. Relations and permissions are
+/// ordered by name, condition expressions are read with the dsl grammar.
+pub fn from_openfga(model: &AuthorizationModel) -> Result<Schema, Vec<Diagnostic>> {
+    let mut errors = vec![];
+    let mut schema = Schema::default();
+    for definition in &model.type_definitions {
+        let mut typ = Type::new(definition.r#type.clone(), vec![], (0, 0));
+        for (name, userset) in &definition.relations {
+            let context = format!("relation `{}.{}`", typ.name, name);
+            match userset {
+                Userset::This(_) => {
+                    let references = definition
+                        .metadata
+                        .as_ref()
+                        .and_then(|m| m.relations.get(name))
+                        .map(|m| m.directly_related_user_types.as_slice())
+                        .unwrap_or_default();
+                    if references.is_empty() {
+                        errors.push(Diagnostic::new(
+                            None,
+                            format!("{} has no directly related user types", context),
+                        ));
+                        continue;
+                    }
+                    typ.relations.push(Relation {
+                        name: name.clone(),
+                        subjects: references.iter().map(subject).collect(),
+                        description: None,
+                        loc: (0, 0),
+                    });
+                }
+                userset => match relationship(userset) {
+                    Some(permission) => typ.permissions.push(Permission {
+                        name: name.clone(),
+                        permission: permission.compute(),
+                        description: None,
+                        loc: (0, 0),
+                    }),
+                    None => errors.push(Diagnostic::new(
+                        None,
+                        format!(
+                            "{} mixes direct types with a rewrite, split it into a relation and a permission",
+                            context
+                        ),
+                    )),
+                },
+            }
+        }
+        schema.types.push(typ);
+    }
+    for (name, definition) in &model.conditions {
+        match condition(name, definition) {
+            Ok(cond) => schema.conditions.push(cond),
+            Err(err) => errors.push(Diagnostic::new(None, format!("condition `{}`: {}", name, err))),
+        }
+    }
+    if errors.is_empty() {
+        Ok(schema)
+    } else {
+        Err(errors)
+    }
+}
+
+fn subject(reference: &RelationReference) -> RelationshipSet {
+    let set = match (&reference.relation, &reference.wildcard) {
+        (_, Some(_)) => RelationshipSet::set(&reference.r#type, WILDCARD),
+        (Some(relation), None) => RelationshipSet::set(&reference.r#type, relation),
+        (None, None) => RelationshipSet::single(&reference.r#type),
+    };
+    if reference.condition.is_empty() {
+        set
+    } else {
+        RelationshipSet::conditional(set, &reference.condition)
+    }
+}
+
+/// `None` when `this` is found in the rewrite
+fn relationship(userset: &Userset) -> Option<Relationship> {
+    let children = |usersets: &Usersets| {
+        usersets
+            .child
+            .iter()
+            .map(|c| relationship(c).map(Box::new))
+            .collect::<Option<Vec<_>>>()
+    };
+    Some(match userset {
+        Userset::This(_) => return None,
+        Userset::ComputedUserset(computed) => Relationship::Set(RelationshipSet::single(&computed.relation)),
+        Userset::TupleToUserset(ttu) => Relationship::Set(RelationshipSet::set(
+            &ttu.tupleset.relation,
+            &ttu.computed_userset.relation,
+        )),
+        Userset::Union(usersets) => Relationship::Union {
+            children: children(usersets)?,
+        },
+        Userset::Intersection(usersets) => Relationship::Intersection {
+            children: children(usersets)?,
+        },
+        Userset::Difference(difference) => Relationship::Difference {
+            base: Box::new(relationship(&difference.base)?),
+            subtract: Box::new(relationship(&difference.subtract)?),
+        },
+    })
+}
+
+fn condition(name: &str, definition: &ConditionDefinition) -> Result<Condition, String> {
+    let args = definition
+        .parameters
+        .iter()
+        .map(|(name, typ)| {
+            Ok(ConditionArg {
+                name: name.clone(),
+                r#type: condition_type(typ)?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    parse_condition(name, args, &definition.expression)
+}
+
+fn condition_type(typ: &ParameterType) -> Result<ConditionType, String> {
+    let generic = || match typ.generic_types.as_slice() {
+        [t] => condition_type(t).map(Box::new),
+        _ => Err(format!("`{}` takes one generic type", typ.type_name)),
+    };
+    Ok(match typ.type_name.as_str() {
+        "TYPE_NAME_INT" => ConditionType::Int,
+        "TYPE_NAME_UINT" => ConditionType::Uint,
+        "TYPE_NAME_DOUBLE" => ConditionType::Double,
+        "TYPE_NAME_BOOL" => ConditionType::Bool,
+        "TYPE_NAME_BYTES" => ConditionType::Bytes,
+        "TYPE_NAME_STRING" => ConditionType::String,
+        "TYPE_NAME_DURATION" => ConditionType::Duration,
+        "TYPE_NAME_TIMESTAMP" => ConditionType::Timestamp,
+        "TYPE_NAME_ANY" => ConditionType::Any,
+        "TYPE_NAME_IPADDRESS" => ConditionType::IPaddress,
+        "TYPE_NAME_LIST" => ConditionType::List(generic()?),
+        "TYPE_NAME_MAP" => ConditionType::Map(generic()?),
+        other => return Err(format!("unsupported parameter type `{}`", other)),
+    })
+}
@@ -0,0 +1,468 @@
+//! Translation of the SpiceDB schema language (`.zed`) into a model.
+//!
+//! `definition`, `relation`, `permission` with `+`, `&`, `-`, `->` and `.any()`, and `caveat` are read; `nil`,
+//! `.all()`, namespaced names (`org/user`) and the `expiration` trait have no counterpart and are reported. As in
+//! SpiceDB `+` binds tighter than `&`, which binds tighter than `-`.
+
+use crate::{
+    parse_condition, Condition, ConditionArg, ConditionType, Diagnostic, Loc, Permission, Relation, Relationship,
+    RelationshipSet, Schema, SetKind, Type,
+};
+
+/// Translates a schema, every syntax error stops the translation, constructs that cannot be represented are all
+/// reported. Diagnostics are resolved against `source`.
+pub fn from_spicedb(source: &str) -> Result<Schema, Vec<Diagnostic>> {
+    let mut parser = Parser {
+        src: source,
+        pos: 0,
+        errors: vec![],
+    };
+    let schema = parser.schema();
+    match schema {
+        Ok(schema) if parser.errors.is_empty() => Ok(schema),
+        _ => Err(parser.errors.into_iter().map(|e| e.with_source(source)).collect()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Identifier(&'a str),
+    Symbol(&'static str),
+    /// any other char
+    Unknown(char),
+    Eof,
+}
+
+impl std::fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Identifier(id) => write!(f, "identifier `{}`", id),
+            Token::Symbol(s) => write!(f, "`{}`", s),
+            Token::Unknown(c) => write!(f, "`{}`", c),
+            Token::Eof => f.write_str("end of file"),
+        }
+    }
+}
+
+// longest first
+const SYMBOLS: [&str; 17] = [
+    "->", "{", "}", "(", ")", ":", "|", "#", "*", ",", "=", "+", "-", "&", ".", "<", ">",
+];
+
+/// a syntax error, already recorded
+struct SyntaxError;
+
+type Parsed<T> = Result<T, SyntaxError>;
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    errors: Vec<Diagnostic>,
+}
+
+impl<'a> Parser<'a> {
+    fn schema(&mut self) -> Parsed<Schema> {
+        let mut schema = Schema::default();
+        loop {
+            match self.next()? {
+                (Token::Eof, _) => return Ok(schema),
+                (Token::Identifier("definition"), _) => {
+                    if let Some(typ) = self.definition()? {
+                        schema.types.push(typ);
+                    }
+                }
+                (Token::Identifier("caveat"), l) => {
+                    if let Some(cond) = self.caveat(l)? {
+                        schema.conditions.push(cond);
+                    }
+                }
+                (Token::Identifier("use"), l) => {
+                    let (feature, r) = self.identifier()?;
+                    self.unsupported((l.0, r.1), format!("`use {}`", feature));
+                }
+                (token, loc) => return self.syntax_error(token, loc, "`definition`, `caveat` or `use`"),
+            }
+        }
+    }
+
+    fn definition(&mut self) -> Parsed<Option<Type>> {
+        let (name, loc) = self.identifier()?;
+        let mut typ = Type::new(name.to_string(), vec![], loc);
+        self.expect("{")?;
+        loop {
+            match self.next()? {
+                (Token::Symbol("}"), r) => {
+                    typ.loc = (loc.0, r.1);
+                    return Ok(self.representable(name, loc).then_some(typ));
+                }
+                (Token::Identifier("relation"), l) => {
+                    if let Some(rel) = self.relation(l)? {
+                        typ.relations.push(rel);
+                    }
+                }
+                (Token::Identifier("permission"), l) => {
+                    if let Some(perm) = self.permission(l)? {
+                        typ.permissions.push(perm);
+                    }
+                }
+                (token, loc) => return self.syntax_error(token, loc, "`relation`, `permission` or `}`"),
+            }
+        }
+    }
+
+    fn relation(&mut self, l: Loc) -> Parsed<Option<Relation>> {
+        let (name, _) = self.identifier()?;
+        self.expect(":")?;
+        let mut subjects = vec![];
+        let mut representable = true;
+        loop {
+            match self.subject()? {
+                Some(subject) => subjects.push(subject),
+                None => representable = false,
+            }
+            if self.peek().0 != Token::Symbol("|") {
+                break;
+            }
+            self.next()?;
+        }
+        let loc = (l.0, subjects.last().map(|s| s.loc.1).unwrap_or(l.1));
+        Ok(representable.then(|| Relation {
+            name: name.to_string(),
+            subjects,
+            description: None,
+            loc,
+        }))
+    }
+
+    /// `user`, `user:*`, `group#member`, each one optionally `with` a caveat
+    fn subject(&mut self) -> Parsed<Option<RelationshipSet>> {
+        let (typ, l) = self.identifier()?;
+        let mut representable = self.representable(typ, l);
+        let mut r = l.1;
+        let kind = match self.peek().0 {
+            Token::Symbol(":") => {
+                self.next()?;
+                r = self.expect("*")?.1;
+                SetKind::Set(typ.to_string(), "*".to_string())
+            }
+            Token::Symbol("#") => {
+                self.next()?;
+                let (relation, loc) = self.identifier()?;
+                r = loc.1;
+                SetKind::Set(typ.to_string(), relation.to_string())
+            }
+            _ => SetKind::Single(typ.to_string()),
+        };
+        let mut subject = RelationshipSet::new(kind, (l.0, r));
+        if self.peek().0 == Token::Identifier("with") {
+            self.next()?;
+            let mut traits = vec![self.identifier()?];
+            while self.peek().0 == Token::Identifier("and") {
+                self.next()?;
+                traits.push(self.identifier()?);
+            }
+            for (name, loc) in traits {
+                r = loc.1;
+                if name == "expiration" {
+                    self.unsupported(loc, "the `expiration` trait".to_string());
+                    representable = false;
+                } else if let SetKind::Conditional(..) = subject.kind {
+                    self.unsupported(loc, "several caveats on a subject".to_string());
+                    representable = false;
+                } else {
+                    subject = RelationshipSet::conditional(subject, name.to_string());
+                }
+            }
+            subject.loc = (l.0, r);
+        }
+        Ok(representable.then_some(subject))
+    }
+
+    fn permission(&mut self, l: Loc) -> Parsed<Option<Permission>> {
+        let (name, _) = self.identifier()?;
+        self.expect("=")?;
+        let expr = self.exclusion()?;
+        Ok(expr.map(|expr| {
+            let r = expr.sets().last().map(|s| s.loc.1).unwrap_or(l.1);
+            Permission {
+                name: name.to_string(),
+                permission: expr.compute(),
+                description: None,
+                loc: (l.0, r),
+            }
+        }))
+    }
+
+    fn exclusion(&mut self) -> Parsed<Option<Relationship>> {
+        let mut expr = self.intersection()?;
+        while self.peek().0 == Token::Symbol("-") {
+            self.next()?;
+            let subtract = self.intersection()?;
+            expr = expr.zip(subtract).map(|(base, subtract)| Relationship::Difference {
+                base: Box::new(base),
+                subtract: Box::new(subtract),
+            });
+        }
+        Ok(expr)
+    }
+
+    fn intersection(&mut self) -> Parsed<Option<Relationship>> {
+        let mut expr = self.union()?;
+        while self.peek().0 == Token::Symbol("&") {
+            self.next()?;
+            let right = self.union()?;
+            expr = expr.zip(right).map(|(l, r)| Relationship::Intersection {
+                children: vec![Box::new(l), Box::new(r)],
+            });
+        }
+        Ok(expr)
+    }
+
+    fn union(&mut self) -> Parsed<Option<Relationship>> {
+        let mut expr = self.arrow()?;
+        while self.peek().0 == Token::Symbol("+") {
+            self.next()?;
+            let right = self.arrow()?;
+            expr = expr.zip(right).map(|(l, r)| Relationship::Union {
+                children: vec![Box::new(l), Box::new(r)],
+            });
+        }
+        Ok(expr)
+    }
+
+    /// `viewer`, `parent->view`, `parent.any(view)`, `nil` or a parenthesized expression
+    fn arrow(&mut self) -> Parsed<Option<Relationship>> {
+        if self.peek().0 == Token::Symbol("(") {
+            self.next()?;
+            let expr = self.exclusion()?;
+            self.expect(")")?;
+            return Ok(expr);
+        }
+        let (name, l) = self.identifier()?;
+        if name == "nil" {
+            self.unsupported(l, "`nil`".to_string());
+            return Ok(None);
+        }
+        match self.peek().0 {
+            Token::Symbol("->") => {
+                self.next()?;
+                let (computed, r) = self.identifier()?;
+                Ok(Some(Relationship::Set(RelationshipSet::new(
+                    SetKind::Set(name.to_string(), computed.to_string()),
+                    (l.0, r.1),
+                ))))
+            }
+            Token::Symbol(".") => {
+                self.next()?;
+                let (function, _) = self.identifier()?;
+                self.expect("(")?;
+                let (computed, _) = self.identifier()?;
+                let r = self.expect(")")?;
+                match function {
+                    "any" => Ok(Some(Relationship::Set(RelationshipSet::new(
+                        SetKind::Set(name.to_string(), computed.to_string()),
+                        (l.0, r.1),
+                    )))),
+                    _ => {
+                        self.unsupported((l.0, r.1), format!("the arrow function `{}`", function));
+                        Ok(None)
+                    }
+                }
+            }
+            _ => Ok(Some(Relationship::Set(RelationshipSet::new(
+                SetKind::Single(name.to_string()),
+                l,
+            )))),
+        }
+    }
+
+    /// `caveat name(p1 int, p2 list<string>) { cel expression }`
+    fn caveat(&mut self, l: Loc) -> Parsed<Option<Condition>> {
+        let (name, loc) = self.identifier()?;
+        let mut representable = self.representable(name, loc);
+        self.expect("(")?;
+        let mut args = vec![];
+        loop {
+            let (arg, _) = self.identifier()?;
+            match self.parameter_type()? {
+                Some(r#type) => args.push(ConditionArg {
+                    name: arg.to_string(),
+                    r#type,
+                }),
+                None => representable = false,
+            }
+            match self.next()? {
+                (Token::Symbol(","), _) => {}
+                (Token::Symbol(")"), _) => break,
+                (token, loc) => return self.syntax_error(token, loc, "`,` or `)`"),
+            }
+        }
+        self.expect("{")?;
+        let (body, r) = self.block()?;
+        if !representable {
+            return Ok(None);
+        }
+        match parse_condition(name, args, body) {
+            Ok(mut cond) => {
+                cond.loc = (l.0, r.1);
+                Ok(representable.then_some(cond))
+            }
+            Err(err) => {
+                let loc = (l.0, r.1);
+                self.errors
+                    .push(Diagnostic::new(Some(loc), format!("caveat `{}`: {}", name, err)));
+                Ok(None)
+            }
+        }
+    }
+
+    /// `None` once the type was reported as unsupported
+    fn parameter_type(&mut self) -> Parsed<Option<ConditionType>> {
+        let (name, loc) = self.identifier()?;
+        let generic = if self.peek().0 == Token::Symbol("<") {
+            self.next()?;
+            let generic = self.parameter_type()?;
+            self.expect(">")?;
+            Some(generic)
+        } else {
+            None
+        };
+        Ok(match (name, generic) {
+            ("int", None) => Some(ConditionType::Int),
+            ("uint", None) => Some(ConditionType::Uint),
+            ("double", None) => Some(ConditionType::Double),
+            ("bool", None) => Some(ConditionType::Bool),
+            ("bytes", None) => Some(ConditionType::Bytes),
+            ("string", None) => Some(ConditionType::String),
+            ("duration", None) => Some(ConditionType::Duration),
+            ("timestamp", None) => Some(ConditionType::Timestamp),
+            ("any", None) => Some(ConditionType::Any),
+            ("ipaddress", None) => Some(ConditionType::IPaddress),
+            ("list", Some(generic)) => generic.map(|t| ConditionType::List(Box::new(t))),
+            ("map", Some(generic)) => generic.map(|t| ConditionType::Map(Box::new(t))),
+            _ => {
+                self.unsupported(loc, format!("the parameter type `{}`", name));
+                None
+            }
+        })
+    }
+
+    /// the raw source up to the `}` closing an already consumed `{`
+    fn block(&mut self) -> Parsed<(&'a str, Loc)> {
+        let start = self.pos;
+        let mut depth = 0;
+        let mut in_string = None;
+        let mut chars = self.src[start..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match (in_string, c) {
+                (Some(_), '\\') => {
+                    chars.next();
+                }
+                (Some(quote), c) if c == quote => in_string = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => in_string = Some(c),
+                (None, '{') => depth += 1,
+                (None, '}') if depth == 0 => {
+                    let end = start + i;
+                    self.pos = end + 1;
+                    return Ok((&self.src[start..end], (end, end + 1)));
+                }
+                (None, '}') => depth -= 1,
+                _ => {}
+            }
+        }
+        self.pos = self.src.len();
+        self.syntax_error(Token::Eof, (self.pos, self.pos), "`}`")
+    }
+
+    /// SpiceDB allows a namespace prefix in the names of definitions and caveats, `org/user`
+    fn representable(&mut self, name: &str, loc: Loc) -> bool {
+        if name.contains('/') {
+            self.unsupported(loc, format!("the namespaced name `{}`", name));
+            return false;
+        }
+        true
+    }
+
+    fn identifier(&mut self) -> Parsed<(&'a str, Loc)> {
+        match self.next()? {
+            (Token::Identifier(id), loc) => Ok((id, loc)),
+            (token, loc) => self.syntax_error(token, loc, "identifier"),
+        }
+    }
+
+    fn expect(&mut self, symbol: &'static str) -> Parsed<Loc> {
+        match self.next()? {
+            (Token::Symbol(s), loc) if s == symbol => Ok(loc),
+            (token, loc) => self.syntax_error(token, loc, &format!("`{}`", symbol)),
+        }
+    }
+
+    fn unsupported(&mut self, loc: Loc, what: String) {
+        self.errors.push(Diagnostic::new(
+            Some(loc),
+            format!("{} cannot be represented in a model", what),
+        ));
+    }
+
+    fn syntax_error<T>(&mut self, token: Token, loc: Loc, expected: &str) -> Parsed<T> {
+        self.errors.push(Diagnostic::new(
+            Some(loc),
+            format!("unexpected {}, expected {}", token, expected),
+        ));
+        Err(SyntaxError)
+    }
+
+    fn next(&mut self) -> Parsed<(Token<'a>, Loc)> {
+        let (token, loc) = self.peek();
+        self.pos = loc.1;
+        if let Token::Unknown(c) = token {
+            return self.syntax_error(token, loc, &format!("anything but `{}`", c));
+        }
+        Ok((token, loc))
+    }
+
+    fn peek(&mut self) -> (Token<'a>, Loc) {
+        self.skip_trivia();
+        let rest = &self.src[self.pos..];
+        let start = self.pos;
+        let Some(c) = rest.chars().next() else {
+            return (Token::Eof, (start, start));
+        };
+        if c.is_ascii_alphabetic() || c == '_' {
+            let mut len = identifier_len(rest);
+            // `org/user`
+            if rest[len..].starts_with('/') && identifier_len(&rest[len + 1..]) > 0 {
+                len += 1 + identifier_len(&rest[len + 1..]);
+            }
+            return (Token::Identifier(&rest[..len]), (start, start + len));
+        }
+        match SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+            Some(s) => (Token::Symbol(s), (start, start + s.len())),
+            None => (Token::Unknown(c), (start, start + c.len_utf8())),
+        }
+    }
+
+    /// whitespace, `// line` and `/* block */` comments, doc comments included
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = &self.src[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                self.pos += comment.find("*/").map(|i| i + 4).unwrap_or(trimmed.len());
+            } else {
+                return;
+            }
+        }
+    }
+}
+
+fn identifier_len(s: &str) -> usize {
+    s.char_indices()
+        .find(|(i, c)| !(c.is_ascii_alphanumeric() || *c == '_') || (*i == 0 && c.is_ascii_digit()))
+        .map(|(i, _)| i)
+        .unwrap_or(s.len())
+}
@@ -4,12 +4,12 @@ use crate::*;
 fn test_relationship_compute_1() {
     let source = Relationship::Union {
         children: vec![
-            Box::new(Relationship::Set(RelationshipSet::Single("x".to_string()))),
+            Box::new(Relationship::Set(RelationshipSet::single("x".to_string()))),
             Box::new(Relationship::Union {
                 children: vec![
-                    Box::new(Relationship::Set(RelationshipSet::Single("y".to_string()))),
+                    Box::new(Relationship::Set(RelationshipSet::single("y".to_string()))),
                     Box::new(Relationship::Union {
-                        children: vec![Box::new(Relationship::Set(RelationshipSet::Single("z".to_string())))],
+                        children: vec![Box::new(Relationship::Set(RelationshipSet::single("z".to_string())))],
                     }),
                 ],
             }),
@@ -19,9 +19,9 @@ fn test_relationship_compute_1() {
         source.compute(),
         Relationship::Union {
             children: vec![
-                Box::new(Relationship::Set(RelationshipSet::Single("x".to_string()))),
-                Box::new(Relationship::Set(RelationshipSet::Single("y".to_string()))),
-                Box::new(Relationship::Set(RelationshipSet::Single("z".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("x".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("y".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("z".to_string()))),
             ]
         }
     )
@@ -31,18 +31,18 @@ fn test_relationship_compute_1() {
 fn test_relationship_compute_2() {
     let source = Relationship::Union {
         children: vec![
-            Box::new(Relationship::Set(RelationshipSet::Single("x".to_string()))),
+            Box::new(Relationship::Set(RelationshipSet::single("x".to_string()))),
             Box::new(Relationship::Union {
                 children: vec![
-                    Box::new(Relationship::Set(RelationshipSet::Single("y".to_string()))),
+                    Box::new(Relationship::Set(RelationshipSet::single("y".to_string()))),
                     Box::new(Relationship::Intersection {
                         children: vec![
-                            Box::new(Relationship::Set(RelationshipSet::Single("a".to_string()))),
-                            Box::new(Relationship::Set(RelationshipSet::Single("b".to_string()))),
+                            Box::new(Relationship::Set(RelationshipSet::single("a".to_string()))),
+                            Box::new(Relationship::Set(RelationshipSet::single("b".to_string()))),
                         ],
                     }),
                     Box::new(Relationship::Union {
-                        children: vec![Box::new(Relationship::Set(RelationshipSet::Single("z".to_string())))],
+                        children: vec![Box::new(Relationship::Set(RelationshipSet::single("z".to_string())))],
                     }),
                 ],
             }),
@@ -52,15 +52,15 @@ fn test_relationship_compute_2() {
         source.compute(),
         Relationship::Union {
             children: vec![
-                Box::new(Relationship::Set(RelationshipSet::Single("x".to_string()))),
-                Box::new(Relationship::Set(RelationshipSet::Single("y".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("x".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("y".to_string()))),
                 Box::new(Relationship::Intersection {
                     children: vec![
-                        Box::new(Relationship::Set(RelationshipSet::Single("a".to_string()))),
-                        Box::new(Relationship::Set(RelationshipSet::Single("b".to_string()))),
+                        Box::new(Relationship::Set(RelationshipSet::single("a".to_string()))),
+                        Box::new(Relationship::Set(RelationshipSet::single("b".to_string()))),
                     ],
                 }),
-                Box::new(Relationship::Set(RelationshipSet::Single("z".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("z".to_string()))),
             ]
         }
     )
@@ -71,24 +71,24 @@ fn test_relationship_compute_3() {
     let source = Relationship::Difference {
         base: Box::new(Relationship::Union {
             children: vec![
-                Box::new(Relationship::Set(RelationshipSet::Single("x".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::single("x".to_string()))),
                 Box::new(Relationship::Union {
                     children: vec![
-                        Box::new(Relationship::Set(RelationshipSet::Single("y".to_string()))),
+                        Box::new(Relationship::Set(RelationshipSet::single("y".to_string()))),
                         Box::new(Relationship::Intersection {
                             children: vec![
-                                Box::new(Relationship::Set(RelationshipSet::Single("a".to_string()))),
-                                Box::new(Relationship::Set(RelationshipSet::Single("b".to_string()))),
+                                Box::new(Relationship::Set(RelationshipSet::single("a".to_string()))),
+                                Box::new(Relationship::Set(RelationshipSet::single("b".to_string()))),
                             ],
                         }),
                         Box::new(Relationship::Union {
-                            children: vec![Box::new(Relationship::Set(RelationshipSet::Single("z".to_string())))],
+                            children: vec![Box::new(Relationship::Set(RelationshipSet::single("z".to_string())))],
                         }),
                     ],
                 }),
             ],
         }),
-        subtract: Box::new(Relationship::Set(RelationshipSet::Single("c".to_string()))),
+        subtract: Box::new(Relationship::Set(RelationshipSet::single("c".to_string()))),
     };
 
     assert_eq!(
@@ -96,18 +96,18 @@ fn test_relationship_compute_3() {
         Relationship::Difference {
             base: Box::new(Relationship::Union {
                 children: vec![
-                    Box::new(Relationship::Set(RelationshipSet::Single("x".to_string()))),
-                    Box::new(Relationship::Set(RelationshipSet::Single("y".to_string()))),
+                    Box::new(Relationship::Set(RelationshipSet::single("x".to_string()))),
+                    Box::new(Relationship::Set(RelationshipSet::single("y".to_string()))),
                     Box::new(Relationship::Intersection {
                         children: vec![
-                            Box::new(Relationship::Set(RelationshipSet::Single("a".to_string()))),
-                            Box::new(Relationship::Set(RelationshipSet::Single("b".to_string()))),
+                            Box::new(Relationship::Set(RelationshipSet::single("a".to_string()))),
+                            Box::new(Relationship::Set(RelationshipSet::single("b".to_string()))),
                         ],
                     }),
-                    Box::new(Relationship::Set(RelationshipSet::Single("z".to_string()))),
+                    Box::new(Relationship::Set(RelationshipSet::single("z".to_string()))),
                 ]
             }),
-            subtract: Box::new(Relationship::Set(RelationshipSet::Single("c".to_string())))
+            subtract: Box::new(Relationship::Set(RelationshipSet::single("c".to_string())))
         }
     )
 }
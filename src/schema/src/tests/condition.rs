@@ -0,0 +1,153 @@
+use crate::*;
+
+fn ident(name: &str) -> ConditionExpression {
+    ConditionExpression::new(ExpressionKind::Identifier(name.to_string()), (0, 0))
+}
+
+fn int(i: i64) -> ConditionExpression {
+    ConditionExpression::new(ExpressionKind::Literal(Literal::Int(i)), (0, 0))
+}
+
+#[test]
+fn test_condition_method_call() {
+    let schema = r"condition has_valid_ip(user_ip: ipaddress, allowed_range: string) {
+  user_ip.in_cidr(allowed_range)
+}";
+    let (schema, _) = parse(schema).unwrap();
+    assert_eq!(
+        schema.conditions,
+        vec![Condition {
+            name: String::from("has_valid_ip"),
+            args: vec![
+                ConditionArg {
+                    name: String::from("user_ip"),
+                    r#type: ConditionType::IPaddress,
+                },
+                ConditionArg {
+                    name: String::from("allowed_range"),
+                    r#type: ConditionType::String,
+                },
+            ],
+            body: ConditionExpression::new(
+                ExpressionKind::MethodCall {
+                    receiver: Box::new(ident("user_ip")),
+                    method: String::from("in_cidr"),
+                    args: vec![ident("allowed_range")],
+                },
+                (0, 0)
+            ),
+            loc: (0, 0),
+        }]
+    );
+    assert_eq!(schema.conditions[0].body.loc, (70, 100));
+}
+
+#[test]
+fn test_condition_precedence() {
+    let schema = r"condition c(a: int, b: list<string>, c: bool) {
+  a + 1 * 2 >= 3 && b.contains('x') || !c
+}";
+    let (schema, _) = parse(&schema.replace('\'', "\"")).unwrap();
+    let contains = ConditionExpression::new(
+        ExpressionKind::MethodCall {
+            receiver: Box::new(ident("b")),
+            method: String::from("contains"),
+            args: vec![ConditionExpression::new(
+                ExpressionKind::Literal(Literal::String(String::from("x"))),
+                (0, 0),
+            )],
+        },
+        (0, 0),
+    );
+    let sum = ConditionExpression::binary(
+        BinaryOperator::Add,
+        ident("a"),
+        ConditionExpression::binary(BinaryOperator::Mul, int(1), int(2), (0, 0)),
+        (0, 0),
+    );
+    assert_eq!(
+        schema.conditions[0].body,
+        ConditionExpression::binary(
+            BinaryOperator::Or,
+            ConditionExpression::binary(
+                BinaryOperator::And,
+                ConditionExpression::binary(BinaryOperator::Ge, sum, int(3), (0, 0)),
+                contains,
+                (0, 0)
+            ),
+            ConditionExpression::unary(UnaryOperator::Not, ident("c"), (0, 0)),
+            (0, 0)
+        )
+    );
+}
+
+#[test]
+fn test_condition_timestamp() {
+    let schema = r#"condition not_expired(grant_time: timestamp, current_time: timestamp, grant_duration: duration) {
+  current_time < grant_time + grant_duration &&
+    current_time - grant_time <= duration("1h")
+}"#;
+    assert!(parse(schema).is_ok());
+}
+
+fn type_errors(dsl: &str) -> Vec<(Loc, String)> {
+    parse(dsl)
+        .unwrap_err()
+        .into_iter()
+        .map(|d| (d.loc.unwrap(), d.message))
+        .collect()
+}
+
+#[test]
+fn test_condition_type_errors() {
+    let schema = r"condition c(a: int, s: string) {
+  a + s > 1 && missing
+}";
+    let errors = type_errors(schema);
+    assert_eq!(
+        errors,
+        vec![
+            (
+                (35, 40),
+                String::from("condition `c`: cannot apply `+` to int and string")
+            ),
+            ((48, 55), String::from("condition `c`: undefined variable `missing`")),
+        ]
+    );
+
+    let schema = r"condition c(a: int) {
+  a.in_cidr('10.0.0.0/8') || a
+}";
+    let errors = type_errors(&schema.replace('\'', "\""));
+    assert_eq!(
+        errors,
+        vec![((24, 47), String::from("condition `c`: no method `in_cidr` on int"))]
+    );
+
+    let schema = r"condition c(a: int) {
+  a + 1
+}";
+    let errors = type_errors(schema);
+    assert_eq!(
+        errors,
+        vec![(
+            (24, 29),
+            String::from("condition `c`: mismatched types: expected bool, found int")
+        )]
+    );
+}
+
+#[test]
+fn test_condition_body_layout() {
+    let expected = ConditionExpression::binary(BinaryOperator::Gt, ident("a"), int(1), (0, 0));
+    for dsl in [
+        "condition c(a: int) {a > 1}",
+        "condition c(a: int) {\n\n  a > 1\n\n\n}",
+        "condition c(a: int) {\n  // the lower bound\n  a > 1\n}",
+        "condition c(a: int) { // the lower bound\n  a > 1 // exclusive\n  // nothing else\n\n}",
+        "condition c(a: int) {\r\n  a > 1\r\n\r\n}",
+    ] {
+        let (schema, _) = parse(dsl).unwrap_or_else(|errors| panic!("{:?}: {:?}", dsl, errors));
+        assert_eq!(schema.conditions[0].body, expected, "{:?}", dsl);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::*;
+
+fn changes(old: &str, new: &str) -> Vec<Change> {
+    let (old, _) = parse(old).unwrap();
+    let (new, _) = parse(new).unwrap();
+    diff(&old, &new)
+}
+
+fn change(kind: ChangeKind, impact: Impact, target: &str, from: Option<String>, to: Option<String>) -> Change {
+    Change {
+        kind,
+        impact,
+        target: target.to_string(),
+        from,
+        to,
+    }
+}
+
+fn s(s: &str) -> Option<String> {
+    Some(s.to_string())
+}
+
+#[test]
+fn test_diff_unchanged() {
+    let model = r"type user {}
+type doc {
+  relation viewer: user
+  permission view: viewer
+}";
+    assert_eq!(changes(model, model), vec![]);
+}
+
+#[test]
+fn test_diff() {
+    let old = r"type user {}
+type team {}
+type doc {
+  relation owner: user
+  relation viewer: user | team
+  relation editor: user
+  permission view: viewer
+  permission edit: editor
+  permission share: owner
+}
+condition c(a: int) {
+  a > 1
+}";
+    let new = r"type user {}
+type group {
+  relation member: user
+}
+type doc {
+  relation owner: user
+  relation viewer: user | group#member
+  permission view: viewer + owner
+  permission edit: owner
+  permission delete: owner
+}
+condition c(a: int) {
+  a > 2
+}
+condition d(a: int) {
+  a > 1
+}";
+    assert_eq!(
+        changes(old, new),
+        vec![
+            change(ChangeKind::TypeRemoved, Impact::Breaking, "team", None, None),
+            change(
+                ChangeKind::DirectTypeRemoved,
+                Impact::Breaking,
+                "doc#viewer",
+                s("team"),
+                None
+            ),
+            change(
+                ChangeKind::DirectTypeAdded,
+                Impact::Additive,
+                "doc#viewer",
+                None,
+                s("group#member")
+            ),
+            change(
+                ChangeKind::RelationRemoved,
+                Impact::Breaking,
+                "doc#editor",
+                s("relation editor: user"),
+                None
+            ),
+            change(
+                ChangeKind::PermissionChanged,
+                Impact::Additive,
+                "doc#view",
+                s("viewer"),
+                s("viewer + owner")
+            ),
+            change(
+                ChangeKind::PermissionChanged,
+                Impact::Breaking,
+                "doc#edit",
+                s("editor"),
+                s("owner")
+            ),
+            change(
+                ChangeKind::PermissionRemoved,
+                Impact::Breaking,
+                "doc#share",
+                s("owner"),
+                None
+            ),
+            change(
+                ChangeKind::PermissionAdded,
+                Impact::Additive,
+                "doc#delete",
+                None,
+                s("owner")
+            ),
+            change(ChangeKind::TypeAdded, Impact::Additive, "group", None, None),
+            change(
+                ChangeKind::RelationAdded,
+                Impact::Additive,
+                "group#member",
+                None,
+                s("relation member: user")
+            ),
+            change(
+                ChangeKind::ConditionChanged,
+                Impact::Breaking,
+                "c",
+                s("condition c(a: int) { a > 1 }"),
+                s("condition c(a: int) { a > 2 }")
+            ),
+            change(
+                ChangeKind::ConditionAdded,
+                Impact::Additive,
+                "d",
+                None,
+                s("condition d(a: int) { a > 1 }")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_diff_superadmin() {
+    let model = r"type user {}
+type system {
+  relation admin: user
+  relation owner: user
+}";
+    assert_eq!(
+        changes(
+            &format!("superadmin system#admin\n{}", model),
+            &format!("superadmin system#owner\n{}", model)
+        ),
+        vec![
+            change(
+                ChangeKind::SuperadminRemoved,
+                Impact::Breaking,
+                "system#admin",
+                None,
+                None
+            ),
+            change(
+                ChangeKind::SuperadminAdded,
+                Impact::Additive,
+                "system#owner",
+                None,
+                None
+            ),
+        ]
+    );
+}
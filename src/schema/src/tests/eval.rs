@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::*;
+
+fn condition(dsl: &str) -> Condition {
+    let (schema, _) = parse(dsl).unwrap();
+    schema.conditions.into_iter().next().unwrap()
+}
+
+fn context(value: Value) -> HashMap<String, Value> {
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn test_eval_ipaddress() {
+    let cond = condition(
+        r"condition has_valid_ip(user_ip: ipaddress, allowed_range: string) {
+  user_ip.in_cidr(allowed_range)
+}",
+    );
+    let allow = |ip: &str, range: &str| cond.evaluate(&context(json!({"user_ip": ip, "allowed_range": range})));
+    assert_eq!(allow("192.168.0.1", "192.168.0.0/24"), Ok(true));
+    assert_eq!(allow("192.168.1.1", "192.168.0.0/24"), Ok(false));
+    assert_eq!(allow("10.1.2.3", "0.0.0.0/0"), Ok(true));
+    assert_eq!(allow("2001:db8::1", "2001:db8::/32"), Ok(true));
+    assert_eq!(allow("2001:db9::1", "2001:db8::/32"), Ok(false));
+    assert!(allow("192.168.0.1", "192.168.0.0/33").is_err());
+    assert!(cond
+        .evaluate(&context(json!({"user_ip": "not-an-ip", "allowed_range": "0.0.0.0/0"})))
+        .is_err());
+}
+
+#[test]
+fn test_eval_timestamp_duration() {
+    let cond = condition(
+        r"condition not_expired(grant_time: timestamp, current_time: timestamp, grant_duration: duration) {
+  current_time < grant_time + grant_duration
+}",
+    );
+    let allow = |now: &str| {
+        cond.evaluate(&context(json!({
+            "grant_time": "2024-01-01T00:00:00Z",
+            "current_time": now,
+            "grant_duration": "1h30m",
+        })))
+    };
+    assert_eq!(allow("2024-01-01T01:29:59Z"), Ok(true));
+    assert_eq!(allow("2024-01-01T01:30:00Z"), Ok(false));
+    assert_eq!(allow("2024-01-01T02:00:00+01:00"), Ok(true));
+
+    assert_eq!(parse_duration("1h30m"), chrono::Duration::try_minutes(90));
+    assert_eq!(parse_duration("-1.5s"), chrono::Duration::try_milliseconds(-1500));
+    assert_eq!(parse_duration("10"), None);
+    assert_eq!(parse_duration("1d"), None);
+}
+
+#[test]
+fn test_eval_list_map() {
+    let cond = condition(
+        r#"condition c(roles: list<string>, quota: map<int>, role: string) {
+  roles.contains(role) && quota.get(role) > 0 && quota.contains_key("admin")
+}"#,
+    );
+    let ctx = |role: &str| {
+        context(json!({
+            "roles": ["admin", "editor"],
+            "quota": {"admin": 1, "editor": 0},
+            "role": role,
+        }))
+    };
+    assert_eq!(cond.evaluate(&ctx("admin")), Ok(true));
+    assert_eq!(cond.evaluate(&ctx("editor")), Ok(false));
+    assert_eq!(cond.evaluate(&ctx("viewer")), Ok(false));
+}
+
+#[test]
+fn test_eval_arithmetic() {
+    let cond = condition(
+        r#"condition c(a: int, b: uint, d: double) {
+  a * 2 - 1 == 5 && b % 2 == 1 && d / 2 >= 1.25 && int("7") == 7 && string(a) == "3"
+}"#,
+    );
+    assert_eq!(cond.evaluate(&context(json!({"a": 3, "b": 7, "d": 2.5}))), Ok(true));
+    assert_eq!(cond.evaluate(&context(json!({"a": 3, "b": 7, "d": 2.4}))), Ok(false));
+
+    let cond = condition(
+        r"condition c(a: int, b: int) {
+  a / b > 0
+}",
+    );
+    assert_eq!(
+        cond.evaluate(&context(json!({"a": 1, "b": 0}))),
+        Err(ConditionError::Evaluation(String::from("division by zero")))
+    );
+}
+
+#[test]
+fn test_eval_short_circuit() {
+    let cond = condition(
+        r"condition c(a: bool, b: int) {
+  a || 1 / b > 0
+}",
+    );
+    assert_eq!(cond.evaluate(&context(json!({"a": true, "b": 0}))), Ok(true));
+    assert!(cond.evaluate(&context(json!({"a": false, "b": 0}))).is_err());
+}
+
+#[test]
+fn test_eval_parameters() {
+    let cond = condition(
+        r"condition c(a: int) {
+  a > 1
+}",
+    );
+    assert_eq!(
+        cond.evaluate(&HashMap::new()),
+        Err(ConditionError::MissingParameter(String::from("a")))
+    );
+    assert_eq!(
+        cond.evaluate(&context(json!({"a": "2"}))),
+        Err(ConditionError::InvalidParameter {
+            name: String::from("a"),
+            expected: ConditionType::Int,
+        })
+    );
+}
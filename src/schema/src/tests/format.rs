@@ -0,0 +1,240 @@
+use crate::*;
+
+fn assert_round_trip(dsl: &str) -> String {
+    let (schema, _) = parse(dsl).unwrap();
+    let formatted = format(&schema);
+    let (reparsed, _) = parse(&formatted).unwrap();
+    assert_eq!(reparsed, schema, "{}", formatted);
+    assert_eq!(format(&reparsed), formatted);
+    formatted
+}
+
+#[test]
+fn test_format_canonical() {
+    let dsl = r"type user {}
+type group { relation member: user|group#member }
+type folder {
+    permission view: viewer+owner + parent#view - blocked#assignment
+    relation owner: user
+  relation parent: folder
+  relation blocked: block
+    relation viewer: user | user#* | group#member | user ^ in_office
+}
+type block { relation assignment: user }
+condition in_office(ip: ipaddress, cidrs: list<string>, hour: int) {
+  cidrs.contains(string(ip)) && (hour - 1) * 2 >= 10 || !(hour < 3)
+}";
+    assert_eq!(
+        assert_round_trip(dsl),
+        r#"type user {}
+
+type group {
+  relation member: user | group#member
+}
+
+type folder {
+  permission view: viewer + owner + parent->view - blocked->assignment
+  relation owner: user
+  relation parent: folder
+  relation blocked: block
+  relation viewer: user | user#* | group#member | user with in_office
+}
+
+type block {
+  relation assignment: user
+}
+
+condition in_office(ip: ipaddress, cidrs: list<string>, hour: int) {
+  cidrs.contains(string(ip)) && (hour - 1) * 2 >= 10 || !(hour < 3)
+}
+"#
+    );
+}
+
+#[test]
+fn test_format_parentheses() {
+    assert_round_trip(
+        r"type doc {
+  relation a: user
+  relation b: user
+  relation c: user
+  permission p1: a - (b + c)
+  permission p2: (a + b) & c
+  permission p3: a & (b & c) - (a - b)
+  permission p4: a + (b - c) + c
+}
+condition c(a: int, b: double, s: string, d: duration) {
+  -(a + 1) > int(b * 2.0) && !(s.starts_with('x') || a % 2 == 0) && (s + 'y').size() == 2 && d > duration('1h')
+}"
+        .replace('\'', "\"")
+        .as_str(),
+    );
+}
+
+#[test]
+fn test_format_imports() {
+    assert_eq!(
+        assert_round_trip("import \"users.authz\"\nimport \"./groups.authz\"\ntype doc {}"),
+        "import \"users.authz\"\nimport \"./groups.authz\"\n\ntype doc {}\n"
+    );
+}
+
+#[test]
+fn test_format_json_model() {
+    let (schema, _) = parse(
+        include_str!("../../README.md")
+            .split("```")
+            .nth(1)
+            .unwrap()
+            .trim_start_matches("authz"),
+    )
+    .unwrap();
+    let schema: Schema = serde_json::from_value(serde_json::to_value(&schema).unwrap()).unwrap();
+    let (reparsed, _) = parse(&schema.to_string()).unwrap();
+    assert_eq!(reparsed, schema);
+}
+
+#[test]
+fn test_format_comments() {
+    let dsl = r"// model header
+
+// users
+type user {}
+type doc {
+  // owners
+  relation owner: user
+  permission view: owner // trailing
+  // end of doc
+}
+// ip guard
+condition c(a: int) {
+  a > 1
+}
+// bye";
+    let (schema, comments) = parse(dsl).unwrap();
+    assert_eq!(
+        format_with_comments(&schema, &comments, dsl),
+        r"// model header
+// users
+type user {}
+
+type doc {
+  // owners
+  relation owner: user
+  permission view: owner // trailing
+  // end of doc
+}
+
+// ip guard
+condition c(a: int) {
+  a > 1
+}
+// bye
+"
+    );
+}
+
+#[test]
+fn test_format_trailing_comments() {
+    let dsl = r#"import "users.authz" // users
+
+type doc { // docs
+  relation owner: user // owners
+  permission view: owner
+
+  // readers
+} // end of doc
+
+condition c(a: int) { // guard
+  a > 1 // a
+}
+"#;
+    let (schema, comments) = parse(dsl).unwrap();
+    let formatted = format_with_comments(&schema, &comments, dsl);
+    assert_eq!(
+        formatted,
+        r#"import "users.authz" // users
+
+type doc { // docs
+  relation owner: user // owners
+  permission view: owner
+  // readers
+} // end of doc
+
+condition c(a: int) { // guard
+  a > 1 // a
+}
+"#
+    );
+    let (reparsed, comments) = parse(&formatted).unwrap();
+    assert_eq!(format_with_comments(&reparsed, &comments, &formatted), formatted);
+}
+
+#[test]
+fn test_format_literals() {
+    let formatted = assert_round_trip(
+        r#"condition c(d: double, s: string) {
+  d > 100000000000000000000.0 && d < 0.000001 && d != 2.50 && s != "say \"hi\" \\o/" && s != "C:\dir"
+}"#,
+    );
+    assert!(
+        formatted.contains(r#"d > 100000000000000000000.0 && d < 0.000001 && d != 2.5"#),
+        "{}",
+        formatted
+    );
+    assert!(
+        formatted.contains(r#"s != "say \"hi\" \\o/" && s != "C:\\dir""#),
+        "{}",
+        formatted
+    );
+
+    for (dsl, value) in [(r#""say \"hi\" \\o/""#, r#"say "hi" \o/"#), (r#""C:\dir""#, r"C:\dir")] {
+        let (schema, _) = parse(&format!("condition c(s: string) {{\n  s == {}\n}}", dsl)).unwrap();
+        let ExpressionKind::Binary { right, .. } = &schema.conditions[0].body.kind else {
+            panic!("{:?}", schema.conditions[0].body);
+        };
+        assert_eq!(right.kind, ExpressionKind::Literal(Literal::String(value.to_owned())));
+    }
+}
+
+#[test]
+fn test_format_descriptions() {
+    let dsl = r"/// A person.
+type user {}
+
+// owners and readers
+/// Documents of the drive.
+///
+/// Shared by links.
+type doc {
+  /// who created it
+  relation owner: user
+  // readers
+  /// who can read it
+  permission view: owner
+}
+";
+    let (schema, comments) = parse(dsl).unwrap();
+    assert_eq!(format_with_comments(&schema, &comments, dsl), dsl);
+    assert_round_trip(dsl);
+}
+
+#[test]
+fn test_format_superadmin() {
+    let dsl = r"superadmin system#admin
+superadmin system#owner
+
+type user {}
+
+type system {
+  relation admin: user
+  relation owner: user
+}
+";
+    assert_eq!(assert_round_trip(dsl), dsl);
+
+    // models read back from json print superadmins first
+    let (schema, _) = parse(dsl).unwrap();
+    let stored: Schema = serde_json::from_value(serde_json::to_value(&schema).unwrap()).unwrap();
+    assert_eq!(format(&stored), dsl);
+}
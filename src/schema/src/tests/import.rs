@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::*;
+
+fn files(files: &[(&str, &str)]) -> HashMap<String, String> {
+    files
+        .iter()
+        .map(|(name, source)| (name.to_string(), source.to_string()))
+        .collect()
+}
+
+fn compose(entry: &str, files: &HashMap<String, String>) -> Result<(Schema, SourceMap), Vec<Diagnostic>> {
+    parse_with_imports("main.authz", entry, |path| {
+        files.get(path).cloned().ok_or_else(|| "not found".to_string())
+    })
+}
+
+#[test]
+fn test_import() {
+    let files = files(&[
+        ("users.authz", "type user {}\ntype group {\n  relation member: user\n}"),
+        (
+            "docs.authz",
+            "import \"users.authz\"\ntype doc {\n  relation viewer: user | group#member\n}",
+        ),
+    ]);
+    let (schema, map) = compose(
+        "import \"./docs.authz\"\nimport \"users.authz\"\ntype folder {}",
+        &files,
+    )
+    .unwrap();
+    assert_eq!(
+        schema.types.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+        vec!["folder", "doc", "user", "group"]
+    );
+    assert!(schema.imports.is_empty());
+    assert!(schema.warnings.is_empty());
+    assert_eq!(
+        map.files().collect::<Vec<_>>(),
+        vec!["main.authz", "docs.authz", "users.authz"]
+    );
+    assert_eq!(validate(&schema), vec![]);
+}
+
+#[test]
+fn test_parse_import_warning() {
+    let (schema, _) = parse("import \"users.authz\"\ntype doc {}").unwrap();
+    assert_eq!(schema.imports.len(), 1);
+    assert_eq!(
+        schema.warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>(),
+        vec![
+            "1:1: `users.authz` is not imported, only `parse_with_imports` composes the imported files\n1 | import \"users.authz\"\n  | ^^^^^^^^^^^^^^^^^^^^"
+        ]
+    );
+}
+
+#[test]
+fn test_import_errors() {
+    let files = files(&[
+        ("users.authz", "type user {}\ntype doc {}"),
+        ("broken.authz", "type user {\n  relation : user\n}"),
+    ]);
+    let errors = compose(
+        "import \"users.authz\"\nimport \"broken.authz\"\nimport \"missing.authz\"\ntype doc {}",
+        &files,
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec![
+            "main.authz:3:1: cannot import `missing.authz`: not found\n3 | import \"missing.authz\"\n  | ^^^^^^^^^^^^^^^^^^^^^^",
+            "users.authz:2:1: duplicate type `doc`, already defined in `main.authz`\n2 | type doc {}\n  | ^^^^^^^^^^^",
+            "broken.authz:2:12: unexpected `:`, expected identifier\n2 |   relation : user\n  |            ^",
+        ]
+    );
+}
+
+#[test]
+fn test_import_locate() {
+    let files = files(&[("users.authz", "type user {}\ntype group {\n  relation member: usr\n}")]);
+    let (schema, map) = compose("import \"users.authz\"\ntype doc {\n  relation viewer: team\n}", &files).unwrap();
+    let errors: Vec<_> = validate(&schema)
+        .into_iter()
+        .map(|d| map.locate(d).to_string())
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            "main.authz:3:20: undefined type `team` in relation `doc.viewer`\n3 |   relation viewer: team\n  |                    ^^^^",
+            "users.authz:3:20: undefined type `usr` in relation `group.member`\n3 |   relation member: usr\n  |                    ^^^",
+        ]
+    );
+}
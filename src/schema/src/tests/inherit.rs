@@ -0,0 +1,131 @@
+use crate::*;
+
+const MODEL: &str = r"type user {}
+
+mixin owned {
+  relation owner: user
+  permission can_write: owner
+}
+
+mixin shared extends owned {
+  relation viewer: user
+  permission can_read: viewer + owner
+}
+
+type folder extends shared {}
+
+type doc extends shared {
+  relation parent: folder
+  permission can_read: viewer + owner + parent->can_read
+}
+";
+
+fn messages(dsl: &str) -> Vec<String> {
+    let (schema, _) = parse(dsl).unwrap();
+    validate(&schema).into_iter().map(|d| d.message).collect()
+}
+
+fn members(typ: &Type) -> Vec<String> {
+    let relations = typ.relations.iter().map(|r| r.to_string());
+    relations.chain(typ.permissions.iter().map(|p| p.to_string())).collect()
+}
+
+#[test]
+fn test_flatten() {
+    let (schema, _) = parse(MODEL).unwrap();
+    assert_eq!(schema.mixins.len(), 2);
+    assert_eq!(schema.types[2].extends, vec!["shared"]);
+    assert!(validate(&schema).is_empty());
+
+    let flat = flatten(&schema);
+    assert!(flat.mixins.is_empty());
+    assert_eq!(
+        flat.types.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+        vec!["user", "folder", "doc"]
+    );
+    assert_eq!(
+        members(&flat.types[1]),
+        vec![
+            "relation owner: user",
+            "relation viewer: user",
+            "permission can_write: owner",
+            "permission can_read: viewer + owner",
+        ]
+    );
+    // the permission declared by doc overrides the inherited one
+    assert_eq!(
+        members(&flat.types[2]),
+        vec![
+            "relation owner: user",
+            "relation viewer: user",
+            "relation parent: folder",
+            "permission can_write: owner",
+            "permission can_read: viewer + owner + parent->can_read",
+        ]
+    );
+    assert!(flat.types[2].extends.is_empty());
+
+    let typesystem = schema.to_typesystem();
+    assert!(!typesystem.types.contains_key("shared"));
+    assert!(typesystem.get_relation("doc", "owner").is_ok());
+    assert!(typesystem.types["folder"].relations.contains_key("can_write"));
+}
+
+#[test]
+fn test_flatten_diamond() {
+    let dsl = r"type user {}
+mixin owned {
+  relation owner: user
+}
+mixin a extends owned {
+  relation editor: user
+}
+mixin b extends owned {
+  relation editor: user | user#*
+}
+type doc extends a, b {}";
+    assert_eq!(
+        messages(dsl),
+        vec!["`editor` is inherited from both `a` and `b` by `doc`, declare it to override them"]
+    );
+    // owner reaches doc twice with the same definition, overriding editor settles the conflict
+    let dsl = dsl.replace(
+        "type doc extends a, b {}",
+        "type doc extends a, b {\n  relation editor: user\n}",
+    );
+    let (schema, _) = parse(&dsl).unwrap();
+    assert!(validate(&schema).is_empty());
+    assert_eq!(
+        members(&flatten(&schema).types[1]),
+        vec!["relation owner: user", "relation editor: user"]
+    );
+}
+
+#[test]
+fn test_flatten_errors() {
+    let dsl = r"type user {}
+mixin a extends b {}
+mixin b extends a {}
+mixin user {}
+type doc extends a, missing {
+  permission view: owner
+}";
+    assert_eq!(
+        messages(dsl),
+        vec![
+            "duplicate mixin `user`",
+            "cyclic extends `a -> b -> a`",
+            "undefined type or mixin `missing` extended by `doc`",
+            "undefined relation `owner` in permission `doc.view`",
+        ]
+    );
+}
+
+#[test]
+fn test_format_extends() {
+    let (schema, _) = parse(MODEL).unwrap();
+    assert_eq!(format(&schema), MODEL);
+
+    let stored: Schema = serde_json::from_value(serde_json::to_value(&schema).unwrap()).unwrap();
+    assert_eq!(stored, schema);
+}
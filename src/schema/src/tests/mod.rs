@@ -1,2 +1,11 @@
 mod ast;
+mod condition;
+mod diff;
+mod eval;
+mod format;
+mod import;
+mod inherit;
+mod openfga;
 mod parser;
+mod spicedb;
+mod validate;
@@ -0,0 +1,233 @@
+use serde_json::json;
+
+use crate::{openfga::*, *};
+
+const DSL: &str = r"type user {}
+type group {
+  relation member: user
+}
+type folder {
+  relation blocked: user
+  relation owner: user
+  relation parent: folder
+  relation viewer: user | user#* | group#member | user with in_office
+  permission edit: owner & viewer
+  permission view: viewer + owner + parent->view - blocked
+}
+condition in_office(cidr: string, ip: ipaddress) {
+  ip.in_cidr(cidr)
+}";
+
+fn import(model: serde_json::Value) -> Result<Schema, Vec<String>> {
+    let model: AuthorizationModel = serde_json::from_value(model).unwrap();
+    from_openfga(&model).map_err(|errors| errors.into_iter().map(|e| e.message).collect())
+}
+
+fn direct(types: serde_json::Value) -> serde_json::Value {
+    json!({ "directly_related_user_types": types })
+}
+
+#[test]
+fn test_from_openfga() {
+    let model = json!({
+        "id": "01HVMMBCMGZNT3SED4Z17ECXCA",
+        "schema_version": "1.1",
+        "type_definitions": [
+            { "type": "user", "relations": {}, "metadata": null },
+            {
+                "type": "group",
+                "relations": { "member": { "this": {} } },
+                "metadata": { "relations": { "member": direct(json!([{ "type": "user" }])) } }
+            },
+            {
+                "type": "folder",
+                "relations": {
+                    "owner": { "this": {} },
+                    "parent": { "this": {} },
+                    "viewer": { "this": {} },
+                    "blocked": { "this": {} },
+                    "view": {
+                        "difference": {
+                            "base": {
+                                "union": {
+                                    "child": [
+                                        { "computedUserset": { "object": "", "relation": "viewer" } },
+                                        { "computedUserset": { "relation": "owner" } },
+                                        {
+                                            "tupleToUserset": {
+                                                "tupleset": { "object": "", "relation": "parent" },
+                                                "computedUserset": { "object": "", "relation": "view" }
+                                            }
+                                        }
+                                    ]
+                                }
+                            },
+                            "subtract": { "computedUserset": { "relation": "blocked" } }
+                        }
+                    },
+                    "edit": {
+                        "intersection": {
+                            "child": [
+                                { "computedUserset": { "relation": "owner" } },
+                                { "computedUserset": { "relation": "viewer" } }
+                            ]
+                        }
+                    }
+                },
+                "metadata": {
+                    "relations": {
+                        "owner": direct(json!([{ "type": "user" }])),
+                        "parent": direct(json!([{ "type": "folder" }])),
+                        "viewer": direct(json!([
+                            { "type": "user" },
+                            { "type": "user", "wildcard": {} },
+                            { "type": "group", "relation": "member" },
+                            { "type": "user", "condition": "in_office" }
+                        ])),
+                        "blocked": direct(json!([{ "type": "user" }]))
+                    }
+                }
+            }
+        ],
+        "conditions": {
+            "in_office": {
+                "name": "in_office",
+                "expression": "ip.in_cidr(cidr)",
+                "parameters": {
+                    "cidr": { "type_name": "TYPE_NAME_STRING" },
+                    "ip": { "type_name": "TYPE_NAME_IPADDRESS" }
+                }
+            }
+        }
+    });
+    let (expected, _) = parse(DSL).unwrap();
+    assert_eq!(import(model).unwrap(), expected);
+}
+
+#[test]
+fn test_to_openfga() {
+    let (schema, _) = parse(DSL).unwrap();
+    let exported = serde_json::to_value(to_openfga(&schema)).unwrap();
+    assert_eq!(exported["schema_version"], "1.1");
+    assert_eq!(exported["type_definitions"][0], json!({ "type": "user" }));
+    assert_eq!(
+        exported["type_definitions"][2]["relations"]["view"],
+        json!({
+            "difference": {
+                "base": {
+                    "union": {
+                        "child": [
+                            { "computedUserset": { "relation": "viewer" } },
+                            { "computedUserset": { "relation": "owner" } },
+                            {
+                                "tupleToUserset": {
+                                    "tupleset": { "relation": "parent" },
+                                    "computedUserset": { "relation": "view" }
+                                }
+                            }
+                        ]
+                    }
+                },
+                "subtract": { "computedUserset": { "relation": "blocked" } }
+            }
+        })
+    );
+    assert_eq!(
+        exported["type_definitions"][2]["metadata"]["relations"]["viewer"],
+        direct(json!([
+            { "type": "user" },
+            { "type": "user", "wildcard": {} },
+            { "type": "group", "relation": "member" },
+            { "type": "user", "condition": "in_office" }
+        ]))
+    );
+    assert_eq!(
+        exported["conditions"]["in_office"],
+        json!({
+            "name": "in_office",
+            "expression": "ip.in_cidr(cidr)",
+            "parameters": {
+                "cidr": { "type_name": "TYPE_NAME_STRING" },
+                "ip": { "type_name": "TYPE_NAME_IPADDRESS" }
+            }
+        })
+    );
+
+    assert_eq!(import(exported).unwrap(), schema);
+}
+
+#[test]
+fn test_from_openfga_errors() {
+    let errors = import(json!({
+        "schema_version": "1.1",
+        "type_definitions": [
+            { "type": "user" },
+            {
+                "type": "doc",
+                "relations": {
+                    "owner": { "this": {} },
+                    "viewer": {
+                        "union": { "child": [{ "this": {} }, { "computedUserset": { "relation": "owner" } }] }
+                    }
+                }
+            }
+        ],
+        "conditions": {
+            "recent": {
+                "name": "recent",
+                "expression": "created > now",
+                "parameters": { "created": { "type_name": "TYPE_NAME_TIMESTAMP" } }
+            },
+            "tagged": {
+                "name": "tagged",
+                "expression": "tags.contains(\"x\")",
+                "parameters": { "tags": { "type_name": "TYPE_NAME_LIST" } }
+            }
+        }
+    }))
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            "relation `doc.owner` has no directly related user types",
+            "relation `doc.viewer` mixes direct types with a rewrite, split it into a relation and a permission",
+            "condition `recent`: undefined variable `now`",
+            "condition `tagged`: `TYPE_NAME_LIST` takes one generic type",
+        ]
+    );
+}
+
+#[test]
+fn test_typesystem_openfga() {
+    let (schema, _) = parse(DSL).unwrap();
+    let mut expected = to_openfga(&schema);
+    expected.type_definitions.sort_by(|l, r| l.r#type.cmp(&r.r#type));
+    expected.conditions.clear();
+    let typesystem = schema.clone().to_typesystem();
+    assert_eq!(typesystem_to_openfga(&typesystem), expected);
+    assert_eq!(typesystem_from_openfga(&to_openfga(&schema)).unwrap(), typesystem);
+}
+
+#[test]
+fn test_from_openfga_condition_expression() {
+    let model = |expression: &str| {
+        json!({
+            "type_definitions": [{ "type": "user" }],
+            "conditions": {
+                "c": {
+                    "name": "c",
+                    "expression": expression,
+                    "parameters": { "a": { "type_name": "TYPE_NAME_INT" } }
+                }
+            }
+        })
+    };
+    let schema = import(model("\n\n  a > 1\n\n")).unwrap();
+    assert_eq!(schema.conditions[0].name, "c");
+    assert_eq!(schema.conditions[0].body.to_string(), "a > 1");
+
+    // the expression is parsed on its own, it can't close the condition and define other units
+    let errors = import(model("true\n}\ntype injected {}\ncondition c(a: int) {\n  a > 1")).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("condition `c`: unexpected"), "{}", errors[0]);
+}
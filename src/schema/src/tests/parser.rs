@@ -1,3 +1,5 @@
+use protocol::{ObjectRelation, RelationReference};
+
 use crate::*;
 
 #[test]
@@ -48,6 +50,29 @@ type block {
     );
 }
 
+#[test]
+fn test_lexer_star() {
+    let mut comments = vec![];
+    let tokens = Lexer::new("user#* a*2", &mut comments).collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        vec![
+            Ok((0, Token::Identifier("user"), 4)),
+            Ok((4, Token::Sharp, 5)),
+            Ok((5, Token::Star, 6)),
+            Ok((7, Token::Identifier("a"), 8)),
+            Ok((8, Token::Star, 9)),
+            Ok((9, Token::IntLiteral(2), 10)),
+        ]
+    );
+
+    let (schema, _) = parse("type user {}\ntype doc {\n  relation viewer: user#* | user # *\n}").unwrap();
+    assert_eq!(
+        schema.types[1].relations[0].subjects,
+        vec![RelationshipSet::set("user", "*"), RelationshipSet::set("user", "*")]
+    );
+}
+
 #[test]
 fn test_parser1() {
     let schema = r"// comment: model define
@@ -64,16 +89,24 @@ type block {
         vec![
             Type {
                 name: String::from("user"),
+                extends: vec![],
                 relations: vec![],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("block"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("assignment"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             }
         ]
     )
@@ -106,48 +139,70 @@ type folder {
         vec![
             Type {
                 name: String::from("user"),
+                extends: vec![],
                 relations: vec![],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("block"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("assignment"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("group"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("member"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("folder"),
+                extends: vec![],
                 relations: vec![
                     Relation {
                         name: String::from("owner"),
-                        subjects: vec![RelationshipSet::Single(String::from("user"))]
+                        subjects: vec![RelationshipSet::single(String::from("user"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("parent"),
-                        subjects: vec![RelationshipSet::Single(String::from("folder"))]
+                        subjects: vec![RelationshipSet::single(String::from("folder"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("viewer"),
                         subjects: vec![
-                            RelationshipSet::Single(String::from("user")),
-                            RelationshipSet::Set(String::from("user"), String::from("*")),
-                            RelationshipSet::Set(String::from("group"), String::from("member")),
-                            RelationshipSet::Single(String::from("owner")),
-                            RelationshipSet::Set(String::from("parent"), String::from("viewer")),
-                        ]
+                            RelationshipSet::single(String::from("user")),
+                            RelationshipSet::set(String::from("user"), String::from("*")),
+                            RelationshipSet::set(String::from("group"), String::from("member")),
+                            RelationshipSet::single(String::from("owner")),
+                            RelationshipSet::set(String::from("parent"), String::from("viewer")),
+                        ],
+                        description: None,
+                        loc: (0, 0),
                     }
                 ],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             }
         ]
     )
@@ -182,57 +237,81 @@ fn test_parser3() {
         vec![
             Type {
                 name: String::from("user"),
+                extends: vec![],
                 relations: vec![],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("block"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("assignment"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("group"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("member"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("folder"),
+                extends: vec![],
                 relations: vec![
                     Relation {
                         name: String::from("owner"),
-                        subjects: vec![RelationshipSet::Single(String::from("user"))]
+                        subjects: vec![RelationshipSet::single(String::from("user"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("parent"),
-                        subjects: vec![RelationshipSet::Single(String::from("folder"))]
+                        subjects: vec![RelationshipSet::single(String::from("folder"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("viewer"),
                         subjects: vec![
-                            RelationshipSet::Single(String::from("user")),
-                            RelationshipSet::Set(String::from("user"), String::from("*")),
-                            RelationshipSet::Set(String::from("group"), String::from("member")),
-                            RelationshipSet::Single(String::from("owner")),
-                            RelationshipSet::Set(String::from("parent"), String::from("viewer")),
-                        ]
+                            RelationshipSet::single(String::from("user")),
+                            RelationshipSet::set(String::from("user"), String::from("*")),
+                            RelationshipSet::set(String::from("group"), String::from("member")),
+                            RelationshipSet::single(String::from("owner")),
+                            RelationshipSet::set(String::from("parent"), String::from("viewer")),
+                        ],
+                        description: None,
+                        loc: (0, 0),
                     },
                 ],
                 permissions: vec![Permission {
                     name: String::from("view"),
                     permission: Relationship::Difference {
-                        base: Box::new(Relationship::Set(RelationshipSet::Single(String::from("viewer")))),
-                        subtract: Box::new(Relationship::Set(RelationshipSet::Set(
+                        base: Box::new(Relationship::Set(RelationshipSet::single(String::from("viewer")))),
+                        subtract: Box::new(Relationship::Set(RelationshipSet::set(
                             String::from("block"),
                             String::from("assignment")
                         ))),
-                    }
-                }]
+                    },
+                    description: None,
+                    loc: (0, 0),
+                }],
+                description: None,
+                loc: (0, 0),
             }
         ]
     )
@@ -266,14 +345,13 @@ fn test_parser4() {
     type doc {
       relation owner: user
       relation parent: folder
-      relation viewer: user | user#* | group#member // | user ^ has_valid_ip
+      relation viewer: user | user#* | group#member | user ^ has_valid_ip
       permission can_change_owner: owner
       permission can_read: viewer + owner + parent#viewer
       permission can_share: owner + parent#owner
       permission can_write: owner + parent#owner
     }
 
-    // expr eval engine use evalexpr?
     condition has_valid_ip(user_ip: ipaddress, allowed_range: string) {
       user_ip.in_cidr(allowed_range)
     }";
@@ -283,122 +361,417 @@ fn test_parser4() {
         vec![
             Type {
                 name: String::from("user"),
+                extends: vec![],
                 relations: vec![],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("block"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("assignment"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("group"),
+                extends: vec![],
                 relations: vec![Relation {
                     name: String::from("member"),
-                    subjects: vec![RelationshipSet::Single(String::from("user"))]
+                    subjects: vec![RelationshipSet::single(String::from("user"))],
+                    description: None,
+                    loc: (0, 0),
                 }],
-                permissions: vec![]
+                permissions: vec![],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("folder"),
+                extends: vec![],
                 relations: vec![
                     Relation {
                         name: String::from("owner"),
-                        subjects: vec![RelationshipSet::Single(String::from("user"))]
+                        subjects: vec![RelationshipSet::single(String::from("user"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("parent"),
-                        subjects: vec![RelationshipSet::Single(String::from("folder"))]
+                        subjects: vec![RelationshipSet::single(String::from("folder"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("viewer"),
                         subjects: vec![
-                            RelationshipSet::Single(String::from("user")),
-                            RelationshipSet::Set(String::from("user"), String::from("*")),
-                            RelationshipSet::Set(String::from("group"), String::from("member")),
-                            RelationshipSet::Single(String::from("owner")),
-                            RelationshipSet::Set(String::from("parent"), String::from("viewer")),
-                        ]
+                            RelationshipSet::single(String::from("user")),
+                            RelationshipSet::set(String::from("user"), String::from("*")),
+                            RelationshipSet::set(String::from("group"), String::from("member")),
+                            RelationshipSet::single(String::from("owner")),
+                            RelationshipSet::set(String::from("parent"), String::from("viewer")),
+                        ],
+                        description: None,
+                        loc: (0, 0),
                     },
                 ],
                 permissions: vec![Permission {
                     name: String::from("view"),
                     permission: Relationship::Difference {
-                        base: Box::new(Relationship::Set(RelationshipSet::Single(String::from("viewer")))),
-                        subtract: Box::new(Relationship::Set(RelationshipSet::Set(
+                        base: Box::new(Relationship::Set(RelationshipSet::single(String::from("viewer")))),
+                        subtract: Box::new(Relationship::Set(RelationshipSet::set(
                             String::from("block"),
                             String::from("assignment")
                         ))),
-                    }
-                }]
+                    },
+                    description: None,
+                    loc: (0, 0),
+                }],
+                description: None,
+                loc: (0, 0),
             },
             Type {
                 name: String::from("doc"),
+                extends: vec![],
                 relations: vec![
                     Relation {
                         name: String::from("owner"),
-                        subjects: vec![RelationshipSet::Single(String::from("user"))]
+                        subjects: vec![RelationshipSet::single(String::from("user"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("parent"),
-                        subjects: vec![RelationshipSet::Single(String::from("folder"))]
+                        subjects: vec![RelationshipSet::single(String::from("folder"))],
+                        description: None,
+                        loc: (0, 0),
                     },
                     Relation {
                         name: String::from("viewer"),
                         subjects: vec![
-                            RelationshipSet::Single(String::from("user")),
-                            RelationshipSet::Set(String::from("user"), String::from("*")),
-                            RelationshipSet::Set(String::from("group"), String::from("member")),
-                        ]
+                            RelationshipSet::single(String::from("user")),
+                            RelationshipSet::set(String::from("user"), String::from("*")),
+                            RelationshipSet::set(String::from("group"), String::from("member")),
+                            RelationshipSet::conditional(
+                                RelationshipSet::single(String::from("user")),
+                                String::from("has_valid_ip")
+                            ),
+                        ],
+                        description: None,
+                        loc: (0, 0),
                     },
                 ],
                 permissions: vec![
                     Permission {
                         name: String::from("can_change_owner"),
-                        permission: Relationship::Set(RelationshipSet::Single(String::from("owner")))
+                        permission: Relationship::Set(RelationshipSet::single(String::from("owner"))),
+                        description: None,
+                        loc: (0, 0),
                     },
                     Permission {
                         name: String::from("can_read"),
                         permission: Relationship::Union {
                             children: vec![
-                                Box::new(Relationship::Set(RelationshipSet::Single(String::from("viewer")))),
-                                Box::new(Relationship::Set(RelationshipSet::Single(String::from("owner")))),
-                                Box::new(Relationship::Set(RelationshipSet::Set(
+                                Box::new(Relationship::Set(RelationshipSet::single(String::from("viewer")))),
+                                Box::new(Relationship::Set(RelationshipSet::single(String::from("owner")))),
+                                Box::new(Relationship::Set(RelationshipSet::set(
                                     String::from("parent"),
                                     String::from("viewer")
                                 ))),
                             ]
-                        }
+                        },
+                        description: None,
+                        loc: (0, 0),
                     },
                     Permission {
                         name: String::from("can_share"),
                         permission: Relationship::Union {
                             children: vec![
-                                Box::new(Relationship::Set(RelationshipSet::Single(String::from("owner")))),
-                                Box::new(Relationship::Set(RelationshipSet::Set(
+                                Box::new(Relationship::Set(RelationshipSet::single(String::from("owner")))),
+                                Box::new(Relationship::Set(RelationshipSet::set(
                                     String::from("parent"),
                                     String::from("owner")
                                 ))),
                             ]
-                        }
+                        },
+                        description: None,
+                        loc: (0, 0),
                     },
                     Permission {
                         name: String::from("can_write"),
                         permission: Relationship::Union {
                             children: vec![
-                                Box::new(Relationship::Set(RelationshipSet::Single(String::from("owner")))),
-                                Box::new(Relationship::Set(RelationshipSet::Set(
+                                Box::new(Relationship::Set(RelationshipSet::single(String::from("owner")))),
+                                Box::new(Relationship::Set(RelationshipSet::set(
                                     String::from("parent"),
                                     String::from("owner")
                                 ))),
                             ]
-                        }
+                        },
+                        description: None,
+                        loc: (0, 0),
                     },
-                ]
+                ],
+                description: None,
+                loc: (0, 0),
             }
         ]
     )
 }
+
+#[test]
+fn test_conditional_subject() {
+    let schema = r"type user {}
+type group {
+  relation member: user
+}
+type doc {
+  relation viewer: user with in_office | group#member with in_office | user#* ^ in_office
+}
+condition in_office(ip: ipaddress) {
+  ip.in_cidr('10.0.0.0/8')
+}";
+    let (schema, _) = parse(&schema.replace('\'', "\"")).unwrap();
+    let typesystem = schema.to_typesystem();
+    let conditional = |reference| RelationReference::Conditional {
+        reference: Box::new(reference),
+        condition: String::from("in_office"),
+    };
+    assert_eq!(
+        typesystem.get_directly_related_types("doc", "viewer").unwrap(),
+        vec![
+            conditional(RelationReference::Direct(String::from("user"))),
+            conditional(RelationReference::Relation {
+                r#type: String::from("group"),
+                relation: String::from("member"),
+            }),
+            conditional(RelationReference::Wildcard(String::from("user"))),
+        ]
+    );
+
+    // conditions restrict relation subjects only
+    assert!(parse("type doc {\n  relation owner: user\n  permission view: owner with in_office\n}").is_err());
+}
+
+#[test]
+fn test_parser_locations() {
+    let dsl = r"type user {}
+type doc {
+  relation viewer: user | user with in_office
+  permission view: viewer + (parent#viewer & owner)
+}
+condition in_office(ip: ipaddress) {
+  true
+}";
+    let (schema, _) = parse(dsl).unwrap();
+    let text = |loc: Loc| &dsl[loc.0..loc.1];
+    let doc = &schema.types[1];
+    assert_eq!(text(schema.types[0].loc), "type user {}");
+    assert!(text(doc.loc).starts_with("type doc {") && text(doc.loc).ends_with('}'));
+    assert_eq!(
+        text(doc.relations[0].loc),
+        "relation viewer: user | user with in_office"
+    );
+    let subjects = &doc.relations[0].subjects;
+    assert_eq!(
+        subjects.iter().map(|s| text(s.loc)).collect::<Vec<_>>(),
+        vec!["user", "user with in_office"]
+    );
+    let SetKind::Conditional(set, _) = &subjects[1].kind else {
+        panic!("expected a conditional subject, got {:?}", subjects[1]);
+    };
+    assert_eq!(text(set.loc), "user");
+    assert_eq!(
+        text(doc.permissions[0].loc),
+        "permission view: viewer + (parent#viewer & owner)"
+    );
+    assert_eq!(
+        doc.permissions[0]
+            .permission
+            .sets()
+            .iter()
+            .map(|s| text(s.loc))
+            .collect::<Vec<_>>(),
+        vec!["viewer", "parent#viewer", "owner"]
+    );
+    assert!(text(schema.conditions[0].loc).starts_with("condition in_office"));
+}
+
+#[test]
+fn test_parser_errors() {
+    let dsl = r"type user {}
+type doc {
+  relation : user
+  relation owner: user
+  permission view viewer
+}
+type folder {
+  relation parent: doc |
+}";
+    let errors = parse(dsl).unwrap_err();
+    assert_eq!(
+        errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec![
+            "3:12: unexpected `:`, expected identifier\n3 |   relation : user\n  |            ^",
+            "5:19: unexpected identifier `viewer`, expected `:`\n5 |   permission view viewer\n  |                   ^^^^^^",
+            "8:25: unexpected end of line, expected identifier\n8 |   relation parent: doc |\n  |                         ^",
+        ]
+    );
+    assert_eq!(errors[0].position, Some(Position { line: 3, column: 12 }));
+
+    let errors = parse("type user {\n  relation a: user $ b\n}").unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        "unexpected `$`, expected one of end of line, `#`, `^`, `with`, `|` or `}`"
+    );
+}
+
+#[test]
+fn test_reserved_keyword() {
+    for (dsl, keyword) in [
+        ("type user {}\ntype doc {\n  relation mixin: user\n}", "mixin"),
+        ("type import {}", "import"),
+        (
+            "type user {}\ntype doc {\n  relation viewer: user\n  permission view: viewer + true\n}",
+            "true",
+        ),
+    ] {
+        let errors = parse(dsl).unwrap_err();
+        assert_eq!(
+            errors[0].message,
+            format!("`{}` is a reserved keyword, it cannot be used as a name", keyword)
+        );
+    }
+}
+
+#[test]
+fn test_parser_arrow() {
+    let dsl = |ttu: &str| {
+        format!(
+            "type user {{}}\ntype folder {{\n  relation parent: folder\n  relation viewer: user\n  permission view: viewer + {}\n}}",
+            ttu
+        )
+    };
+    let (arrow, _) = parse(&dsl("parent->view")).unwrap();
+    assert!(arrow.warnings.is_empty());
+    assert_eq!(
+        arrow.types[1].permissions[0].permission,
+        Relationship::Union {
+            children: vec![
+                Box::new(Relationship::Set(RelationshipSet::single("viewer".to_string()))),
+                Box::new(Relationship::Set(RelationshipSet::set(
+                    "parent".to_string(),
+                    "view".to_string()
+                ))),
+            ]
+        }
+    );
+
+    let (sharp, _) = parse(&dsl("parent#view")).unwrap();
+    assert_eq!(sharp, arrow);
+    assert_eq!(
+        sharp.warnings.iter().map(|w| w.to_string()).collect::<Vec<_>>(),
+        vec![
+            "5:29: `parent#view` in a permission is deprecated, use `parent->view` for a tuple to userset\n5 |   permission view: viewer + parent#view\n  |                             ^^^^^^^^^^^"
+        ]
+    );
+    assert_eq!(sharp.warnings[0].severity, Severity::Warning);
+
+    // usersets of relation subjects keep `#`
+    let errors = parse("type user {}\ntype folder {\n  relation viewer: folder->viewer\n}").unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        "unexpected `->`, expected one of end of line, `#`, `^`, `with`, `|` or `}`"
+    );
+}
+
+#[test]
+fn test_parser_descriptions() {
+    let dsl = r"// not a description
+
+/// A person.
+type user {}
+
+// free comment, ends the description above it
+/// Documents of the drive.
+///
+/// Shared by links.
+type doc {
+  /// who created it
+  relation owner: user
+  relation viewer: user /// trailing, not a description
+  relation editor: user
+  // who can read it
+  permission view: viewer + owner
+}";
+    let (schema, comments) = parse(dsl).unwrap();
+    let doc = &schema.types[1];
+    assert_eq!(schema.types[0].description.as_deref(), Some("A person."));
+    assert_eq!(
+        doc.description.as_deref(),
+        Some("Documents of the drive.\n\nShared by links.")
+    );
+    assert_eq!(doc.relations[0].description.as_deref(), Some("who created it"));
+    assert_eq!(doc.relations[1].description, None);
+    assert_eq!(doc.relations[2].description, None);
+    assert_eq!(doc.permissions[0].description, None);
+    assert_eq!(
+        comments.into_iter().map(|c| c.1).collect::<Vec<_>>(),
+        vec![
+            "// not a description",
+            "// free comment, ends the description above it",
+            "/// trailing, not a description",
+            "// who can read it",
+        ]
+    );
+
+    let json = serde_json::to_value(&schema).unwrap();
+    assert_eq!(json["types"][1]["relations"][0]["description"], "who created it");
+    assert!(json["types"][1]["relations"][1].get("description").is_none());
+    let stored: Schema = serde_json::from_value(json).unwrap();
+    assert_eq!(stored, schema);
+}
+
+#[test]
+fn test_parser_superadmin() {
+    let dsl = r"superadmin system#admin
+type user {}
+type system {
+  relation admin: user | group#member
+}
+type group {
+  relation member: user
+}";
+    let (schema, _) = parse(dsl).unwrap();
+    assert_eq!(schema.superadmins.len(), 1);
+    assert_eq!(schema.superadmins[0].r#type, "system");
+    assert_eq!(schema.superadmins[0].relation, "admin");
+    assert_eq!(
+        &dsl[schema.superadmins[0].loc.0..schema.superadmins[0].loc.1],
+        "superadmin system#admin"
+    );
+
+    let json = serde_json::to_value(&schema).unwrap();
+    assert_eq!(
+        json["superadmins"],
+        serde_json::json!([{"type": "system", "relation": "admin"}])
+    );
+    let stored: Schema = serde_json::from_value(json).unwrap();
+    assert_eq!(stored, schema);
+
+    let typesystem = schema.to_typesystem();
+    assert_eq!(
+        typesystem.superadmins,
+        vec![ObjectRelation {
+            object: "system".to_string(),
+            relation: "admin".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,118 @@
+use crate::{spicedb::from_spicedb, *};
+
+fn errors(zed: &str) -> Vec<String> {
+    from_spicedb(zed)
+        .unwrap_err()
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect()
+}
+
+#[test]
+fn test_from_spicedb() {
+    let zed = r#"/** a user of the system */
+definition user {}
+
+definition group {
+    relation member: user | group#member
+}
+
+// folders nest
+definition folder {
+    relation parent: folder
+    relation owner: user
+    relation viewer: user | user:* | group#member | user with in_office
+    relation banned: user
+
+    /* everyone who can see the folder */
+    permission view = viewer + owner + parent->view - banned
+    permission edit = owner & viewer + parent.any(edit)
+}
+
+caveat in_office(ip ipaddress, cidrs list<string>) {
+    cidrs.contains(string(ip)) && ip.in_cidr("10.0.0.0/8")
+}
+"#;
+    let dsl = r#"type user {}
+type group {
+  relation member: user | group#member
+}
+type folder {
+  relation parent: folder
+  relation owner: user
+  relation viewer: user | user#* | group#member | user with in_office
+  relation banned: user
+  permission view: viewer + owner + parent->view - banned
+  permission edit: owner & (viewer + parent->edit)
+}
+condition in_office(ip: ipaddress, cidrs: list<string>) {
+  cidrs.contains(string(ip)) && ip.in_cidr("10.0.0.0/8")
+}"#;
+    let schema = from_spicedb(zed).unwrap();
+    assert_eq!(schema, parse(dsl).unwrap().0);
+    assert!(validate(&schema).is_empty());
+
+    let folder = &schema.types[2];
+    let text = |loc: Loc| &zed[loc.0..loc.1];
+    assert_eq!(text(folder.relations[2].subjects[1].loc), "user:*");
+    assert_eq!(text(folder.permissions[0].permission.sets()[2].loc), "parent->view");
+}
+
+#[test]
+fn test_from_spicedb_precedence() {
+    let permission = |expr: &str| {
+        let zed = format!(
+            "definition doc {{\n relation a: doc\n relation b: doc\n relation c: doc\n permission p = {}\n}}",
+            expr
+        );
+        from_spicedb(&zed).unwrap().types[0].permissions[0]
+            .permission
+            .to_string()
+    };
+    // `+` binds tighter than `&`, which binds tighter than `-`
+    assert_eq!(permission("a - b + c"), "a - (b + c)");
+    assert_eq!(permission("a & b + c"), "a & (b + c)");
+    assert_eq!(permission("a - b & c"), "a - b & c");
+    assert_eq!(permission("(a - b) + c"), "a - b + c");
+    assert_eq!(permission("a + b - c"), "a + b - c");
+}
+
+#[test]
+fn test_from_spicedb_unsupported() {
+    let zed = r"use expiration
+
+definition org/user {}
+
+definition doc {
+    relation viewer: user with expiration
+    relation owner: user
+    permission nothing = nil
+    permission every = owner.all(viewer)
+}";
+    assert_eq!(
+        errors(zed),
+        vec![
+            "1:1: `use expiration` cannot be represented in a model\n1 | use expiration\n  | ^^^^^^^^^^^^^^",
+            "3:12: the namespaced name `org/user` cannot be represented in a model\n3 | definition org/user {}\n  |            ^^^^^^^^",
+            "6:32: the `expiration` trait cannot be represented in a model\n6 |     relation viewer: user with expiration\n  |                                ^^^^^^^^^^",
+            "8:26: `nil` cannot be represented in a model\n8 |     permission nothing = nil\n  |                          ^^^",
+            "9:24: the arrow function `all` cannot be represented in a model\n9 |     permission every = owner.all(viewer)\n  |                        ^^^^^^^^^^^^^^^^^",
+        ]
+    );
+}
+
+#[test]
+fn test_from_spicedb_errors() {
+    assert_eq!(
+        errors("definition doc {\n    relation viewer user\n}"),
+        vec!["2:21: unexpected identifier `user`, expected `:`\n2 |     relation viewer user\n  |                     ^^^^"]
+    );
+    assert_eq!(
+        errors("definition doc {\n    permission view = \n}"),
+        vec!["3:1: unexpected `}`, expected identifier\n3 | }\n  | ^"]
+    );
+    assert_eq!(
+        errors("caveat c(a int) {\n  a > \"x\"\n}"),
+        vec!["1:1: caveat `c`: cannot apply `>` to int and string\n1 | caveat c(a int) {\n  | ^^^^^^^^^^^^^^^^^"]
+    );
+}
@@ -0,0 +1,170 @@
+use crate::*;
+
+fn messages(dsl: &str) -> Vec<String> {
+    let (schema, _) = parse(dsl).unwrap();
+    validate(&schema).into_iter().map(|d| d.message).collect()
+}
+
+#[test]
+fn test_validate_valid() {
+    let schema = r"type user {}
+type group {
+  relation member: user | group#member
+}
+type folder {
+  relation owner: user
+  relation parent: folder
+  relation viewer: user | user#* | group#member | user with in_office
+  permission view: viewer + owner + parent#view
+  permission edit: owner & view
+  permission browse: view - edit
+}
+condition in_office(ip: ipaddress) {
+  ip.in_cidr('10.0.0.0/8')
+}";
+    assert_eq!(messages(&schema.replace('\'', "\"")), Vec::<String>::new());
+}
+
+#[test]
+fn test_validate_undefined() {
+    let schema = r"type user {}
+type folder {
+  relation owner: user
+  relation parent: foldr
+  relation viewer: user#member | user with missing
+  permission view: viewer + editor
+}";
+    assert_eq!(
+        messages(schema),
+        vec![
+            "undefined type `foldr` in relation `folder.parent`",
+            "undefined relation `user#member` in relation `folder.viewer`",
+            "undefined condition `missing` in relation `folder.viewer`",
+            "undefined relation `editor` in permission `folder.view`",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_tupleset() {
+    let schema = r"type user {}
+type group {
+  relation member: user
+}
+type folder {
+  relation owner: user
+  relation viewer: group#member
+  permission parent: owner
+  permission a: parent#owner
+  permission b: viewer#member
+  permission c: owner#viewer
+}";
+    assert_eq!(
+        messages(schema),
+        vec![
+            "tupleset `parent` in permission `folder.a` must be a relation, not a permission",
+            "tupleset `folder.viewer` used in permission `folder.b` allows only direct types",
+            "relation `member` is not defined on any type of tupleset `folder.viewer` in permission `folder.b`",
+            "relation `viewer` is not defined on any type of tupleset `folder.owner` in permission `folder.c`",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_duplicate() {
+    let schema = r"type user {}
+type user {}
+type folder {
+  relation owner: user
+  permission owner: owner
+}";
+    assert_eq!(
+        messages(schema),
+        vec![
+            "duplicate type `user`",
+            "duplicate relation or permission `owner` in type `folder`",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_base_case() {
+    let schema = r"type user {}
+type folder {
+  relation owner: user
+  relation parent: folder
+  permission a: b
+  permission b: a & owner
+  permission c: c
+  permission d: owner + d
+  permission e: parent#e
+}";
+    assert_eq!(
+        messages(schema),
+        vec![
+            "permission `folder.a` has no base case, it only refers to itself",
+            "permission `folder.b` has no base case, it only refers to itself",
+            "permission `folder.c` has no base case, it only refers to itself",
+            "permission `folder.e` has no base case, it only refers to itself",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_locations() {
+    let dsl = r"type user {}
+type folder {
+  relation owner: user | team
+  permission view: owner + editor
+}";
+    let (schema, _) = parse(dsl).unwrap();
+    let diagnostics = validate(&schema);
+    let texts: Vec<_> = diagnostics.iter().map(|d| d.loc.map(|l| &dsl[l.0..l.1])).collect();
+    assert_eq!(texts, vec![Some("team"), Some("editor")]);
+
+    // models read back from json have no spans
+    let schema: Schema = serde_json::from_value(serde_json::to_value(&schema).unwrap()).unwrap();
+    assert!(validate(&schema).iter().all(|d| d.loc.is_none()));
+}
+
+#[test]
+fn test_validate_superadmin() {
+    let schema = r"superadmin system#admin
+superadmin system#manage
+superadmin system#owner
+superadmin root#admin
+superadmin system#admin
+type user {}
+type system {
+  relation admin: user
+  permission manage: admin
+}";
+    assert_eq!(
+        messages(schema),
+        vec![
+            "superadmin `system#manage` must be a relation, not a permission",
+            "undefined relation `system#owner` in superadmin",
+            "undefined type `root` in superadmin",
+            "duplicate superadmin `system#admin`",
+        ]
+    );
+
+    let schema = r"condition ok(allowed: bool) {
+  allowed
+}
+superadmin system#admin
+type user {}
+type group {
+  relation member: user
+}
+type system {
+  relation admin: user | user with ok | group#member | user#*
+}";
+    assert_eq!(
+        messages(schema),
+        vec![
+            "superadmin `system#admin` must relate users directly, not `group#member`",
+            "superadmin `system#admin` must relate users directly, not `user#*`",
+        ]
+    );
+}
@@ -8,12 +8,19 @@ pub enum Token<'input> {
     Relation,                   // relation
     Permission,                 // permission
     Cond,                       // cond
+    With,                       // with
+    Import,                     // import
+    Superadmin,                 // superadmin
+    Extends,                    // extends
+    Mixin,                      // mixin
     Colon,                      // :
     LBracket,                   // (
     RBracket,                   // )
     Caret,                      // ^
     And,                        // &
+    AndAnd,                     // &&
     Or,                         // |
+    OrOr,                       // ||
     Add,                        // +
     Sub,                        // -
     YulArrow,                   // ->
@@ -27,9 +34,13 @@ pub enum Token<'input> {
     Comma,                      // ,
     Semicolon,                  // ;
     Eq,                         // =
+    EqEq,                       // ==
+    NotEq,                      // !=
     ExclamationMark,            // (nothing)!
     Gt,                         // >
+    Ge,                         // >=
     Lt,                         // <
+    Le,                         // <=
     Condition,                  // condition
     Int,                        // int
     Uint,                       // uint
@@ -43,6 +54,8 @@ pub enum Token<'input> {
     List,                       // list
     Map,                        // map
     IPaddress,                  // ipaddress
+    True,                       // true
+    False,                      // false
     StringLiteral(&'input str), // string literal
     IntLiteral(i64),            // int literal
     DoubleLiteral(f64),         // double literal
@@ -61,12 +74,19 @@ impl<'input> fmt::Display for Token<'input> {
             Token::Relation => f.write_str("relation"),
             Token::Permission => f.write_str("permission"),
             Token::Cond => f.write_str("cond"),
+            Token::With => f.write_str("with"),
+            Token::Import => f.write_str("import"),
+            Token::Superadmin => f.write_str("superadmin"),
+            Token::Extends => f.write_str("extends"),
+            Token::Mixin => f.write_str("mixin"),
             Token::Colon => f.write_str(":"),
             Token::LBracket => f.write_str("("),
             Token::RBracket => f.write_str(")"),
             Token::Caret => f.write_str("^"),
             Token::And => f.write_str("&"),
+            Token::AndAnd => f.write_str("&&"),
             Token::Or => f.write_str("|"),
+            Token::OrOr => f.write_str("||"),
             Token::Add => f.write_str("+"),
             Token::Sub => f.write_str("-"),
             Token::YulArrow => f.write_str("->"),
@@ -80,9 +100,13 @@ impl<'input> fmt::Display for Token<'input> {
             Token::Comma => f.write_str(","),
             Token::Semicolon => f.write_str(";"),
             Token::Eq => f.write_str("="),
+            Token::EqEq => f.write_str("=="),
+            Token::NotEq => f.write_str("!="),
             Token::ExclamationMark => f.write_str("!"),
             Token::Gt => f.write_str(">"),
+            Token::Ge => f.write_str(">="),
             Token::Lt => f.write_str("<"),
+            Token::Le => f.write_str("<="),
             Token::Condition => f.write_str("condition"),
             Token::Int => f.write_str("Int"),
             Token::Uint => f.write_str("Uint"),
@@ -96,6 +120,8 @@ impl<'input> fmt::Display for Token<'input> {
             Token::List => f.write_str("List"),
             Token::Map => f.write_str("Map"),
             Token::IPaddress => f.write_str("IPaddress"),
+            Token::True => f.write_str("true"),
+            Token::False => f.write_str("false"),
             Token::StringLiteral(string) => f.write_fmt(format_args!("\"{}\"", string)),
             Token::IntLiteral(int) => f.write_fmt(format_args!("{}", int)),
             Token::DoubleLiteral(double) => f.write_fmt(format_args!("{}", double)),
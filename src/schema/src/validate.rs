@@ -0,0 +1,302 @@
+use std::collections::{HashMap, HashSet};
+
+use protocol::WILDCARD;
+
+use crate::{
+    inherit::flatten_with_errors, Diagnostic, Loc, Permission, Relationship, RelationshipSet, Schema, SetKind,
+    Superadmin, Type,
+};
+
+/// Checks that every name used by the model resolves and that every permission can be satisfied. Types are checked
+/// with the members they inherit, a mixin through the types that extend it.
+pub fn validate(schema: &Schema) -> Vec<Diagnostic> {
+    let (flat, diagnostics) = flatten_with_errors(schema);
+    let mut validator = Validator {
+        types: HashMap::new(),
+        conditions: HashSet::new(),
+        diagnostics,
+    };
+    validator.validate(&flat);
+    validator.diagnostics
+}
+
+struct Validator<'a> {
+    types: HashMap<&'a str, &'a Type>,
+    conditions: HashSet<&'a str>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Validator<'a> {
+    fn validate(&mut self, schema: &'a Schema) {
+        for typ in &schema.types {
+            if self.types.insert(&typ.name, typ).is_some() {
+                self.error(span(typ.loc), format!("duplicate type `{}`", typ.name));
+            }
+        }
+        for cond in &schema.conditions {
+            if !self.conditions.insert(&cond.name) {
+                self.error(span(cond.loc), format!("duplicate condition `{}`", cond.name));
+            }
+            for (loc, err) in cond.type_check() {
+                self.error(Some(loc), format!("condition `{}`: {}", cond.name, err));
+            }
+        }
+        for typ in &schema.types {
+            let mut names = HashSet::new();
+            let relation_names = typ.relations.iter().map(|r| (&r.name, r.loc));
+            let permission_names = typ.permissions.iter().map(|p| (&p.name, p.loc));
+            for (name, loc) in relation_names.chain(permission_names) {
+                if !names.insert(name) {
+                    self.error(
+                        span(loc),
+                        format!("duplicate relation or permission `{}` in type `{}`", name, typ.name),
+                    );
+                }
+            }
+            for rel in &typ.relations {
+                let context = format!("relation `{}.{}`", typ.name, rel.name);
+                for sub in &rel.subjects {
+                    self.validate_subject(sub, &context);
+                }
+            }
+            for perm in &typ.permissions {
+                self.validate_permission(typ, perm);
+            }
+        }
+        let mut superadmins = HashSet::new();
+        for superadmin in &schema.superadmins {
+            self.validate_superadmin(superadmin);
+            if !superadmins.insert((&superadmin.r#type, &superadmin.relation)) {
+                self.error(
+                    span(superadmin.loc),
+                    format!("duplicate superadmin `{}#{}`", superadmin.r#type, superadmin.relation),
+                );
+            }
+        }
+        // unresolved names already make permissions unsatisfiable, do not report them twice
+        if self.diagnostics.is_empty() {
+            self.validate_base_cases(schema);
+        }
+    }
+
+    fn error(&mut self, loc: Option<Loc>, message: String) {
+        self.diagnostics.push(Diagnostic::new(loc, message));
+    }
+
+    fn validate_subject(&mut self, sub: &RelationshipSet, context: &str) {
+        let loc = span(sub.loc);
+        match &sub.kind {
+            SetKind::Single(typ) => {
+                if !self.types.contains_key(typ.as_str()) {
+                    self.error(loc, format!("undefined type `{}` in {}", typ, context));
+                }
+            }
+            SetKind::Set(typ, relation) => match self.types.get(typ.as_str()) {
+                None => self.error(loc, format!("undefined type `{}` in {}", typ, context)),
+                Some(t) => {
+                    if !relation.eq(WILDCARD) && !has_member(t, relation) {
+                        self.error(loc, format!("undefined relation `{}#{}` in {}", typ, relation, context));
+                    }
+                }
+            },
+            SetKind::Conditional(set, condition) => {
+                if !self.conditions.contains(condition.as_str()) {
+                    self.error(loc, format!("undefined condition `{}` in {}", condition, context));
+                }
+                self.validate_subject(set, context);
+            }
+        }
+    }
+
+    /// Superadmins are read from the tuples of the relation, a permission has none. Only the users of the tuples are
+    /// superadmins, a userset or a wildcard would not be resolved.
+    fn validate_superadmin(&mut self, superadmin: &Superadmin) {
+        let loc = span(superadmin.loc);
+        let Some(typ) = self.types.get(superadmin.r#type.as_str()) else {
+            self.error(loc, format!("undefined type `{}` in superadmin", superadmin.r#type));
+            return;
+        };
+        if let Some(relation) = typ.relations.iter().find(|r| r.name.eq(&superadmin.relation)) {
+            for subject in &relation.subjects {
+                let set = match &subject.kind {
+                    SetKind::Conditional(set, _) => set.as_ref(),
+                    _ => subject,
+                };
+                if !matches!(set.kind, SetKind::Single(_)) {
+                    self.error(
+                        loc,
+                        format!(
+                            "superadmin `{}#{}` must relate users directly, not `{}`",
+                            superadmin.r#type, superadmin.relation, set
+                        ),
+                    );
+                }
+            }
+            return;
+        }
+        if typ.permissions.iter().any(|p| p.name.eq(&superadmin.relation)) {
+            self.error(
+                loc,
+                format!(
+                    "superadmin `{}#{}` must be a relation, not a permission",
+                    superadmin.r#type, superadmin.relation
+                ),
+            );
+        } else {
+            self.error(
+                loc,
+                format!(
+                    "undefined relation `{}#{}` in superadmin",
+                    superadmin.r#type, superadmin.relation
+                ),
+            );
+        }
+    }
+
+    fn validate_permission(&mut self, typ: &'a Type, perm: &Permission) {
+        let context = format!("permission `{}.{}`", typ.name, perm.name);
+        for set in perm.permission.sets() {
+            self.validate_set(typ, set, &context);
+        }
+    }
+
+    fn validate_set(&mut self, typ: &'a Type, set: &RelationshipSet, context: &str) {
+        let loc = span(set.loc);
+        match &set.kind {
+            SetKind::Single(relation) => {
+                if !has_member(typ, relation) {
+                    self.error(loc, format!("undefined relation `{}` in {}", relation, context));
+                }
+            }
+            SetKind::Set(tupleset, computed) => {
+                let Some(rel) = typ.relations.iter().find(|r| r.name.eq(tupleset)) else {
+                    if typ.permissions.iter().any(|p| p.name.eq(tupleset)) {
+                        self.error(
+                            loc,
+                            format!(
+                                "tupleset `{}` in {} must be a relation, not a permission",
+                                tupleset, context
+                            ),
+                        );
+                    } else {
+                        self.error(loc, format!("undefined relation `{}` in {}", tupleset, context));
+                    }
+                    return;
+                };
+                if computed.eq(WILDCARD) {
+                    self.error(loc, format!("wildcard `{}#*` is not allowed in {}", tupleset, context));
+                    return;
+                }
+                let mut defined = false;
+                for sub in &rel.subjects {
+                    match &unconditional(sub).kind {
+                        SetKind::Single(t) => {
+                            defined |= self.types.get(t.as_str()).is_some_and(|t| has_member(t, computed));
+                        }
+                        _ => self.error(
+                            loc,
+                            format!(
+                                "tupleset `{}.{}` used in {} allows only direct types",
+                                typ.name, tupleset, context
+                            ),
+                        ),
+                    }
+                }
+                if !defined {
+                    self.error(
+                        loc,
+                        format!(
+                            "relation `{}` is not defined on any type of tupleset `{}.{}` in {}",
+                            computed, typ.name, tupleset, context
+                        ),
+                    );
+                }
+            }
+            SetKind::Conditional(..) => {
+                self.error(
+                    loc,
+                    format!(
+                        "conditions are only allowed on relation subjects, found one in {}",
+                        context
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Relations are satisfied by tuples, a permission is satisfiable once it can reach a relation, iterate until
+    /// nothing changes, whatever is left only refers to itself.
+    fn validate_base_cases(&mut self, schema: &'a Schema) {
+        let mut satisfiable: HashSet<(&str, &str)> = HashSet::new();
+        for typ in &schema.types {
+            for rel in &typ.relations {
+                satisfiable.insert((&typ.name, &rel.name));
+            }
+        }
+        loop {
+            let mut changed = false;
+            for typ in &schema.types {
+                for perm in &typ.permissions {
+                    let key = (typ.name.as_str(), perm.name.as_str());
+                    if !satisfiable.contains(&key) && self.has_base_case(typ, &perm.permission, &satisfiable) {
+                        satisfiable.insert(key);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        for typ in &schema.types {
+            for perm in &typ.permissions {
+                if !satisfiable.contains(&(typ.name.as_str(), perm.name.as_str())) {
+                    self.error(
+                        span(perm.loc),
+                        format!(
+                            "permission `{}.{}` has no base case, it only refers to itself",
+                            typ.name, perm.name
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn has_base_case(&self, typ: &Type, relationship: &Relationship, satisfiable: &HashSet<(&str, &str)>) -> bool {
+        match relationship {
+            Relationship::Set(set) => match &set.kind {
+                SetKind::Single(relation) => satisfiable.contains(&(typ.name.as_str(), relation.as_str())),
+                SetKind::Set(tupleset, computed) => typ
+                    .relations
+                    .iter()
+                    .filter(|r| r.name.eq(tupleset))
+                    .flat_map(|r| r.subjects.iter())
+                    .any(|sub| match &unconditional(sub).kind {
+                        SetKind::Single(t) => satisfiable.contains(&(t.as_str(), computed.as_str())),
+                        _ => false,
+                    }),
+                SetKind::Conditional(..) => false,
+            },
+            Relationship::Union { children } => children.iter().any(|c| self.has_base_case(typ, c, satisfiable)),
+            Relationship::Intersection { children } => children.iter().all(|c| self.has_base_case(typ, c, satisfiable)),
+            Relationship::Difference { base, .. } => self.has_base_case(typ, base, satisfiable),
+        }
+    }
+}
+
+/// Nodes deserialized from json carry no span.
+fn span(loc: Loc) -> Option<Loc> {
+    (loc.0 < loc.1).then_some(loc)
+}
+
+fn has_member(typ: &Type, name: &str) -> bool {
+    typ.relations.iter().any(|r| r.name.eq(name)) || typ.permissions.iter().any(|p| p.name.eq(name))
+}
+
+fn unconditional(sub: &RelationshipSet) -> &RelationshipSet {
+    match &sub.kind {
+        SetKind::Conditional(set, _) => unconditional(set),
+        _ => sub,
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use checker::{CheckRequest, CheckResult, CheckerRef, Memo};
+use futures::StreamExt;
+
+use crate::error::ServerError;
+
+/// The most items of a batch, a larger one is rejected as a whole.
+pub(crate) const MAX_BATCH_SIZE: usize = 50;
+
+/// Fails when a batch has more than `MAX_BATCH_SIZE` items.
+pub(crate) fn ensure_batch_size(size: usize) -> Result<(), ServerError> {
+    if size > MAX_BATCH_SIZE {
+        return Err(ServerError::BatchTooLarge {
+            size,
+            max: MAX_BATCH_SIZE,
+        });
+    }
+    Ok(())
+}
+
+/// Fails when two items of a batch have the same correlation id, their results could not be told apart.
+pub(crate) fn ensure_unique_correlation_ids<'a>(ids: impl IntoIterator<Item = &'a str>) -> Result<(), ServerError> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            return Err(ServerError::DuplicateCorrelationId(id.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Runs the checks of a batch at most `concurrency_limit` at a time, they share one memo so that the sub-problems
+/// they have in common are resolved once. The results are in the order of the requests, an error fails only its item,
+/// be it the error of the check or that of an item which could not be read into a request.
+pub(crate) async fn batch_check(
+    checker: CheckerRef,
+    requests: Vec<Result<CheckRequest>>,
+    concurrency_limit: usize,
+) -> Vec<Result<CheckResult>> {
+    let memo = Memo::default();
+    let (checker, memo) = (&checker, &memo);
+    futures::stream::iter(requests)
+        .map(|req| async move {
+            checker
+                .check(CheckRequest {
+                    memo: Some(memo.clone()),
+                    ..req?
+                })
+                .await
+        })
+        .buffered(concurrency_limit.max(1))
+        .collect()
+        .await
+}
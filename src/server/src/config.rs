@@ -7,6 +7,54 @@ pub struct Config {
     pub http: Option<HttpConfig>,
     pub grpc: Option<GrpcConfig>,
     pub datasource: Datasource,
+    #[serde(default)]
+    pub check: CheckConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CheckConfig {
+    /// the most dispatches of a check, a request may ask for less
+    pub resolution_depth: u32,
+    /// how many datastore queries of a check are in flight at the same time, see `checker::DEFAULT_CONCURRENCY_LIMIT`
+    pub concurrency_limit: usize,
+}
+
+impl CheckConfig {
+    /// The depth of a check asking for `requested`, the limit of the server when it asks for none or for more.
+    pub fn resolution_depth(&self, requested: Option<u32>) -> u32 {
+        requested.map_or(self.resolution_depth, |d| d.min(self.resolution_depth))
+    }
+}
+
+impl Default for CheckConfig {
+    fn default() -> Self {
+        Self {
+            resolution_depth: checker::DEFAULT_RESOLUTION_DEPTH,
+            concurrency_limit: checker::DEFAULT_CONCURRENCY_LIMIT,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// the most check results kept
+    pub capacity: u64,
+    /// how long a check result is kept, until a write of its tenant when none
+    #[serde(default, with = "humantime_serde")]
+    pub ttl: Option<Duration>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: checker::DEFAULT_CACHE_CAPACITY,
+            ttl: None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
@@ -5,8 +5,13 @@ use aide::{
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
+    Json,
 };
+use checker::error::CheckerError;
 use indexmap::IndexMap;
+use schema::Diagnostic;
+use serde_json::json;
+use storage::StorageError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -19,7 +24,13 @@ pub enum ServerError {
     #[error("Database connect error")]
     DatabaseConnect,
     #[error("parser dsl error")]
-    ParserError,
+    ParserError(Vec<Diagnostic>),
+    #[error("invalid authz model")]
+    InvalidModel(Vec<Diagnostic>),
+    #[error("duplicate correlation id: {0}")]
+    DuplicateCorrelationId(String),
+    #[error("batch of {size} items, at most {max} are allowed")]
+    BatchTooLarge { size: usize, max: usize },
 }
 
 pub struct AppError(anyhow::Error);
@@ -27,6 +38,60 @@ pub struct AppError(anyhow::Error);
 // Tell axum how to convert `AppError` into a response.
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        if let Some(err @ (ServerError::ParserError(diagnostics) | ServerError::InvalidModel(diagnostics))) =
+            self.0.downcast_ref::<ServerError>()
+        {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "message": err.to_string(),
+                    "diagnostics": diagnostics,
+                })),
+            )
+                .into_response();
+        }
+        if let Some(err @ (ServerError::DuplicateCorrelationId(_) | ServerError::BatchTooLarge { .. })) =
+            self.0.downcast_ref::<ServerError>()
+        {
+            return (StatusCode::BAD_REQUEST, Json(json!({ "message": err.to_string() }))).into_response();
+        }
+        if let Some(
+            err @ StorageError::OrphanedTuples {
+                count,
+                relations,
+                sample,
+            },
+        ) = self.0.downcast_ref::<StorageError>()
+        {
+            return (
+                StatusCode::CONFLICT,
+                Json(json!({
+                    "message": err.to_string(),
+                    "count": count,
+                    "relations": relations,
+                    "sample": sample,
+                })),
+            )
+                .into_response();
+        }
+        // a conditional tuple written against a model lacking its condition, or before any model, or a context without
+        // a condition
+        if let Some(
+            err @ (StorageError::NotFoundCondition(_)
+            | StorageError::InvalidConditionContext { .. }
+            | StorageError::ContextWithoutCondition(_)
+            | StorageError::NotFoundAuthzModel),
+        ) = self.0.downcast_ref::<StorageError>()
+        {
+            return (StatusCode::BAD_REQUEST, Json(json!({ "message": err.to_string() }))).into_response();
+        }
+        if let Some(err @ CheckerError::ResolutionTooComplex) = self.0.downcast_ref::<CheckerError>() {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(json!({ "message": err.to_string() })),
+            )
+                .into_response();
+        }
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             format!("Something went wrong: {}", self.0),
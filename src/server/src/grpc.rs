@@ -1,7 +1,8 @@
-mod zanzibar;
+pub(crate) mod zanzibar;
 
 use std::net::SocketAddr;
 
+use crate::config::CheckConfig;
 use crate::error::ServerError;
 use crate::Server;
 use anyhow::{ensure, Result};
@@ -18,14 +19,16 @@ use tonic_reflection::server::Builder as ReflectionBuilder;
 pub struct GrpcServer {
     checker: CheckerRef,
     model_reader: AuthzModelReaderRef,
+    check_config: CheckConfig,
     shutdown_tx: Mutex<Option<Sender<()>>>,
 }
 
 impl GrpcServer {
-    pub fn new(checker: CheckerRef, model_reader: AuthzModelReaderRef) -> Self {
+    pub fn new(checker: CheckerRef, model_reader: AuthzModelReaderRef, check_config: CheckConfig) -> Self {
         Self {
             checker,
             model_reader,
+            check_config,
             shutdown_tx: Mutex::new(None),
         }
     }
@@ -63,6 +66,7 @@ impl Server for GrpcServer {
                 .add_service(FgarsServiceServer::new(zanzibar::Service {
                     checker: self.checker.clone(),
                     model_reader: self.model_reader.clone(),
+                    check_config: self.check_config.clone(),
                 }))
                 .serve_with_shutdown(listening, rx.map(drop));
             *shutdown_tx = Some(tx);
@@ -1,64 +1,49 @@
+use anyhow::anyhow;
+use checker::error::CheckerError;
 use checker::CheckRequest as InnerCheckRequest;
+use checker::CheckResult;
 use checker::CheckerRef;
+use checker::ResolutionMetadata as InnerResolutionMetadata;
 use proto::ResolutionMetadata;
+use proto::{batch_check_result::Outcome, BatchCheckReply, BatchCheckRequest, BatchCheckResult};
 use proto::{fgars_service_server::FgarsService, CheckReply, CheckRequest};
 use protocol::TupleKey;
-use storage::AuthzModelReaderRef;
+use schema::Schema;
+use storage::{AuthzModelReaderRef, StorageError};
 use tonic::{Request, Response, Status};
 use tracing::Instrument;
 
+use crate::batch::{self, ensure_batch_size, ensure_unique_correlation_ids};
+use crate::config::CheckConfig;
+
 pub struct Service {
     pub checker: CheckerRef,
     pub model_reader: AuthzModelReaderRef,
+    pub check_config: CheckConfig,
 }
 
 #[tonic::async_trait]
 impl FgarsService for Service {
     async fn check(&self, request: Request<CheckRequest>) -> Result<Response<CheckReply>, Status> {
         let req = request.into_inner();
-        let (id, model) = if let Some(model_id) = req.model_id {
-            self.model_reader
-                .clone()
-                .get(String::from(&req.tenant_id), model_id)
-                .await
-                .map_err(|err| Status::internal(err.to_string()))?
-        } else {
-            self.model_reader
-                .clone()
-                .get_latest(String::from(&req.tenant_id))
-                .await
-                .map_err(|err| Status::internal(err.to_string()))?
-        };
+        let tuple_key = req
+            .tuple_key
+            .ok_or_else(|| Status::invalid_argument("tuple_key is required"))?;
+        let (id, model) = self.model(&req.tenant_id, req.model_id).await?;
         let span = trace_span!("check");
 
-        let tuple_key = req.tuple_key.unwrap();
-
-        let contextual_tuples = req
-            .contextual_tuples
-            .into_iter()
-            .map(|ct| TupleKey {
-                user_type: ct.user_type,
-                user_id: ct.user_id,
-                user_relation: ct.user_relation,
-                relation: ct.relation,
-                object_type: ct.object_type,
-                object_id: ct.object_id,
-            })
-            .collect();
-
         let cr = InnerCheckRequest {
             tenant_id: req.tenant_id,
             model_id: id,
-            tuple_key: TupleKey {
-                user_type: tuple_key.user_type,
-                user_id: tuple_key.user_id,
-                user_relation: tuple_key.user_relation,
-                relation: tuple_key.relation,
-                object_type: tuple_key.object_type,
-                object_id: tuple_key.object_id,
-            },
-            contextual_tuples,
+            tuple_key: to_tuple_key(tuple_key),
+            contextual_tuples: req.contextual_tuples.into_iter().map(to_tuple_key).collect(),
+            context: req.context.map(proto::struct_to_json).unwrap_or_default(),
+            conditions: model.condition_map(),
             typesystem: model.to_typesystem(),
+            resolution_metadata: InnerResolutionMetadata {
+                depth: self.check_config.resolution_depth(req.resolution_depth),
+                ..Default::default()
+            },
             ..Default::default()
         };
         let result = self
@@ -67,13 +52,130 @@ impl FgarsService for Service {
             .check(cr)
             .instrument(span)
             .await
-            .map_err(|err| Status::internal(err.to_string()))?;
-        Ok(Response::new(CheckReply {
-            allow: result.allow,
-            resolution_metadata: Some(ResolutionMetadata {
-                depth: result.resolution_metadata.depth,
-                datastore_query_count: result.resolution_metadata.datastore_query_count,
-            }),
-        }))
+            .map_err(check_status)?;
+        Ok(Response::new(to_reply(result)))
+    }
+
+    async fn batch_check(&self, request: Request<BatchCheckRequest>) -> Result<Response<BatchCheckReply>, Status> {
+        let req = request.into_inner();
+        ensure_batch_size(req.items.len()).map_err(|err| Status::invalid_argument(err.to_string()))?;
+        ensure_unique_correlation_ids(req.items.iter().map(|item| item.correlation_id.as_str()))
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let (id, model) = self.model(&req.tenant_id, req.model_id).await?;
+        let span = trace_span!("batch-check");
+
+        let conditions = model.condition_map();
+        let typesystem = model.to_typesystem();
+        let depth = self.check_config.resolution_depth(req.resolution_depth);
+        let mut correlation_ids = Vec::with_capacity(req.items.len());
+        let mut requests = Vec::with_capacity(req.items.len());
+        for item in req.items {
+            // an item without a tuple key fails on its own, the others are still checked
+            let request = item
+                .tuple_key
+                .ok_or_else(|| anyhow!("missing tuple_key"))
+                .map(|tuple_key| InnerCheckRequest {
+                    tenant_id: String::from(&req.tenant_id),
+                    model_id: String::from(&id),
+                    tuple_key: to_tuple_key(tuple_key),
+                    contextual_tuples: item.contextual_tuples.into_iter().map(to_tuple_key).collect(),
+                    context: item.context.map(proto::struct_to_json).unwrap_or_default(),
+                    conditions: conditions.clone(),
+                    typesystem: typesystem.clone(),
+                    resolution_metadata: InnerResolutionMetadata {
+                        depth,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            correlation_ids.push(item.correlation_id);
+            requests.push(request);
+        }
+        let results = batch::batch_check(self.checker.clone(), requests, self.check_config.concurrency_limit)
+            .instrument(span)
+            .await;
+        let results = correlation_ids
+            .into_iter()
+            .zip(results)
+            .map(|(correlation_id, result)| BatchCheckResult {
+                correlation_id,
+                outcome: Some(match result {
+                    Ok(result) => Outcome::Reply(to_reply(result)),
+                    Err(err) => Outcome::Error(err.to_string()),
+                }),
+            })
+            .collect();
+        Ok(Response::new(BatchCheckReply { results }))
+    }
+}
+
+impl Service {
+    /// The model of the tenant with the id, the latest one when the id is none.
+    async fn model(&self, tenant_id: &str, model_id: Option<String>) -> Result<(String, Schema), Status> {
+        if let Some(model_id) = model_id {
+            self.model_reader
+                .clone()
+                .get(String::from(tenant_id), model_id)
+                .await
+                .map_err(model_status)
+        } else {
+            self.model_reader
+                .clone()
+                .get_latest(String::from(tenant_id))
+                .await
+                .map_err(model_status)
+        }
+    }
+}
+
+fn to_tuple_key(tk: proto::TupleKey) -> TupleKey {
+    TupleKey {
+        user_type: tk.user_type,
+        user_id: tk.user_id,
+        user_relation: tk.user_relation,
+        relation: tk.relation,
+        object_type: tk.object_type,
+        object_id: tk.object_id,
+        condition: tk.condition,
+        context: tk.context.map(proto::struct_to_json),
+    }
+}
+
+fn to_reply(result: CheckResult) -> CheckReply {
+    CheckReply {
+        allow: result.allow,
+        resolution_metadata: Some(ResolutionMetadata {
+            depth: result.resolution_metadata.depth,
+            datastore_query_count: result.resolution_metadata.datastore_query_count,
+            superadmin: result.resolution_metadata.superadmin,
+        }),
+    }
+}
+
+fn model_status(err: anyhow::Error) -> Status {
+    match err.downcast_ref::<StorageError>() {
+        Some(StorageError::NotFoundAuthzModel) => Status::not_found(err.to_string()),
+        _ => Status::internal(err.to_string()),
+    }
+}
+
+fn check_status(err: anyhow::Error) -> Status {
+    match err.downcast_ref::<CheckerError>() {
+        Some(CheckerError::ResolutionTooComplex) => return Status::resource_exhausted(err.to_string()),
+        // the request names a relation or a condition the model lacks, or misses condition parameters
+        Some(
+            CheckerError::NotFoundThisTypes { .. }
+            | CheckerError::NotFoundCondition(_)
+            | CheckerError::ConditionEvaluation { .. },
+        ) => return Status::invalid_argument(err.to_string()),
+        None => {}
+    }
+    match err.downcast_ref::<StorageError>() {
+        Some(
+            StorageError::NotFoundCondition(_)
+            | StorageError::InvalidConditionContext { .. }
+            | StorageError::ContextWithoutCondition(_),
+        ) => Status::invalid_argument(err.to_string()),
+        _ => Status::internal(err.to_string()),
     }
 }
@@ -1,4 +1,4 @@
-mod authz_model;
+pub(crate) mod authz_model;
 mod tenant;
 mod zanzibar;
 
@@ -8,7 +8,7 @@ use aide::{
     redoc::Redoc,
     scalar::Scalar,
 };
-use std::{net::SocketAddr, sync::Arc, time::Duration};
+use std::{net::SocketAddr, sync::Arc};
 use tower_http::trace::TraceLayer;
 
 use checker::{
@@ -16,13 +16,12 @@ use checker::{
     CheckerRef,
 };
 use futures::FutureExt;
-use serde::{Deserialize, Serialize};
 use storage::{
     AuthzModelReaderRef, AuthzModelWriterRef, RelationshipTupleReaderRef, RelationshipTupleWriterRef, TenantOperatorRef,
 };
 use tokio::sync::oneshot::{self, Sender};
 
-use crate::{error::ServerError, Server};
+use crate::{config::CheckConfig, error::ServerError, Server};
 use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use axum::{extract::MatchedPath, http::Request, routing::get, Extension, Json, Router};
@@ -38,27 +37,12 @@ pub struct HttpServer {
     expander: Arc<Expander>,
     objects_expander: Arc<ObjectsExpander>,
     users_expander: Arc<UsersExpander>,
+    check_config: CheckConfig,
     shutdown_tx: Mutex<Option<Sender<()>>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(default)]
-pub struct HttpOptions {
-    pub addr: String,
-    #[serde(with = "humantime_serde")]
-    pub timeout: Duration,
-}
-
-impl Default for HttpOptions {
-    fn default() -> Self {
-        Self {
-            addr: "127.0.0.1:4000".to_string(),
-            timeout: Duration::from_secs(30),
-        }
-    }
-}
-
 impl HttpServer {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tuple_reader: RelationshipTupleReaderRef,
         tuple_writer: RelationshipTupleWriterRef,
@@ -69,6 +53,7 @@ impl HttpServer {
         expander: Arc<Expander>,
         objects_expander: Arc<ObjectsExpander>,
         users_expander: Arc<UsersExpander>,
+        check_config: CheckConfig,
     ) -> Self {
         Self {
             tuple_reader,
@@ -80,6 +65,7 @@ impl HttpServer {
             expander,
             objects_expander,
             users_expander,
+            check_config,
             shutdown_tx: Mutex::new(None),
         }
     }
@@ -153,7 +139,19 @@ impl HttpServer {
             )
             .api_route(
                 "/zanzibar/:tenant_id/check",
-                apirouting::post(zanzibar::check_x).with_state((self.checker.clone(), self.authz_model_reader.clone())),
+                apirouting::post(zanzibar::check_x).with_state((
+                    self.checker.clone(),
+                    self.authz_model_reader.clone(),
+                    self.check_config.clone(),
+                )),
+            )
+            .api_route(
+                "/zanzibar/:tenant_id/batch-check",
+                apirouting::post(zanzibar::batch_check).with_state((
+                    self.checker.clone(),
+                    self.authz_model_reader.clone(),
+                    self.check_config.clone(),
+                )),
             )
             .api_route(
                 "/zanzibar/:tenant_id/expand",
@@ -179,13 +177,29 @@ impl HttpServer {
                 "/authz_models/:tenant_id/dsl",
                 apirouting::post(authz_model::create_by_dsl).with_state(self.authz_model_writer.clone()),
             )
+            .api_route(
+                "/authz_models/:tenant_id/openfga",
+                apirouting::post(authz_model::create_by_openfga).with_state(self.authz_model_writer.clone()),
+            )
             .api_route(
                 "/authz_models/:tenant_id",
                 apirouting::get(authz_model::list).with_state(self.authz_model_reader.clone()),
             )
+            .api_route(
+                "/authz_models/:tenant_id/diff",
+                apirouting::get(authz_model::diff).with_state(self.authz_model_reader.clone()),
+            )
             .api_route(
                 "/authz_models/:tenant_id/:id",
                 apirouting::get(authz_model::get).with_state(self.authz_model_reader.clone()),
+            )
+            .api_route(
+                "/authz_models/:tenant_id/:id/dsl",
+                apirouting::get(authz_model::get_dsl).with_state(self.authz_model_reader.clone()),
+            )
+            .api_route(
+                "/authz_models/:tenant_id/:id/openfga",
+                apirouting::get(authz_model::get_openfga).with_state(self.authz_model_reader.clone()),
             );
         let tenant_route = ApiRouter::new()
             .api_route("/tenants", apirouting::get(tenant::list))
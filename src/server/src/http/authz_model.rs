@@ -1,7 +1,11 @@
+use std::collections::HashMap;
+
 use crate::error::{Result, ServerError};
 use axum::extract::{Json, Path, Query, State};
-use protocol::Tenant;
-use schema::Schema;
+use schema::{
+    openfga::{self, AuthorizationModel},
+    Change, Diagnostic, Schema,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use storage::{AuthzModelReaderRef, AuthzModelWriterRef, Pagination};
@@ -22,11 +26,9 @@ impl From<(Vec<(String, Schema)>, Option<u64>)> for ReadResult {
         Self {
             models: schemas
                 .iter()
-                .map(|(id, schema)| {
-                    return Model {
-                        id: id.to_string(),
-                        model: schema.to_owned(),
-                    };
+                .map(|(id, schema)| Model {
+                    id: id.to_string(),
+                    model: schema.to_owned(),
                 })
                 .collect(),
             total: total.map(|x| x as u32),
@@ -37,6 +39,15 @@ impl From<(Vec<(String, Schema)>, Option<u64>)> for ReadResult {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub struct CreateByDslRequest {
     dsl: String,
+    /// sources of the files imported by `dsl`, by import path
+    #[serde(default)]
+    files: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct CreateByDslResult {
+    /// deprecated syntax found in `dsl` or its imports, the model is saved anyway
+    pub warnings: Vec<Diagnostic>,
 }
 
 #[axum::debug_handler]
@@ -49,37 +60,142 @@ pub async fn list(
     Ok(Json(result.into()))
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+pub struct SaveQuery {
+    /// save even if existing tuples do not fit the new model
+    #[serde(default)]
+    force: bool,
+}
+
 #[axum::debug_handler]
 pub async fn create(
     State(state): State<AuthzModelWriterRef>,
     Path(tenant_id): Path<String>,
+    Query(query): Query<SaveQuery>,
     Json(cr): Json<Schema>,
 ) -> Result<Json<()>> {
-    let _ = state.save(tenant_id, cr).await?;
+    validate(&cr)?;
+    state.save(tenant_id, cr, query.force).await?;
     Ok(Json(()))
 }
 
+/// name of the posted dsl in diagnostics
+const DSL_FILE: &str = "dsl";
+
 #[axum::debug_handler]
 pub async fn create_by_dsl(
     State(state): State<AuthzModelWriterRef>,
     Path(tenant_id): Path<String>,
+    Query(query): Query<SaveQuery>,
     Json(cr): Json<CreateByDslRequest>,
+) -> Result<Json<CreateByDslResult>> {
+    let files = cr.files;
+    let (model, sources) = schema::parse_with_imports(DSL_FILE, &cr.dsl, |path| {
+        files.get(path).cloned().ok_or_else(|| "not found in files".to_string())
+    })
+    .map_err(ServerError::ParserError)?;
+    let diagnostics = schema::validate(&model);
+    if !diagnostics.is_empty() {
+        let diagnostics = diagnostics.into_iter().map(|d| sources.locate(d)).collect();
+        return Err(ServerError::InvalidModel(diagnostics).into());
+    }
+    let warnings = model.warnings.clone();
+    state.save(tenant_id, model, query.force).await?;
+    Ok(Json(CreateByDslResult { warnings }))
+}
+
+#[axum::debug_handler]
+pub async fn create_by_openfga(
+    State(state): State<AuthzModelWriterRef>,
+    Path(tenant_id): Path<String>,
+    Query(query): Query<SaveQuery>,
+    Json(cr): Json<AuthorizationModel>,
 ) -> Result<Json<()>> {
-    let cr = schema::parse(&cr.dsl).map_err(|e| {
-        error!("{:?}", e);
-        return ServerError::ParserError;
-    })?;
-    let _ = state.save(tenant_id, cr.0).await?;
+    let model = openfga::from_openfga(&cr).map_err(ServerError::InvalidModel)?;
+    validate(&model)?;
+    state.save(tenant_id, model, query.force).await?;
     Ok(Json(()))
 }
 
+fn validate(model: &Schema) -> Result<()> {
+    let diagnostics = schema::validate(model);
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(ServerError::InvalidModel(diagnostics).into())
+    }
+}
+
 #[axum::debug_handler]
-#[allow(unused)]
 pub async fn get(
     State(state): State<AuthzModelReaderRef>,
     Path((tenant_id, id)): Path<(String, String)>,
-) -> Result<Json<Tenant>> {
-    // let result = state.get(id).await?;
-    // Ok(Json(result))
-    todo!()
+) -> Result<Json<Model>> {
+    let (id, model) = state.get(tenant_id, id).await?;
+    Ok(Json(Model { id, model }))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct DslResult {
+    id: String,
+    dsl: String,
+}
+
+#[axum::debug_handler]
+pub async fn get_dsl(
+    State(state): State<AuthzModelReaderRef>,
+    Path((tenant_id, id)): Path<(String, String)>,
+) -> Result<Json<DslResult>> {
+    let (id, model) = state.get(tenant_id, id).await?;
+    Ok(Json(DslResult {
+        id,
+        dsl: schema::format(&model),
+    }))
+}
+
+#[axum::debug_handler]
+pub async fn get_openfga(
+    State(state): State<AuthzModelReaderRef>,
+    Path((tenant_id, id)): Path<(String, String)>,
+) -> Result<Json<AuthorizationModel>> {
+    let (id, model) = state.get(tenant_id, id).await?;
+    Ok(Json(AuthorizationModel {
+        id: Some(id),
+        ..openfga::to_openfga(&model)
+    }))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct DiffQuery {
+    from: String,
+    /// defaults to the latest model
+    to: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct DiffResult {
+    from: String,
+    to: String,
+    breaking: bool,
+    changes: Vec<Change>,
+}
+
+#[axum::debug_handler]
+pub async fn diff(
+    State(state): State<AuthzModelReaderRef>,
+    Path(tenant_id): Path<String>,
+    Query(query): Query<DiffQuery>,
+) -> Result<Json<DiffResult>> {
+    let (from, old) = state.get(tenant_id.clone(), query.from).await?;
+    let (to, new) = match query.to {
+        Some(to) => state.get(tenant_id, to).await?,
+        None => state.get_latest(tenant_id).await?,
+    };
+    let changes = schema::diff(&old, &new);
+    Ok(Json(DiffResult {
+        from,
+        to,
+        breaking: changes.iter().any(Change::is_breaking),
+        changes,
+    }))
 }
@@ -37,7 +37,7 @@ pub async fn list(State(state): State<TenantOperatorRef>, page: Option<Query<Pag
 
 #[axum::debug_handler]
 pub async fn create(State(state): State<TenantOperatorRef>, Json(cr): Json<CreateRequest>) -> Result<Json<()>> {
-    let _ = state.create(cr.id, cr.name).await?;
+    state.create(cr.id, cr.name).await?;
     Ok(Json(()))
 }
 
@@ -49,6 +49,6 @@ pub async fn get(State(state): State<TenantOperatorRef>, Path(id): Path<String>)
 
 #[axum::debug_handler]
 pub async fn delete(State(state): State<TenantOperatorRef>, Path(id): Path<String>) -> Result<Json<()>> {
-    let _ = state.delete(id).await?;
+    state.delete(id).await?;
     Ok(Json(()))
 }
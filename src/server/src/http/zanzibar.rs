@@ -1,4 +1,7 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use axum::{
     extract::{Path, Query, State},
@@ -6,7 +9,7 @@ use axum::{
 };
 use checker::{
     expander::{ExpandTree, Expander, ObjectsExpander, UsersExpander},
-    CheckRequest, CheckResult, CheckerRef,
+    CheckRequest, CheckResult, CheckerRef, ResolutionMetadata,
 };
 use protocol::{Tuple, TupleKey};
 use schemars::JsonSchema;
@@ -14,7 +17,11 @@ use serde::{Deserialize, Serialize};
 use storage::{AuthzModelReaderRef, Pagination, RelationshipTupleReaderRef, RelationshipTupleWriterRef, TupleFilter};
 use tracing::Instrument;
 
-use crate::error::Result;
+use crate::{
+    batch::{self, ensure_batch_size, ensure_unique_correlation_ids},
+    config::CheckConfig,
+    error::Result,
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub struct ReadResult {
@@ -28,6 +35,48 @@ pub struct CheckReq {
     tuple_key: TupleKey,
     #[serde(default)]
     contextual_tuples: Vec<TupleKey>,
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
+    /// the most dispatches of the check, at most the limit of the server
+    #[serde(default)]
+    resolution_depth: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct BatchCheckReq {
+    model_id: Option<String>,
+    /// at most 50 items
+    items: Vec<BatchCheckItem>,
+    /// the most dispatches of each check, at most the limit of the server
+    #[serde(default)]
+    resolution_depth: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct BatchCheckItem {
+    /// tells the result of the item apart, unique in the batch
+    correlation_id: String,
+    tuple_key: TupleKey,
+    #[serde(default)]
+    contextual_tuples: Vec<TupleKey>,
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct BatchCheckResp {
+    /// in the order of the items
+    results: Vec<BatchCheckItemResult>,
+}
+
+/// The result of an item, or the error that failed it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+pub struct BatchCheckItemResult {
+    correlation_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<CheckResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
@@ -36,6 +85,11 @@ pub struct ExpandReq {
     relation: String,
     object_type: String,
     object_id: String,
+    #[serde(default)]
+    contextual_tuples: Vec<TupleKey>,
+    /// values for the condition parameters of the tuples
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
@@ -46,6 +100,11 @@ pub struct ExpandObjectsReq {
     user_type: String,
     user_id: String,
     user_relation: Option<String>,
+    #[serde(default)]
+    contextual_tuples: Vec<TupleKey>,
+    /// values for the condition parameters of the tuples
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
@@ -61,6 +120,11 @@ pub struct ExpandUsersReq {
     object_id: String,
     user_type: String,
     user_relation: Option<String>,
+    #[serde(default)]
+    contextual_tuples: Vec<TupleKey>,
+    /// values for the condition parameters of the tuples
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
 }
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 pub struct ExpandUsersResp {
@@ -104,7 +168,7 @@ pub async fn write_delete(
     Path(tenant_id): Path<String>,
     Json(filter): Json<TupleFilter>,
 ) -> Result<Json<()>> {
-    let _ = state.delete(&tenant_id, filter).await?;
+    state.delete(&tenant_id, filter).await?;
     Ok(Json(()))
 }
 
@@ -117,7 +181,7 @@ pub async fn watch(Path(tenant_id): Path<String>) -> Result<Json<ReadResult>> {
 // define check will fail
 #[axum::debug_handler]
 pub async fn check_x(
-    State((checker, model_reader)): State<(CheckerRef, AuthzModelReaderRef)>,
+    State((checker, model_reader, config)): State<(CheckerRef, AuthzModelReaderRef, CheckConfig)>,
     Path(tenant_id): Path<String>,
     Json(req): Json<CheckReq>,
 ) -> Result<Json<CheckResult>> {
@@ -133,13 +197,80 @@ pub async fn check_x(
         model_id: id,
         tuple_key: req.tuple_key,
         contextual_tuples: req.contextual_tuples,
+        context: req.context,
+        conditions: model.condition_map(),
         typesystem: model.to_typesystem(),
+        resolution_metadata: ResolutionMetadata {
+            depth: config.resolution_depth(req.resolution_depth),
+            ..Default::default()
+        },
         ..Default::default()
     };
     let result = checker.check(cr).instrument(span).await?;
     Ok(Json(result))
 }
 
+#[axum::debug_handler]
+pub async fn batch_check(
+    State((checker, model_reader, config)): State<(CheckerRef, AuthzModelReaderRef, CheckConfig)>,
+    Path(tenant_id): Path<String>,
+    Json(req): Json<BatchCheckReq>,
+) -> Result<Json<BatchCheckResp>> {
+    ensure_batch_size(req.items.len())?;
+    ensure_unique_correlation_ids(req.items.iter().map(|item| item.correlation_id.as_str()))?;
+    let (id, model) = if let Some(model_id) = req.model_id {
+        model_reader.get(String::from(&tenant_id), model_id).await?
+    } else {
+        model_reader.get_latest(String::from(&tenant_id)).await?
+    };
+    let span = trace_span!("batch-check");
+
+    let conditions = model.condition_map();
+    let typesystem = model.to_typesystem();
+    let depth = config.resolution_depth(req.resolution_depth);
+    let (correlation_ids, requests): (Vec<_>, Vec<_>) = req
+        .items
+        .into_iter()
+        .map(|item| {
+            let cr = CheckRequest {
+                tenant_id: String::from(&tenant_id),
+                model_id: String::from(&id),
+                tuple_key: item.tuple_key,
+                contextual_tuples: item.contextual_tuples,
+                context: item.context,
+                conditions: conditions.clone(),
+                typesystem: typesystem.clone(),
+                resolution_metadata: ResolutionMetadata {
+                    depth,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            (item.correlation_id, Ok(cr))
+        })
+        .unzip();
+    let results = batch::batch_check(checker, requests, config.concurrency_limit)
+        .instrument(span)
+        .await;
+    let results = correlation_ids
+        .into_iter()
+        .zip(results)
+        .map(|(correlation_id, result)| match result {
+            Ok(result) => BatchCheckItemResult {
+                correlation_id,
+                result: Some(result),
+                error: None,
+            },
+            Err(err) => BatchCheckItemResult {
+                correlation_id,
+                result: None,
+                error: Some(err.to_string()),
+            },
+        })
+        .collect();
+    Ok(Json(BatchCheckResp { results }))
+}
+
 #[axum::debug_handler]
 pub async fn expand(
     State((expander, model_reader)): State<(Arc<Expander>, AuthzModelReaderRef)>,
@@ -151,6 +282,12 @@ pub async fn expand(
     } else {
         model_reader.get_latest(String::from(&tenant_id)).await?
     };
+    let expander = expander.with_context(model.condition_map(), req.context);
+    let expander = if req.contextual_tuples.is_empty() {
+        expander
+    } else {
+        expander.with_contextual_tuples(req.contextual_tuples.into_iter().map(Tuple::from).collect())
+    };
     let result = expander
         .expand(
             model.to_typesystem(),
@@ -175,6 +312,12 @@ pub async fn expand_objects(
         model_reader.get_latest(String::from(&tenant_id)).await?
     };
 
+    let expander = expander.with_context(model.condition_map(), req.context);
+    let expander = if req.contextual_tuples.is_empty() {
+        expander
+    } else {
+        expander.with_contextual_tuples(req.contextual_tuples.into_iter().map(Tuple::from).collect())
+    };
     let object_ids = expander
         .objects(
             model.to_typesystem(),
@@ -201,6 +344,12 @@ pub async fn expand_users(
     } else {
         model_reader.get_latest(String::from(&tenant_id)).await?
     };
+    let expander = expander.with_context(model.condition_map(), req.context);
+    let expander = if req.contextual_tuples.is_empty() {
+        expander
+    } else {
+        expander.with_contextual_tuples(req.contextual_tuples.into_iter().map(Tuple::from).collect())
+    };
     let user_ids = expander
         .users(
             model.to_typesystem(),
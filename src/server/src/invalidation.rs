@@ -0,0 +1,53 @@
+use anyhow::Result;
+use checker::Generations;
+use protocol::Tuple;
+use schema::Schema;
+use storage::{
+    AuthzModelWriter, AuthzModelWriterRef, RelationshipTupleWriter, RelationshipTupleWriterRef, TupleFilter,
+};
+
+/// Writes tuples and models through to the storage, and bumps the generation of the tenant once a write succeeded so
+/// that a revoked access is not allowed from the cache, nor from a check that was in flight.
+pub struct InvalidatingWriter {
+    tuple_writer: RelationshipTupleWriterRef,
+    model_writer: AuthzModelWriterRef,
+    generations: Generations,
+}
+
+impl InvalidatingWriter {
+    pub fn new(
+        tuple_writer: RelationshipTupleWriterRef,
+        model_writer: AuthzModelWriterRef,
+        generations: Generations,
+    ) -> Self {
+        Self {
+            tuple_writer,
+            model_writer,
+            generations,
+        }
+    }
+}
+
+#[async_trait]
+impl RelationshipTupleWriter for InvalidatingWriter {
+    async fn save(&self, tenant_id: &str, tuples: Vec<Tuple>) -> Result<()> {
+        self.tuple_writer.save(tenant_id, tuples).await?;
+        self.generations.bump(tenant_id);
+        Ok(())
+    }
+
+    async fn delete(&self, tenant_id: &str, filter: TupleFilter) -> Result<()> {
+        self.tuple_writer.delete(tenant_id, filter).await?;
+        self.generations.bump(tenant_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuthzModelWriter for InvalidatingWriter {
+    async fn save(&self, tenant_id: String, model: Schema, force: bool) -> Result<()> {
+        self.model_writer.save(tenant_id.clone(), model, force).await?;
+        self.generations.bump(&tenant_id);
+        Ok(())
+    }
+}
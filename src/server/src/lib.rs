@@ -1,12 +1,15 @@
+mod batch;
 pub mod config;
 mod error;
 mod grpc;
 mod http;
+mod invalidation;
 
 use anyhow::Result;
 use checker::expander::{Expander, ObjectsExpander, UsersExpander};
 use config::Config;
 use http::HttpServer;
+use invalidation::InvalidatingWriter;
 use sea_orm::{ConnectOptions, Database};
 use std::{net::SocketAddr, sync::Arc};
 use storage::sea;
@@ -18,6 +21,9 @@ extern crate tracing;
 #[macro_use]
 extern crate async_trait;
 
+#[cfg(test)]
+mod tests;
+
 #[async_trait]
 pub trait Server: Send + Sync {
     async fn shutdown(&self) -> Result<()>;
@@ -39,9 +45,7 @@ impl Servers {
         let conn = Database::connect(options).await.unwrap();
         let storage = Arc::new(sea::Storage::new(Arc::new(conn)));
         let tuple_reader = storage.clone();
-        let tuple_writer = storage.clone();
         let authz_model_reader = storage.clone();
-        let authz_model_writer = storage.clone();
         let tenant_operator = storage.clone();
 
         let expander = Arc::new(Expander::new(tuple_reader.clone()));
@@ -50,8 +54,19 @@ impl Servers {
 
         // config distributed: if distributed { remote } else { local }
         // let resolver = Arc::new(checker::RemoteChecker::new());
-        let local_checker = Arc::new(checker::LocalChecker::new(None, storage.clone()));
-        let cache_checker = Arc::new(checker::CacheChecker::new(local_checker.clone()));
+        let generations = checker::Generations::default();
+        let singleflight_checker = checker::SingleflightChecker::new_local(
+            storage.clone(),
+            config.check.concurrency_limit,
+            generations.clone(),
+        );
+        let cache_checker = Arc::new(
+            checker::CacheChecker::with_options(singleflight_checker.clone(), config.cache.capacity, config.cache.ttl)
+                .with_generations(generations.clone()),
+        );
+        let writer = Arc::new(InvalidatingWriter::new(storage.clone(), storage.clone(), generations));
+        let tuple_writer = writer.clone();
+        let authz_model_writer = writer;
 
         let mut servers = Vec::<(Box<dyn Server>, SocketAddr)>::with_capacity(2);
         if let Some(http) = &config.http {
@@ -65,11 +80,12 @@ impl Servers {
                 expander,
                 objects_expander,
                 users_expander,
+                config.check.clone(),
             );
             servers.push((Box::new(server), http.addr.parse::<SocketAddr>().unwrap()));
         }
         if let Some(grpc) = &config.grpc {
-            let server = GrpcServer::new(cache_checker.clone(), authz_model_reader.clone());
+            let server = GrpcServer::new(cache_checker.clone(), authz_model_reader.clone(), config.check.clone());
             servers.push((Box::new(server), grpc.addr.parse::<SocketAddr>().unwrap()));
         }
 
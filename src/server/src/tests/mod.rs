@@ -0,0 +1,328 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use checker::{error::CheckerError, CheckRequest, CheckResult, Checker, Generations, Memo};
+use proto::fgars_service_server::FgarsService;
+use protocol::{Tuple, TupleKey};
+use schema::Schema;
+use storage::{AuthzModelReader, AuthzModelWriter, Pagination, RelationshipTupleWriter, StorageError, TupleFilter};
+
+use crate::{
+    batch::{self, ensure_batch_size, ensure_unique_correlation_ids, MAX_BATCH_SIZE},
+    config::{CacheConfig, CheckConfig, Config},
+    error::{AppError, ServerError},
+    grpc::zanzibar,
+    http::authz_model::{self, SaveQuery},
+    invalidation::InvalidatingWriter,
+};
+
+/// Succeeds or fails every write, without storing anything.
+struct Writer(bool);
+
+impl Writer {
+    fn result(&self) -> Result<()> {
+        if self.0 {
+            Ok(())
+        } else {
+            Err(anyhow!("write failed"))
+        }
+    }
+}
+
+#[async_trait]
+impl RelationshipTupleWriter for Writer {
+    async fn save(&self, _tenant_id: &str, _tuples: Vec<Tuple>) -> Result<()> {
+        self.result()
+    }
+
+    async fn delete(&self, _tenant_id: &str, _filter: TupleFilter) -> Result<()> {
+        self.result()
+    }
+}
+
+#[async_trait]
+impl AuthzModelWriter for Writer {
+    async fn save(&self, _tenant_id: String, _model: Schema, _force: bool) -> Result<()> {
+        self.result()
+    }
+}
+
+#[tokio::test]
+async fn test_invalidating_writer() {
+    for succeeds in [true, false] {
+        let generations = Generations::default();
+        let writer = Arc::new(Writer(succeeds));
+        let writer = InvalidatingWriter::new(writer.clone(), writer, generations.clone());
+
+        assert_eq!(
+            RelationshipTupleWriter::save(&writer, "1", vec![]).await.is_ok(),
+            succeeds
+        );
+        assert_eq!(generations.get("1"), u64::from(succeeds));
+        assert_eq!(writer.delete("1", TupleFilter::default()).await.is_ok(), succeeds);
+        assert_eq!(generations.get("1"), 2 * u64::from(succeeds));
+        let saved = AuthzModelWriter::save(&writer, String::from("1"), Schema::default(), false).await;
+        assert_eq!(saved.is_ok(), succeeds);
+        assert_eq!(generations.get("1"), 3 * u64::from(succeeds));
+        assert_eq!(generations.get("2"), 0);
+    }
+}
+
+/// Serves an empty model to the tenant `1`, no model to the others.
+struct Reader;
+
+#[async_trait]
+impl AuthzModelReader for Reader {
+    async fn get_latest(&self, tenant_id: String) -> Result<(String, Schema)> {
+        (tenant_id == "1")
+            .then(|| (String::from("1"), Schema::default()))
+            .context(StorageError::NotFoundAuthzModel)
+    }
+
+    async fn get(&self, tenant_id: String, _id: String) -> Result<(String, Schema)> {
+        self.get_latest(tenant_id).await
+    }
+
+    async fn list(
+        &self,
+        _tenant_id: String,
+        _page: Option<Pagination>,
+    ) -> Result<(Vec<(String, Schema)>, Option<u64>)> {
+        Ok((vec![], None))
+    }
+}
+
+/// Allows the user `allow`, fails on the user `error` and on the user `condition` with a missing condition, after
+/// waiting the object id in milliseconds. Keeps the memo of every check.
+#[derive(Default)]
+struct Recorder(Mutex<Vec<Option<Memo>>>);
+
+#[async_trait]
+impl Checker for Recorder {
+    async fn check(&self, req: CheckRequest) -> Result<CheckResult> {
+        self.0.lock().unwrap().push(req.memo);
+        tokio::time::sleep(Duration::from_millis(req.tuple_key.object_id.parse()?)).await;
+        match req.tuple_key.user_id.as_str() {
+            "error" => Err(anyhow!("check failed")),
+            "condition" => Err(CheckerError::NotFoundCondition(String::from("c")).into()),
+            user_id => Ok(CheckResult {
+                allow: user_id == "allow",
+                ..Default::default()
+            }),
+        }
+    }
+
+    async fn close(&self) {}
+
+    fn name(&self) -> &str {
+        "recorder"
+    }
+}
+
+#[tokio::test]
+async fn test_batch_check() {
+    let request = |user_id: &str, delay: u64| {
+        Ok(CheckRequest {
+            tuple_key: TupleKey {
+                user_id: String::from(user_id),
+                object_id: delay.to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    };
+    let checker = Arc::new(Recorder::default());
+    // the first items are the slowest, the results still follow the requests
+    let results = batch::batch_check(
+        checker.clone(),
+        vec![
+            request("allow", 30),
+            request("deny", 20),
+            Err(anyhow!("missing tuple_key")),
+            request("error", 10),
+            request("allow", 0),
+        ],
+        4,
+    )
+    .await;
+    let results: Vec<_> = results
+        .into_iter()
+        .map(|result| result.map(|r| r.allow).map_err(|err| err.to_string()))
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            Ok(true),
+            Ok(false),
+            Err(String::from("missing tuple_key")),
+            Err(String::from("check failed")),
+            Ok(true),
+        ]
+    );
+
+    // the item that could not be read is not checked, the others share one memo
+    let memos = checker.0.lock().unwrap();
+    assert_eq!(memos.len(), 4);
+    assert!(memos[0].is_some());
+    assert!(memos.iter().all(|memo| memo == &memos[0]));
+}
+
+#[test]
+fn test_batch_validation() {
+    assert!(ensure_unique_correlation_ids(["a", "b", "c"]).is_ok());
+    assert!(ensure_unique_correlation_ids([]).is_ok());
+    assert!(matches!(
+        ensure_unique_correlation_ids(["a", "b", "a", "b"]),
+        Err(ServerError::DuplicateCorrelationId(id)) if id == "a"
+    ));
+
+    assert!(ensure_batch_size(MAX_BATCH_SIZE).is_ok());
+    assert!(matches!(
+        ensure_batch_size(MAX_BATCH_SIZE + 1),
+        Err(ServerError::BatchTooLarge { size, max }) if size == MAX_BATCH_SIZE + 1 && max == MAX_BATCH_SIZE
+    ));
+}
+
+#[test]
+fn test_config_defaults() {
+    let config: Config = serde_json::from_value(serde_json::json!({
+        "datasource": {"uri": "sqlite::memory:", "schema": "public"},
+        "check": {"resolution_depth": 5},
+        "cache": {"ttl": "30s"},
+    }))
+    .unwrap();
+    assert_eq!(
+        config.check,
+        CheckConfig {
+            resolution_depth: 5,
+            concurrency_limit: checker::DEFAULT_CONCURRENCY_LIMIT,
+        }
+    );
+    assert_eq!(
+        config.cache,
+        CacheConfig {
+            capacity: checker::DEFAULT_CACHE_CAPACITY,
+            ttl: Some(Duration::from_secs(30)),
+        }
+    );
+}
+
+#[test]
+fn test_error_status() {
+    let status = |err: anyhow::Error| AppError::from(err).into_response().status();
+    assert_eq!(
+        status(StorageError::NotFoundCondition("c".to_string()).into()),
+        StatusCode::BAD_REQUEST
+    );
+    let context = serde_json::from_value(serde_json::json!({ "x": 1 })).unwrap();
+    let condition_error = schema::parse("condition c(x: string) {\n  x == \"a\"\n}")
+        .unwrap()
+        .0
+        .conditions[0]
+        .validate_context(&context)
+        .unwrap_err();
+    assert_eq!(
+        status(
+            StorageError::InvalidConditionContext {
+                name: "c".to_string(),
+                source: condition_error,
+            }
+            .into()
+        ),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        status(StorageError::ContextWithoutCondition("doc:1#viewer".to_string()).into()),
+        StatusCode::BAD_REQUEST
+    );
+    // the model lookup adds the error as context
+    assert_eq!(
+        status(None::<()>.context(StorageError::NotFoundAuthzModel).unwrap_err()),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        status(CheckerError::ResolutionTooComplex.into()),
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(status(anyhow!("boom")), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_create_by_dsl_warnings() {
+    let request = serde_json::from_value(serde_json::json!({
+        "dsl": "import \"folder.authz\"\ntype user {}",
+        "files": {
+            "folder.authz": "type folder {\n  relation parent: folder\n  relation viewer: user\n  permission view: viewer + parent#view\n}",
+        },
+    }))
+    .unwrap();
+    let Ok(Json(result)) = authz_model::create_by_dsl(
+        State(Arc::new(Writer(true))),
+        Path("1".to_string()),
+        Query(SaveQuery::default()),
+        Json(request),
+    )
+    .await
+    else {
+        panic!("the model should be saved");
+    };
+    assert_eq!(result.warnings.len(), 1);
+    let warning = &result.warnings[0];
+    assert_eq!(warning.file.as_deref(), Some("folder.authz"));
+    assert_eq!(
+        warning.message,
+        "`parent#view` in a permission is deprecated, use `parent->view` for a tuple to userset"
+    );
+}
+
+fn grpc_service() -> zanzibar::Service {
+    zanzibar::Service {
+        checker: Arc::new(Recorder::default()),
+        model_reader: Arc::new(Reader),
+        check_config: CheckConfig::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_grpc_check_status() {
+    let service = grpc_service();
+    let status = service
+        .check(tonic::Request::new(proto::CheckRequest {
+            tenant_id: String::from("1"),
+            ..Default::default()
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert_eq!(status.message(), "tuple_key is required");
+
+    let check = |tenant_id: &str, user_id: &str| {
+        service.check(tonic::Request::new(proto::CheckRequest {
+            tenant_id: String::from(tenant_id),
+            tuple_key: Some(proto::TupleKey {
+                user_type: String::from("user"),
+                user_id: String::from(user_id),
+                relation: String::from("viewer"),
+                object_type: String::from("doc"),
+                object_id: String::from("0"),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+    };
+    assert!(check("1", "allow").await.unwrap().into_inner().allow);
+    assert_eq!(check("2", "allow").await.unwrap_err().code(), tonic::Code::NotFound);
+    assert_eq!(
+        check("1", "condition").await.unwrap_err().code(),
+        tonic::Code::InvalidArgument
+    );
+    assert_eq!(check("1", "error").await.unwrap_err().code(), tonic::Code::Internal);
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use protocol::Tuple;
+
+use crate::{Pagination, RelationshipTupleReader, RelationshipTupleReaderRef, TupleFilter};
+
+/// Reads the tuples sent along with a request as if they were stored, they are never written. A paginated read gets
+/// them on its first page.
+pub struct ContextualTupleReader {
+    inner: RelationshipTupleReaderRef,
+    tuples: Vec<Tuple>,
+}
+
+impl ContextualTupleReader {
+    pub fn new(inner: RelationshipTupleReaderRef, tuples: Vec<Tuple>) -> Self {
+        Self { inner, tuples }
+    }
+}
+
+#[async_trait]
+impl RelationshipTupleReader for ContextualTupleReader {
+    async fn list(
+        &self,
+        tenant_id: &str,
+        filter: TupleFilter,
+        page: Option<Pagination>,
+    ) -> Result<(Vec<Tuple>, Option<u64>)> {
+        let first = page.as_ref().is_none_or(|page| page.page == 1);
+        let (mut tuples, total) = self.inner.list(tenant_id, filter.clone(), page).await?;
+        if first {
+            merge_contextual(&mut tuples, &self.tuples, &filter);
+        }
+        Ok((tuples, total))
+    }
+}
+
+/// Appends the contextual tuples selected by `filter` that are not stored already.
+pub fn merge_contextual(tuples: &mut Vec<Tuple>, contextual: &[Tuple], filter: &TupleFilter) {
+    for t in contextual {
+        if filter.matches(t) && !tuples.contains(t) {
+            tuples.push(t.clone());
+        }
+    }
+}
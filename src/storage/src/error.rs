@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,4 +8,20 @@ pub enum StorageError {
     NotFoundAuthzModel,
     #[error("Not found tenant")]
     NotFoundTenant,
+    #[error("Not found condition {0} in the latest authz model")]
+    NotFoundCondition(String),
+    #[error("Invalid context of condition {name}: {source}")]
+    InvalidConditionContext {
+        name: String,
+        source: schema::ConditionError,
+    },
+    #[error("Context given to {0} without a condition")]
+    ContextWithoutCondition(String),
+    #[error("The authz model would orphan {count} existing tuples, save it with force to override")]
+    OrphanedTuples {
+        count: u64,
+        /// orphaned tuples by `object_type#relation`
+        relations: BTreeMap<String, u64>,
+        sample: Vec<protocol::Tuple>,
+    },
 }
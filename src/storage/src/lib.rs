@@ -1,6 +1,10 @@
+mod contextual;
 mod error;
 pub mod sea;
 
+pub use contextual::*;
+pub use error::StorageError;
+
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -30,6 +34,34 @@ pub struct TupleFilter {
     pub or: Option<Vec<TupleFilter>>,
 }
 
+impl TupleFilter {
+    /// Whether the tuple is selected by the filter, as the datastore would select it.
+    pub fn matches(&self, t: &Tuple) -> bool {
+        let eq = |expected: &Option<String>, actual: &str| expected.as_ref().is_none_or(|e| e.eq(actual));
+        let is_in = |expected: &Option<Vec<String>>, actual: &str| {
+            expected.as_ref().is_none_or(|e| e.iter().any(|x| x.eq(actual)))
+        };
+        eq(&self.object_type_eq, &t.object_type)
+            && eq(&self.object_id_eq, &t.object_id)
+            && is_in(&self.object_id_in, &t.object_id)
+            && eq(&self.relation_eq, &t.relation)
+            && eq(&self.user_type_eq, &t.user_type)
+            && eq(&self.user_id_eq, &t.user_id)
+            && is_in(&self.user_id_in, &t.user_id)
+            && self
+                .user_relation_eq
+                .as_ref()
+                .is_none_or(|e| t.user_relation.as_ref().is_some_and(|r| r.eq(e)))
+            && self
+                .user_relation_is_null
+                .is_none_or(|is_null| is_null == t.user_relation.is_none())
+            && self
+                .or
+                .as_ref()
+                .is_none_or(|or| or.is_empty() || or.iter().any(|f| f.matches(t)))
+    }
+}
+
 #[async_trait]
 pub trait RelationshipTupleReader: Send + Sync {
     async fn list(
@@ -55,7 +87,8 @@ pub trait AuthzModelReader: Send + Sync {
 
 #[async_trait]
 pub trait AuthzModelWriter: Send + Sync {
-    async fn save(&self, tenant_id: String, model: Schema) -> Result<()>;
+    /// Unless `force`, fails with `StorageError::OrphanedTuples` when stored tuples do not fit the model anymore.
+    async fn save(&self, tenant_id: String, model: Schema, force: bool) -> Result<()>;
 }
 
 #[async_trait]
@@ -3,13 +3,17 @@ mod helper;
 mod tenant;
 pub mod tuple;
 
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    sync::Arc,
+};
 
-use ::schema::Schema as AuthzModel;
+use ::schema::{ChangeKind, Schema as AuthzModel};
 use anyhow::Context;
 use async_trait::async_trait;
 use chrono::Utc;
 use helper::filter_to_conds;
+use protocol::Typesystem;
 use sea_orm::*;
 use sea_orm::{sea_query::all, DbConn};
 
@@ -29,6 +33,24 @@ impl Storage {
     pub fn new(pool: Arc<DbConn>) -> Self {
         Self { pool }
     }
+
+    /// Saving a model reads the tuples that writing tuples adds to, and writing tuples reads the model that saving a
+    /// model adds to: both run serializable so that of two concurrent ones, one fails rather than a tuple written
+    /// during the orphan check slipping through it. The failed one may be retried. SQLite transactions are always
+    /// serializable, it does not take the setting.
+    async fn begin_serializable(&self) -> Result<DatabaseTransaction, DbErr> {
+        let isolation_level =
+            (self.pool.get_database_backend() != DbBackend::Sqlite).then_some(IsolationLevel::Serializable);
+        self.pool.begin_with_config(isolation_level, None).await
+    }
+}
+
+async fn latest_model<C: ConnectionTrait>(conn: &C, tenant_id: &str) -> Result<Option<authz_model::Model>, DbErr> {
+    authz_model::Entity::find()
+        .filter(authz_model::Column::TenantId.eq(tenant_id))
+        .order_by_desc(authz_model::Column::Id)
+        .one(conn)
+        .await
 }
 
 #[async_trait]
@@ -42,7 +64,11 @@ impl RelationshipTupleReader for Storage {
         let conds = all![tuple::Column::TenantId.eq(tenant_id), filter_to_conds(&filter)];
         let conn = self.pool.clone();
         if let Some(page) = page {
-            let query = tuple::Entity::find().filter(conds).paginate(conn.as_ref(), page.size);
+            // pages of an unordered query may overlap
+            let query = tuple::Entity::find()
+                .filter(conds)
+                .order_by_asc(tuple::Column::Id)
+                .paginate(conn.as_ref(), page.size);
 
             Ok((
                 query
@@ -60,16 +86,52 @@ impl RelationshipTupleReader for Storage {
     }
 }
 
+fn validate_conditions(model: &AuthzModel, tuples: &[protocol::Tuple]) -> Result<(), StorageError> {
+    for t in tuples {
+        let Some(name) = &t.condition else {
+            continue;
+        };
+        let condition = model
+            .conditions
+            .iter()
+            .find(|c| c.name.eq(name))
+            .ok_or_else(|| StorageError::NotFoundCondition(name.to_owned()))?;
+        if let Some(context) = &t.context {
+            condition
+                .validate_context(context)
+                .map_err(|source| StorageError::InvalidConditionContext {
+                    name: name.to_owned(),
+                    source,
+                })?;
+        }
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl RelationshipTupleWriter for Storage {
     async fn save(&self, tenant_id: &str, tuples: Vec<protocol::Tuple>) -> anyhow::Result<()> {
+        // a context is only read through the condition of its tuple
+        if let Some(t) = tuples.iter().find(|t| t.condition.is_none() && t.context.is_some()) {
+            return Err(StorageError::ContextWithoutCondition(format!(
+                "{}:{}#{}",
+                t.object_type, t.object_id, t.relation
+            ))
+            .into());
+        }
+        let txn = self.begin_serializable().await?;
+        // read even when no condition is validated, so that a model saved meanwhile conflicts with the write
+        let latest = latest_model(&txn, tenant_id).await?;
+        if tuples.iter().any(|t| t.condition.is_some()) {
+            let latest = latest.context(StorageError::NotFoundAuthzModel)?;
+            validate_conditions(&latest.model, &tuples)?;
+        }
         let mut tuples: Vec<ActiveModel> = tuples.iter().map(|t| t.to_owned().into()).collect();
         for t in &mut tuples {
             t.tenant_id = Set(tenant_id.to_owned());
         }
-        tuple::Entity::insert_many(tuples)
-            .exec(self.pool.clone().as_ref())
-            .await?;
+        tuple::Entity::insert_many(tuples).exec(&txn).await?;
+        txn.commit().await?;
         Ok(())
     }
 
@@ -86,9 +148,7 @@ impl RelationshipTupleWriter for Storage {
 #[async_trait]
 impl AuthzModelReader for Storage {
     async fn get_latest(&self, tenant_id: String) -> anyhow::Result<(String, AuthzModel)> {
-        let model = authz_model::Entity::find()
-            .filter(authz_model::Column::TenantId.eq(tenant_id))
-            .one(self.pool.clone().as_ref())
+        let model = latest_model(self.pool.as_ref(), &tenant_id)
             .await?
             .context(StorageError::NotFoundAuthzModel)?;
 
@@ -137,18 +197,108 @@ impl AuthzModelReader for Storage {
     }
 }
 
+const ORPHAN_SAMPLE_SIZE: usize = 10;
+const ORPHAN_SCAN_BATCH_SIZE: u64 = 1000;
+
+impl Storage {
+    /// Only the tuples of the types and conditions touched by a breaking change of the model are scanned, in the
+    /// serializable transaction `conn` the model is saved in, see `begin_serializable`.
+    async fn check_orphans<C: ConnectionTrait>(conn: &C, tenant_id: &str, model: &AuthzModel) -> anyhow::Result<()> {
+        let Some(latest) = latest_model(conn, tenant_id).await? else {
+            return Ok(());
+        };
+        let mut object_types = BTreeSet::new();
+        let mut conditions = BTreeSet::new();
+        for change in ::schema::diff(&latest.model, model) {
+            match change.kind {
+                ChangeKind::TypeRemoved | ChangeKind::RelationRemoved | ChangeKind::DirectTypeRemoved => {
+                    let object_type = change.target.split('#').next().unwrap_or_default();
+                    object_types.insert(object_type.to_owned());
+                }
+                ChangeKind::ConditionRemoved => {
+                    conditions.insert(change.target);
+                }
+                _ => {}
+            }
+        }
+        if object_types.is_empty() && conditions.is_empty() {
+            return Ok(());
+        }
+
+        let typesystem = model.clone().to_typesystem();
+        let defined: HashSet<&str> = model.conditions.iter().map(|c| c.name.as_str()).collect();
+        let mut count = 0;
+        let mut relations = BTreeMap::new();
+        let mut sample = vec![];
+        let mut pages = tuple::Entity::find()
+            .filter(tuple::Column::TenantId.eq(tenant_id))
+            .filter(
+                Condition::any()
+                    .add(tuple::Column::ObjectType.is_in(object_types))
+                    .add(tuple::Column::ConditionName.is_in(conditions)),
+            )
+            .order_by_asc(tuple::Column::Id)
+            .paginate(conn, ORPHAN_SCAN_BATCH_SIZE);
+        while let Some(tuples) = pages.fetch_and_next().await? {
+            for t in tuples {
+                let t: protocol::Tuple = t.into();
+                if fits(&typesystem, &defined, &t) {
+                    continue;
+                }
+                count += 1;
+                *relations
+                    .entry(format!("{}#{}", t.object_type, t.relation))
+                    .or_insert(0) += 1;
+                if sample.len() < ORPHAN_SAMPLE_SIZE {
+                    sample.push(t);
+                }
+            }
+        }
+        if count > 0 {
+            return Err(StorageError::OrphanedTuples {
+                count,
+                relations,
+                sample,
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Whether a stored tuple is still reachable through a directly related type of its relation, a conditional tuple
+/// needs its condition to be defined and the reference not to require another one.
+fn fits(typesystem: &Typesystem, conditions: &HashSet<&str>, t: &protocol::Tuple) -> bool {
+    if t.condition.as_ref().is_some_and(|c| !conditions.contains(c.as_str())) {
+        return false;
+    }
+    let Ok(references) = typesystem.get_directly_related_types(&t.object_type, &t.relation) else {
+        return false;
+    };
+    references.iter().any(|r| {
+        r.matches(t)
+            && match (t.condition.as_deref(), r.condition()) {
+                (Some(tc), Some(rc)) => tc.eq(rc),
+                _ => true,
+            }
+    })
+}
+
 #[async_trait]
 impl AuthzModelWriter for Storage {
-    async fn save(&self, tenant_id: String, model: AuthzModel) -> anyhow::Result<()> {
+    async fn save(&self, tenant_id: String, model: AuthzModel, force: bool) -> anyhow::Result<()> {
+        let txn = self.begin_serializable().await?;
+        if !force {
+            Self::check_orphans(&txn, &tenant_id, &model).await?;
+        }
         let model = authz_model::ActiveModel {
             tenant_id: Set(tenant_id),
             model: Set(model),
             created_at: Set(Utc::now().naive_utc()),
             ..Default::default()
         };
-        authz_model::Entity::insert(model)
-            .exec(self.pool.clone().as_ref())
-            .await?;
+        authz_model::Entity::insert(model).exec(&txn).await?;
+        txn.commit().await?;
         Ok(())
     }
 }
@@ -160,7 +310,6 @@ impl TenantOperator for Storage {
             id: Set(tenant_id),
             name: Set(name),
             created_at: Set(Utc::now().naive_utc()),
-            ..Default::default()
         };
         tenant::Entity::insert(model).exec(self.pool.clone().as_ref()).await?;
         Ok(())
@@ -14,6 +14,8 @@ pub struct Model {
     pub relation: String,
     pub object_type: String,
     pub object_id: String,
+    pub condition_name: Option<String>,
+    pub condition_context: Option<Json>,
     #[sea_orm(default_expr = "Utc::now().naive_utc()")]
     pub created_at: ChronoDateTime,
 }
@@ -32,6 +34,8 @@ impl From<Model> for protocol::Tuple {
             relation: t.relation,
             object_type: t.object_type,
             object_id: t.object_id,
+            condition: t.condition_name,
+            context: t.condition_context.and_then(|c| serde_json::from_value(c).ok()),
         }
     }
 }
@@ -45,6 +49,8 @@ impl From<protocol::Tuple> for ActiveModel {
             relation: Set(t.relation),
             object_type: Set(t.object_type),
             object_id: Set(t.object_id),
+            condition_name: Set(t.condition),
+            condition_context: Set(t.context.map(|c| Json::Object(c.into_iter().collect()))),
             created_at: Set(Utc::now().naive_utc()),
             ..Default::default()
         }